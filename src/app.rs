@@ -1,4 +1,5 @@
-use crate::util;
+
+use anyhow::anyhow;
 
 use tokio::{
     sync::Mutex as tMutex,
@@ -9,29 +10,59 @@ use std::sync::{
     Arc, Mutex,
 };
 
-use crate::constants::IssueModificationOp;
+use crate::constants::{
+    IssueModificationOp,
+    view_panel_columns::{ PanelColumnConfig, default_panel_columns, resolve_panel_columns },
+};
+
+use crate::backend::{ self, BackendHandle, IssueBackend };
+use crate::error::{ ClientError, ClientResult };
+use crate::webhook::{ WebhookAction, WebhookEvents };
+
+use crate::config::{ DEFAULT_DASHBOARD_NAME, DEFAULT_PROFILE_NAME };
 
 use crate::linear::{
-    client::{LinearClient, IssueFieldObject, IssueFieldResponse},
+    client::{LinearClient, IssueFieldObject, IssueFieldResponse, ScaleValue},
     schema::{
-        CustomView, Issue, IssueUpdateInput, Viewer,
+        CustomView, Issue, IssueLabelConnection, IssueUpdateInput, Viewer, IssuesResponseData,
     },
+    cache::CacheTree,
+    config::DEFAULT_PANEL_COLUMNS_KEY,
     LinearConfig,
+    LinearCache,
+    RateLimitHandle,
 };
 
-use std::collections::{HashSet};
+use std::collections::{HashMap, HashSet};
+use std::net::SocketAddr;
+use std::path::PathBuf;
+use std::time::{ Duration, Instant };
+
+use serde::{ Deserialize, Serialize };
 
 use crate::util::{
-    dashboard::fetch_selected_value, dashboard::fetch_selected_view_panel_issue, GraphQLCursor,
+    dashboard::fetch_selected_value, dashboard::fetch_selected_view_panel_issue, dashboard::MIN_DASHBOARD_SLOTS, GraphQLCursor,
+    grid_layout::{ GridLayout, PanelSpan },
+    issue_order::PanelOrder,
+    export,
     stateful_list::StatefulList,
 };
 
 use crate::components::{
     command_bar::{CommandBar, CommandBarType},
     dashboard_view_config_display::DashboardViewConfigDisplay,
-    dashboard_view_panel::DashboardViewPanel,
+    dashboard_name_entry::DashboardNameEntry,
+    panel_jump_prompt::PanelJumpPrompt,
+    panel_export_prompt::{ PanelExportPrompt, PanelExportState },
+    notification_center::NotificationCenter,
+    issue_history::{ self, IssueHistoryLog, IssueModification },
+    dashboard_view_panel::{ DashboardViewPanel, RefreshResult },
     linear_custom_view_select::LinearCustomViewSelect,
     linear_issue_op_interface::LinearIssueOpInterface,
+    linear_issue_create_form::LinearIssueCreateForm,
+    linear_issue_modal::IssueCommentsPane,
+    linear_issue_search::LinearIssueSearch,
+    due_date_picker::DueDatePicker,
 
     token_entry::{ TokenEntry, TokenValidationState },
     title_entry::{ TitleEntry },
@@ -42,12 +73,14 @@ use crate::components::{
 use tui::widgets::TableState;
 
 pub struct ViewLoadBundle {
-    pub linear_client: Arc<tMutex<Option<LinearClient>>>,
+    pub backend: BackendHandle,
 
     pub item_filter: CustomView,
     pub table_data: Arc<Mutex<Vec<Issue>>>,
     pub cursor: Arc<Mutex<Option<GraphQLCursor>>>,
     pub loading: Arc<AtomicBool>,
+    pub order: Arc<Mutex<PanelOrder>>,
+    pub refreshed_at: Arc<Mutex<Instant>>,
 }
 
 #[derive(PartialEq, Clone)]
@@ -55,6 +88,7 @@ pub enum Route {
     ConfigInterface,
     ActionSelect,
     DashboardViewDisplay,
+    IssueSearch,
 }
 
 #[derive(PartialEq)]
@@ -70,9 +104,30 @@ pub enum AppEvent {
     PaginateDashboardView,
     LoadIssueOpData,
     UpdateIssue,
+    CreateIssue,
+    LoadIssueComments,
+    CreateComment,
+    SearchIssues,
+    ExportViewPanel,
+    AutoRefreshViewPanels,
+    ApplyWebhookEvents,
+    UndoIssueModification,
+    RedoIssueModification,
+}
+
+// Result of the last Issue description edit in $EDITOR
+#[derive(Debug, Clone, PartialEq)]
+pub enum DescriptionEditState {
+    Null,
+    Updated,
+    // Remote description changed while editing, local edit kept at path
+    Conflict(PathBuf),
+    Failed(String),
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+// Backend of a profile, as named in the config [[profiles]] 'platform'
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
 pub enum Platform {
     Na,
     Linear,
@@ -87,8 +142,10 @@ pub struct App<'a> {
 
     /// Current value of the Command string
     pub cmd_str: String,
-    // LinearClient
-    pub linear_client: Arc<tMutex<Option<LinearClient>>>,
+    // Backend of the active profile
+    pub backend: BackendHandle,
+    // Local cache shared with LinearClient, readable without acquiring the LinearClient lock
+    pub linear_cache: Option<LinearCache>,
 
     // Current input mode
     pub input_mode: InputMode,
@@ -125,10 +182,44 @@ pub struct App<'a> {
     pub dashboard_view_display: DashboardViewConfigDisplay,
     pub dashboard_view_config_cmd_bar: CommandBar<'a>,
 
+    // Named dashboards, the view list, orders & columns below belong to 'dashboard_name'
+    pub dashboard_name: String,
+    pub dashboard_names: Vec<String>,
+    pub dashboard_name_entry: DashboardNameEntry,
+
+    // Workspace profiles, 'backend' & 'viewer_obj_render' belong to 'profile_name'
+    pub profile_name: String,
+    pub profile_names: Vec<String>,
+    // Backends of the profiles used so far, keyed by profile name
+    pub profile_backends: HashMap<String, BackendHandle>,
+    // Platform of each profile, keyed by profile name, Linear if missing
+    pub profile_platforms: HashMap<String, Platform>,
+    // Cached viewers, keyed by profile name, View Panels are labeled with their organization
+    pub profile_viewers: Arc<Mutex<HashMap<String, Viewer>>>,
+    // Remaining API quota of each profile's backend, keyed by profile name
+    pub profile_rate_limits: Arc<Mutex<HashMap<String, RateLimitHandle>>>,
+
     // Linear Dashboard Custom View List
     pub linear_dashboard_view_list: Vec<Option<CustomView>>,
     pub linear_dashboard_view_idx: Option<usize>,
     pub linear_dashboard_view_list_selected: bool,
+    // View Panel sort & grouping, keyed by CustomView id
+    pub linear_dashboard_panel_orders: HashMap<String, PanelOrder>,
+    // View Panel columns, keyed by CustomView id or name
+    pub linear_dashboard_panel_columns: HashMap<String, Vec<PanelColumnConfig>>,
+    // View Panel grid, 2 panels per row if None
+    pub linear_dashboard_layout: Option<GridLayout>,
+    // View Panel grid spans, keyed by CustomView id
+    pub linear_dashboard_panel_spans: HashMap<String, PanelSpan>,
+    // View Panel profiles, keyed by CustomView id, panels without one use the active profile
+    pub linear_dashboard_panel_profiles: HashMap<String, String>,
+    // View Panel auto-refresh intervals, keyed by CustomView id
+    pub linear_dashboard_panel_refresh_intervals: HashMap<String, Duration>,
+
+    // Webhook listener address, None if not listening
+    pub webhook_addr: Option<SocketAddr>,
+    // Received webhook events, applied on each tick
+    pub webhook_events: WebhookEvents,
 
     // Linear Dashboard View Panel Display
 
@@ -137,15 +228,43 @@ pub struct App<'a> {
     pub linear_dashboard_view_panel_selected: Option<usize>,
     pub view_panel_issue_selected: Option<TableState>,
     pub view_panel_to_paginate: usize,
+    // Render only the selected View Panel
+    pub view_panel_zoomed: bool,
+    pub panel_jump_prompt: PanelJumpPrompt,
+    // Index of the View Panel 'panel_export_prompt' exports
+    pub view_panel_to_export: usize,
+    pub panel_export_prompt: PanelExportPrompt,
+
+    // Errors of background tasks, shown as toasts & in the error log
+    pub notification_center: NotificationCenter,
+
+    // Undo & redo stacks of successful Issue modifications
+    pub issue_history_log: IssueHistoryLog,
 
     pub view_panel_cmd_bar: CommandBar<'a>,
 
+    // Workspace Issue Search
+    pub issue_search: LinearIssueSearch,
+
     pub issue_to_expand: Option<Issue>,
+    pub issue_comments_pane: IssueCommentsPane,
 
     // Issue Modification fields
     pub modifying_issue: bool,
     pub linear_issue_op_interface: LinearIssueOpInterface,
 
+    // Issue Creation fields
+    pub creating_issue: bool,
+    pub linear_issue_create_form: LinearIssueCreateForm,
+
+    // Issue Due Date Picker Component
+    pub due_date_picker: DueDatePicker,
+    // Days until due for an Issue to be highlighted as due soon
+    pub due_soon_day_threshold: u32,
+
+    // Issue Description editing ($EDITOR)
+    pub description_edit_state: DescriptionEditState,
+
     // Available actions
     pub actions: StatefulList<&'a str>,
 }
@@ -158,7 +277,8 @@ impl<'a> Default for App<'a> {
 
             cmd_str: String::new(),
 
-            linear_client: Arc::new(tMutex::new(None)),
+            backend: Arc::new(tMutex::new(None)),
+            linear_cache: None,
 
             input_mode: InputMode::Normal,
             active_input: InputComponent::TokenEntry,
@@ -181,23 +301,62 @@ impl<'a> Default for App<'a> {
             dashboard_view_display: DashboardViewConfigDisplay::default(),
             dashboard_view_config_cmd_bar: CommandBar::with_type(CommandBarType::ViewList),
 
-            linear_dashboard_view_list: vec![None, None, None, None, None, None],
+            dashboard_name: DEFAULT_DASHBOARD_NAME.to_string(),
+            dashboard_names: vec![DEFAULT_DASHBOARD_NAME.to_string()],
+            dashboard_name_entry: DashboardNameEntry::default(),
+
+            profile_name: DEFAULT_PROFILE_NAME.to_string(),
+            profile_names: vec![DEFAULT_PROFILE_NAME.to_string()],
+            profile_backends: HashMap::new(),
+            profile_platforms: HashMap::new(),
+            profile_viewers: Arc::new(Mutex::new(HashMap::new())),
+            profile_rate_limits: Arc::new(Mutex::new(HashMap::new())),
+
+            linear_dashboard_view_list: vec![None; MIN_DASHBOARD_SLOTS],
             linear_dashboard_view_idx: None,
             linear_dashboard_view_list_selected: true,
+            linear_dashboard_panel_orders: HashMap::new(),
+            linear_dashboard_panel_columns: HashMap::new(),
+            linear_dashboard_layout: None,
+            linear_dashboard_panel_spans: HashMap::new(),
+            linear_dashboard_panel_profiles: HashMap::new(),
+            linear_dashboard_panel_refresh_intervals: HashMap::new(),
+
+            webhook_addr: None,
+            webhook_events: WebhookEvents::default(),
 
             linear_dashboard_view_panel_list: Arc::new(Mutex::new(Vec::with_capacity(6))),
             linear_dashboard_view_panel_selected: None,
             view_panel_issue_selected: None,
             view_panel_to_paginate: 0,
+            view_panel_zoomed: false,
+            panel_jump_prompt: PanelJumpPrompt::default(),
+            view_panel_to_export: 0,
+            panel_export_prompt: PanelExportPrompt::default(),
+
+            notification_center: NotificationCenter::default(),
+
+            issue_history_log: IssueHistoryLog::default(),
 
             view_panel_cmd_bar: CommandBar::with_type(CommandBarType::Dashboard),
 
+            issue_search: LinearIssueSearch::default(),
+
             issue_to_expand: None,
+            issue_comments_pane: IssueCommentsPane::default(),
 
             modifying_issue: false,
             linear_issue_op_interface: LinearIssueOpInterface::default(),
 
-            actions: StatefulList::with_items(vec!["Modify Dashboard"]).selected(),
+            creating_issue: false,
+            linear_issue_create_form: LinearIssueCreateForm::default(),
+
+            due_date_picker: DueDatePicker::default(),
+            due_soon_day_threshold: LinearConfig::default().due_soon_day_threshold,
+
+            description_edit_state: DescriptionEditState::Null,
+
+            actions: StatefulList::with_items(vec!["Modify Dashboard", "Search Issues"]).selected(),
         }
     }
 }
@@ -225,10 +384,7 @@ impl<'a> App<'a> {
                 self.actions.next();
 
                 if !self.view_list_cache_read_attempted {
-                    let cached_read_option = LinearConfig::read_view_list();
-                    if let Some(cached_view_list) = cached_read_option {
-                        self.linear_dashboard_view_list = cached_view_list;
-                    }
+                    self.read_dashboard_config();
                 }
 
                 self.dispatch_event(AppEvent::LoadDashboardViews);
@@ -250,10 +406,140 @@ impl<'a> App<'a> {
 
                 self.dispatch_event(AppEvent::LoadCustomViews);
             }
+
+            Route::IssueSearch => {
+                // start with the query input focused
+                self.input_mode = InputMode::Edit;
+                self.active_input = InputComponent::SearchQuery;
+
+                // Unselect from actions list
+                self.actions.unselect();
+            }
         }
         *self.route.lock().unwrap() = route;
     }
 
+    // Read the active dashboard's view list, panel orders & columns from config
+    fn read_dashboard_config(&mut self) {
+        self.dashboard_names = LinearConfig::read_dashboard_names();
+
+        let cached_read_option = LinearConfig::read_view_list(&self.dashboard_name);
+        if let Some(cached_view_list) = cached_read_option {
+            self.linear_dashboard_view_list = cached_view_list;
+        }
+        self.linear_dashboard_panel_orders = LinearConfig::read_panel_orders(&self.dashboard_name);
+        self.linear_dashboard_panel_columns = LinearConfig::read_panel_columns(&self.dashboard_name);
+        self.linear_dashboard_layout = LinearConfig::read_dashboard_layout(&self.dashboard_name);
+        self.linear_dashboard_panel_spans = LinearConfig::read_panel_spans(&self.dashboard_name);
+        self.linear_dashboard_panel_profiles = LinearConfig::read_panel_profiles(&self.dashboard_name);
+        self.linear_dashboard_panel_refresh_intervals = LinearConfig::read_panel_refresh_intervals(&self.dashboard_name);
+        self.issue_search.columns = match self.linear_dashboard_panel_columns.get(DEFAULT_PANEL_COLUMNS_KEY) {
+            Some(column_configs) => resolve_panel_columns(column_configs),
+            None => default_panel_columns(),
+        };
+    }
+
+    // Replace the View Panels with those of the named dashboard, and open it on next startup
+    pub fn switch_dashboard(&mut self, name: String) {
+        info!("switch_dashboard - {:?} -> {:?}", self.dashboard_name, name);
+
+        LinearConfig::save_active_dashboard(&name);
+        self.dashboard_name = name;
+
+        self.linear_dashboard_view_list = vec![None; MIN_DASHBOARD_SLOTS];
+        self.read_dashboard_config();
+
+        self.linear_dashboard_view_panel_list.lock().unwrap().clear();
+        self.linear_dashboard_view_panel_selected = None;
+        self.view_panel_issue_selected = None;
+        self.view_panel_zoomed = false;
+
+        let cur_route: Route = self.route.lock().unwrap().clone();
+        if cur_route == Route::ActionSelect {
+            self.dispatch_event(AppEvent::LoadDashboardViews);
+        }
+    }
+
+    // Backend of the named profile, created from config.toml on first use
+    pub fn profile_backend(&mut self, profile: &str) -> BackendHandle {
+        if let Some(client) = self.profile_backends.get(profile) {
+            return client.clone();
+        }
+
+        let client = backend::connect(profile, self.linear_cache.clone());
+        self.insert_profile_backend(profile, client)
+    }
+
+    pub fn insert_profile_backend(&mut self, profile: &str, client: Option<Arc<dyn IssueBackend>>) -> BackendHandle {
+        if let Some(rate_limit) = client.as_ref().and_then(|x| x.rate_limit()) {
+            self.profile_rate_limits.lock().unwrap().insert(profile.to_string(), rate_limit);
+        }
+
+        let client = Arc::new(tMutex::new(client));
+        self.profile_backends.insert(profile.to_string(), client.clone());
+        client
+    }
+
+    // e.g. "API: 1423/1500 requests", None until the active profile's backend reports its quota
+    pub fn rate_limit_summary(&self) -> Option<String> {
+        let rate_limit = self.profile_rate_limits.lock().unwrap().get(&self.profile_name).cloned()?;
+        let summary = rate_limit.lock().unwrap().summary();
+        summary
+    }
+
+    pub fn profile_platform(&self, profile: &str) -> Platform {
+        self.profile_platforms.get(profile).cloned().unwrap_or(Platform::Linear)
+    }
+
+    // Profile a View Panel's view is loaded with
+    pub fn panel_profile(&self, view: &CustomView) -> String {
+        self.linear_dashboard_panel_profiles.get(&view.id).cloned().unwrap_or_else(|| self.profile_name.clone())
+    }
+
+    // Profile of the selected Issue, the selected View Panel's profile on the dashboard,
+    // otherwise the active profile
+    pub fn selected_issue_profile(&self) -> String {
+        let cur_route: Route = self.route.lock().unwrap().clone();
+        if cur_route != Route::ActionSelect {
+            return self.profile_name.clone();
+        }
+
+        let view: Option<CustomView> = self.linear_dashboard_view_panel_selected.and_then(|idx| {
+            self.linear_dashboard_view_panel_list.lock().unwrap().get(idx.checked_sub(1)?).map(|x| x.view.clone())
+        });
+        match view {
+            Some(view) => self.panel_profile(&view),
+            None => self.profile_name.clone(),
+        }
+    }
+
+    pub fn selected_issue_backend(&mut self) -> BackendHandle {
+        let profile = self.selected_issue_profile();
+        self.profile_backend(&profile)
+    }
+
+    pub fn selected_issue_platform(&self) -> Platform {
+        self.profile_platform(&self.selected_issue_profile())
+    }
+
+    // Make the named profile active, View Panels keep their own profile
+    pub fn switch_profile(&mut self, name: String) {
+        info!("switch_profile - {:?} -> {:?}", self.profile_name, name);
+
+        LinearConfig::save_active_profile(&name);
+        self.backend = self.profile_backend(&name);
+        *self.viewer_obj_render.lock().unwrap() = self.profile_viewers.lock().unwrap().get(&name).cloned();
+        self.profile_name = name;
+
+        // Custom Views & search results belong to the previous workspace
+        self.issue_search.clear_results();
+
+        let cur_route: Route = self.route.lock().unwrap().clone();
+        if cur_route == Route::DashboardViewDisplay {
+            self.change_route(Route::DashboardViewDisplay);
+        }
+    }
+
     pub fn dispatch_event(&mut self, event: AppEvent) {
         match event {
             AppEvent::LoadViewer => {
@@ -267,18 +553,28 @@ impl<'a> App<'a> {
 
                 let token: String = self.token_entry.input.input.clone();
 
-                let linear_client_handle = self.linear_client.clone();
+                let backend_handle = self.backend.clone();
 
                 let route_handle = self.route.clone();
                 let change_route_handle = self.change_route.clone();
 
                 let viewer_obj_render_handle = self.viewer_obj_render.clone();
 
+                let linear_cache = self.linear_cache.clone();
+
+                let profile = self.profile_name.clone();
+                let profile_viewers_handle = self.profile_viewers.clone();
+                let profile_rate_limits_handle = self.profile_rate_limits.clone();
+                self.profile_backends.insert(profile.clone(), backend_handle.clone());
+
+                let notifications = self.notification_center.notifications.clone();
+
                 let _t1 = tokio::spawn(async move {
                     // Temporary client without caching
-                    let temp_client = LinearClient::with_config(LinearConfig::new(&token, None,false)).unwrap();
-
-                    let res = temp_client.viewer().await;
+                    let res = match LinearClient::with_config(LinearConfig::new(&profile, &token, None,false)) {
+                        Ok(temp_client) => temp_client.viewer().await,
+                        Err(err) => Err(err),
+                    };
 
                     debug!("AppEvent::LoadViewer - res: {res:?}");
 
@@ -286,22 +582,35 @@ impl<'a> App<'a> {
                     if let Ok(Some(resp_data)) = res {
                         {
 
-                            let mut linear_client_lock = linear_client_handle.lock().await;
+                            let mut backend_lock = backend_handle.lock().await;
 
-                            *linear_client_lock = Some(
-                                LinearClient::with_config(LinearConfig::new(&token, Some(resp_data.viewer.clone()),true)).unwrap()
-                            );
+                            let mut client = match LinearClient::with_config(LinearConfig::new(&profile, &token, Some(resp_data.viewer.clone()),true)) {
+                                Ok(x) => x,
+                                Err(err) => {
+                                    notifications.error("Saving access token", &err);
+                                    *token_validation_state_handle.lock().unwrap() = TokenValidationState::Invalid;
+                                    return;
+                                }
+                            };
+                            client.cache = linear_cache;
+                            profile_rate_limits_handle.lock().unwrap().insert(profile.clone(), client.scheduler.quota.clone());
+
+                            *backend_lock = Some(Arc::new(client));
 
                             *route_handle.lock().unwrap() = Route::ActionSelect;
                             change_route_handle.store(true, Ordering::Relaxed);
 
                             let mut viewer_obj_render_lock = viewer_obj_render_handle.lock().unwrap();
                             *viewer_obj_render_lock = Some(resp_data.viewer.clone());
+                            profile_viewers_handle.lock().unwrap().insert(profile, resp_data.viewer.clone());
 
                             let mut token_validation_state_lock = token_validation_state_handle.lock().unwrap();
                             *token_validation_state_lock = TokenValidationState::Valid;
                         }
                     } else {
+                        if let Err(err) = res {
+                            notifications.error("Validating access token", &err);
+                        }
                         let mut token_validation_state_lock = token_validation_state_handle.lock().unwrap();
                         *token_validation_state_lock = TokenValidationState::Invalid;
                     }
@@ -319,19 +628,34 @@ impl<'a> App<'a> {
                 // Set Loading 'true' before fetch
                 view_select_loading_handle.store(true, Ordering::Relaxed);
 
-                let linear_client_handle = self.linear_client.clone();
+                let backend_handle = self.backend.clone();
 
                 let view_data_handle = self.linear_custom_view_select.view_table_data.clone();
 
+                // List local filter panels ahead of the first page of Linear custom views
+                if self.profile_platform(&self.profile_name) == Platform::Linear {
+                    let mut view_data_lock = view_data_handle.lock().unwrap();
+                    if view_data_lock.is_empty() {
+                        *view_data_lock = LinearConfig::read_local_filters();
+                    }
+                }
+
                 let view_cursor_handle = self.linear_custom_view_cursor.lock().unwrap();
                 let view_cursor: GraphQLCursor = view_cursor_handle.clone();
                 drop(view_cursor_handle);
 
                 let view_cursor_handle = self.linear_custom_view_cursor.clone();
+                let notifications = self.notification_center.notifications.clone();
 
                 let _t1 = tokio::spawn(async move {
-                    let linear_client_lock = linear_client_handle.lock().await;
-                    let client = linear_client_lock.as_ref().unwrap();
+                    let client = match backend::current(&backend_handle).await {
+                        Some(client) => client,
+                        // Active profile has no valid token
+                        None => {
+                            view_select_loading_handle.store(false, Ordering::Relaxed);
+                            return;
+                        }
+                    };
 
                     let res  = client.custom_views(Some(view_cursor)).await;
 
@@ -356,7 +680,10 @@ impl<'a> App<'a> {
                                 .end_cursor,
                         };
                     } else {
-                        util::error_panic!("LoadCustomViews error: {:?}",res);
+                        view_select_loading_handle.store(false, Ordering::Relaxed);
+                        if let Err(err) = res {
+                            notifications.error("Loading Custom Views", &err);
+                        }
                     }
 
                     info!(
@@ -426,14 +753,38 @@ impl<'a> App<'a> {
                             }
                             // Need to create a new View Panel
                             None => {
+                                let mut view_panel = DashboardViewPanel::with_view(view.clone());
+
+                                let column_configs = self.linear_dashboard_panel_columns.get(&view.id)
+                                    .or_else(|| self.linear_dashboard_panel_columns.get(&view.name))
+                                    .or_else(|| self.linear_dashboard_panel_columns.get(DEFAULT_PANEL_COLUMNS_KEY));
+                                if let Some(column_configs) = column_configs {
+                                    view_panel.columns = resolve_panel_columns(column_configs);
+                                }
+
+                                let order = self.linear_dashboard_panel_orders.get(&view.id).copied().unwrap_or_default();
+                                *view_panel.order.lock().unwrap() = order;
+
+                                view_panel.refresh_interval = self.linear_dashboard_panel_refresh_intervals.get(&view.id).copied();
+
+                                // Display cached issues until the View Panel is refreshed
+                                if let Some(cache) = &self.linear_cache {
+                                    let cache_key = LinearCache::page_key(&view.id, &None);
+                                    if let Some(cached) = cache.get::<IssuesResponseData>(CacheTree::Issues, &cache_key) {
+                                        let mut cached_issues = cached.issues.nodes;
+                                        order.apply(&mut cached_issues);
+                                        *view_panel.issue_table_data.lock().unwrap() = cached_issues;
+                                    }
+                                }
+
                                 if i < view_panel_list_lock.len() {
                                     let _got = std::mem::replace(
                                         &mut view_panel_list_lock[i],
-                                        DashboardViewPanel::with_view(view.clone()),
+                                        view_panel,
                                     );
                                 } else {
                                     view_panel_list_lock
-                                        .insert(i, DashboardViewPanel::with_view(view.clone()));
+                                        .insert(i, view_panel);
                                 }
                             }
                         };
@@ -442,27 +793,34 @@ impl<'a> App<'a> {
 
                 // Create 'view_load_bundles': Vec<ViewLoadBundle> from view_panel_list_handle
                 // Filter to only create ViewLoadBundles for ViewPanels where
-                let view_load_bundles: Vec<ViewLoadBundle> = view_panel_list_lock
+                let view_panels_to_load: Vec<DashboardViewPanel> = view_panel_list_lock
                     .iter()
                     .cloned()
                     .enumerate()
-                    .filter_map(|(i, e)| {
-                        if existing_panel_set.contains(&i) {
-                            None
-                        } else {
-                            Some(ViewLoadBundle {
-                                linear_client: self.linear_client.clone(),
+                    .filter_map(|(i, e)| if existing_panel_set.contains(&i) { None } else { Some(e) })
+                    .collect();
+
+                drop(view_panel_list_lock);
+
+                // Each View Panel is loaded with its profile's client
+                let view_load_bundles: Vec<ViewLoadBundle> = view_panels_to_load
+                    .into_iter()
+                    .map(|e| {
+                            let profile = self.panel_profile(&e.view);
+                            ViewLoadBundle {
+                                backend: self.profile_backend(&profile),
 
                                 item_filter: e.view,
                                 table_data: e.issue_table_data.clone(),
                                 cursor: e.view_cursor.clone(),
                                 loading: e.loading.clone(),
-                            })
-                        }
+                                order: e.order.clone(),
+                                refreshed_at: e.refreshed_at.clone(),
+                            }
                     })
                     .collect();
 
-                drop(view_panel_list_lock);
+                let notifications = self.notification_center.notifications.clone();
 
                 let _t1 = tokio::spawn(async move {
                     // Load all DashboardViewPanels
@@ -481,14 +839,21 @@ impl<'a> App<'a> {
                             // Set ViewPanel loading state to true
                             item.loading.store(true, Ordering::Relaxed);
 
+                            let notifications = notifications.clone();
+
                             tokio::spawn(async move {
-                                let linear_client_lock = item.linear_client.lock().await;
-                                let client = linear_client_lock.as_ref().unwrap();
+                                let client = match backend::current(&item.backend).await {
+                                    Some(client) => client,
+                                    // Panel's profile has no valid token
+                                    None => {
+                                        item.loading.store(false, Ordering::Relaxed);
+                                        return;
+                                    }
+                                };
 
                                 let res = client
-                                    .issues(serde_json::from_value(serde_json::to_value(item.item_filter.filter_data).unwrap()).unwrap(), 
-                                        cursor
-                                    ).await;
+                                    .view_issues(&item.item_filter, cursor)
+                                    .await;
 
                                 let mut view_panel_data_lock = item.table_data.lock().unwrap();
                                 let mut cursor_handle = item.cursor.lock().unwrap();
@@ -497,17 +862,24 @@ impl<'a> App<'a> {
 
                                 if let Ok(Some(x)) = res {
 
-                                    let issues: Vec<Issue> = x.issues.nodes;
+                                    let mut issues: Vec<Issue> = x.issues.nodes;
+                                    item.order.lock().unwrap().apply(&mut issues);
 
                                     *view_panel_data_lock = issues;
+                                    *item.refreshed_at.lock().unwrap() = Instant::now();
 
                                     *cursor_handle = Some(GraphQLCursor{
-                                        platform: Platform::Linear,
+                                        platform: client.platform(),
                                         has_next_page: x.issues.page_info.has_next_page,
                                         end_cursor: x.issues.page_info.end_cursor
                                     });
 
                                     item.loading.store(false, Ordering::Relaxed);
+                                } else {
+                                    item.loading.store(false, Ordering::Relaxed);
+                                    if let Err(err) = res {
+                                        notifications.error(&format!("Loading '{}'", item.item_filter.name), &err);
+                                    }
                                 }
                                 debug!(
                                     "New dashboard_view_panel.issue_table_data: {:?}",
@@ -517,14 +889,11 @@ impl<'a> App<'a> {
                         })
                         .collect();
 
-                    // await the tasks for resolve's to complete and give back our items
-                    let mut items = vec![];
+                    // await the tasks for resolve's to complete
                     for task in tasks {
-                        items.push(task.await.expect("LoadViewIssues failed"));
-                    }
-                    // verify that we've got the results
-                    for item in &items {
-                        info!("LoadViewIssues Result: {:?}", item);
+                        if let Err(err) = task.await {
+                            notifications.error("Loading View Panel", &err);
+                        }
                     }
                 });
             }
@@ -541,9 +910,7 @@ impl<'a> App<'a> {
                 // Set ViewPanel loading state to true
                 is_loading.store(true, Ordering::Relaxed);
 
-                let linear_client_handle = self.linear_client.clone();
-
-                // let linear_config_lock = self.linear_client.config.lock().unwrap();
+                // let linear_config_lock = self.backend.config.lock().unwrap();
                 // let linear_config = linear_config_lock.clone();
                 // drop(linear_config_lock);
 
@@ -568,13 +935,29 @@ impl<'a> App<'a> {
                     .loading
                     .clone();
 
+                let order_handle = view_panel_list_handle[self.view_panel_to_paginate]
+                    .order
+                    .clone();
+
+                let refreshed_at_handle = view_panel_list_handle[self.view_panel_to_paginate]
+                    .refreshed_at
+                    .clone();
+
                 drop(cursor_lock);
                 drop(view_panel_list_handle);
 
+                let profile = self.panel_profile(&view_panel_view_obj);
+                let backend_handle = self.profile_backend(&profile);
+                let notifications = self.notification_center.notifications.clone();
+
+                // Loading the first page, e.g. after a manual refresh, restarts the auto-refresh interval
+                let first_page = cursor.is_none();
+
                 let _t1 = tokio::spawn(async move {
-                    let res = if let Some(linear_client) = &*linear_client_handle.lock().await {
-                        linear_client.issues(serde_json::from_value(serde_json::to_value(view_panel_view_obj.filter_data).unwrap()).unwrap(), cursor).await
+                    let (res, platform) = if let Some(backend) = backend::current(&backend_handle).await {
+                        (backend.view_issues(&view_panel_view_obj, cursor).await, backend.platform())
                     } else {
+                        loading_handle.store(false, Ordering::Relaxed);
                         return;
                     };
 
@@ -586,13 +969,22 @@ impl<'a> App<'a> {
                     if let Ok(Some(x)) = res {
                         let mut issues: Vec<Issue> = x.issues.nodes;
                         current_view_issues.append(&mut issues);
+                        order_handle.lock().unwrap().apply(&mut current_view_issues);
                         *view_panel_data_lock = current_view_issues.clone();
+                        if first_page {
+                            *refreshed_at_handle.lock().unwrap() = Instant::now();
+                        }
                         *cursor = Some(GraphQLCursor{
-                            platform: Platform::Linear,
+                            platform,
                             has_next_page: x.issues.page_info.has_next_page,
                             end_cursor: x.issues.page_info.end_cursor
                         });
                         loading_handle.store(false, Ordering::Relaxed);
+                    } else {
+                        loading_handle.store(false, Ordering::Relaxed);
+                        if let Err(err) = res {
+                            notifications.error(&format!("Loading more of '{}'", view_panel_view_obj.name), &err);
+                        }
                     }
                     info!(
                         "New dashboard_view_panel.issue_table_data: {:?}",
@@ -614,26 +1006,40 @@ impl<'a> App<'a> {
                         None => return,
                     };
 
-                // If current_op is ModifyTitle, return since no data needs to be loaded
-                if current_op == IssueModificationOp::Title {
+                // If current_op is ModifyTitle or DueDate, return since no data needs to be loaded
+                if current_op == IssueModificationOp::Title || current_op == IssueModificationOp::DueDate {
+                    return;
+                }
+
+                // Priorities are fixed, no data needs to be fetched
+                if current_op == IssueModificationOp::Priority {
+                    self.linear_issue_op_interface.obj_data.lock().unwrap().priorities = ScaleValue::priorities();
                     return;
                 }
 
+                // Get the team to load field options for:
+                //     if creating an issue, the team selected in the form (not required to select a team)
+                //     otherwise, the selected Issue's team
+                let selected_team: String = if self.creating_issue {
+                    match &self.linear_issue_create_form.team {
+                        Some(team) => team.id.clone(),
+                        None if current_op == IssueModificationOp::Team => String::new(),
+                        None => return,
+                    }
+                } else {
+                    // Check that an Issue is selected, if not return
+                    match fetch_selected_view_panel_issue(self) {
+                        Some(x) => x.team.id,
+                        None => return,
+                    }
+                };
+
                 // Set Loading 'true' before fetch
                 op_interface_loading_handle.store(true, Ordering::Relaxed);
 
                 let issue_op_data_handle = self.linear_issue_op_interface.obj_data.clone();
 
-                let linear_client_handle = self.linear_client.clone();
-
-                // Check that an Issue is selected, if not return
-                let selected_issue = match fetch_selected_view_panel_issue(self) {
-                    Some(x) => x,
-                    None => return,
-                };
-
-                // Get the Issue's team,
-                let selected_team = selected_issue.team.id;
+                let backend_handle = self.selected_issue_backend();
 
                 // Get Cursor
                 let issue_op_cursor_lock = self.linear_issue_op_interface.cursor.lock().unwrap();
@@ -641,10 +1047,11 @@ impl<'a> App<'a> {
                 drop(issue_op_cursor_lock);
 
                 let issue_op_cursor_handle = self.linear_issue_op_interface.cursor.clone();
+                let notifications = self.notification_center.notifications.clone();
 
                 let _t1 = tokio::spawn(async move {
 
-                    let res = if let Some(client) = &*linear_client_handle.lock().await {
+                    let res = if let Some(client) = backend::current(&backend_handle).await {
                         match current_op {
                             IssueModificationOp::Cycle => {
                                 IssueFieldResponse::Cycles(client.team_cycles(&selected_team, Some(issue_op_cursor)).await)
@@ -657,10 +1064,23 @@ impl<'a> App<'a> {
                             },
                             IssueModificationOp::WorkflowState => {
                                 IssueFieldResponse::States(client.team_states(&selected_team, Some(issue_op_cursor)).await)
+                            },
+                            IssueModificationOp::Team => {
+                                IssueFieldResponse::Teams(client.teams(Some(issue_op_cursor)).await)
+                            },
+                            IssueModificationOp::Labels => {
+                                IssueFieldResponse::Labels(client.team_labels(&selected_team, Some(issue_op_cursor)).await)
+                            },
+                            IssueModificationOp::Estimate => {
+                                IssueFieldResponse::Estimation(client.team_estimation(&selected_team).await)
+                            },
+                            _ => {
+                                op_interface_loading_handle.store(false, Ordering::Relaxed);
+                                return;
                             }
-                            _ => {panic!("Unsupported op!")}
                         }
                     } else {
+                        op_interface_loading_handle.store(false, Ordering::Relaxed);
                         return;
                     };
 
@@ -732,9 +1152,48 @@ impl<'a> App<'a> {
                                 end_cursor: states_resp.workflow_states.page_info.end_cursor
                             }
                         }
-                        _ => {
-                            // TODO: Improve message
-                            util::error_panic!("IssueFieldResponse Error");
+                        IssueFieldResponse::Teams(Ok(Some(teams_resp))) => {
+                            issue_op_data_lock.teams.append(
+                                &mut teams_resp
+                                    .teams
+                                    .nodes
+                                    .into_iter()
+                                    .collect(),
+                            );
+                            *issue_op_cursor_data_lock = GraphQLCursor{
+                                platform: Platform::Linear,
+                                has_next_page: teams_resp.teams.page_info.has_next_page,
+                                end_cursor: teams_resp.teams.page_info.end_cursor
+                            }
+                        }
+                        IssueFieldResponse::Labels(Ok(Some(labels_resp))) => {
+                            issue_op_data_lock.labels.append(
+                                &mut labels_resp
+                                    .issue_labels
+                                    .nodes
+                                    .into_iter()
+                                    .collect(),
+                            );
+                            *issue_op_cursor_data_lock = GraphQLCursor{
+                                platform: Platform::Linear,
+                                has_next_page: labels_resp.issue_labels.page_info.has_next_page,
+                                end_cursor: labels_resp.issue_labels.page_info.end_cursor
+                            }
+                        }
+                        // Estimate scale is not paginated
+                        IssueFieldResponse::Estimation(Ok(Some(estimation_resp))) => {
+                            issue_op_data_lock.estimates = ScaleValue::estimates(
+                                &estimation_resp.team.issue_estimation_type,
+                                estimation_resp.team.issue_estimation_allow_zero,
+                                estimation_resp.team.issue_estimation_extended,
+                            );
+                        }
+                        res => {
+                            let context = format!("Loading {:?} options", current_op);
+                            match res.error() {
+                                Some(err) => notifications.error(&context, err),
+                                None => notifications.error(&context, &"no data returned"),
+                            }
                         }
                     }
                 });
@@ -746,6 +1205,8 @@ impl<'a> App<'a> {
                 let issue_id: String;
                 let selected_issue_opt = fetch_selected_view_panel_issue(self);
                 let issue_obj = if let Some(x) = selected_issue_opt { x } else { return; };
+                // Kept to undo the modification
+                let issue_before = issue_obj.clone();
                 issue_id = issue_obj.id;
 
                 let current_op: IssueModificationOp =
@@ -754,6 +1215,12 @@ impl<'a> App<'a> {
                         None => return,
                     };
 
+                // Labels toggled in the multi-select IssueModificationOp::Labels table
+                let selected_labels = self.linear_issue_op_interface.selected_labels();
+
+                // Used to select a valid workflow state when moving the issue to another team
+                let current_state = issue_obj.state;
+
                 let mut issue_update = IssueUpdateInput {
                     title: None,
                     description: None,
@@ -779,12 +1246,30 @@ impl<'a> App<'a> {
                     snoozed_by_id: None,
                 };
 
+                // Fields to send as null
+                let mut cleared_fields: Vec<IssueModificationOp> = Vec::new();
+
                 // TODO: Prevent sending update query if nothing selected
                 match current_op {
                     IssueModificationOp::Title => {
                         issue_update.title = Some(self.title_entry.input.input.clone());
                         selected_value_id = self.title_entry.input.input.clone();
                     }
+                    // An empty value removes the due date
+                    IssueModificationOp::DueDate => {
+                        if self.due_date_picker.cleared {
+                            cleared_fields.push(IssueModificationOp::DueDate);
+                            selected_value_id = String::new();
+                        } else {
+                            issue_update.due_date = Some(self.due_date_picker.selected_timeless_date());
+                            selected_value_id = self.due_date_picker.selected_timeless_date();
+                        }
+                    }
+                    IssueModificationOp::Labels => {
+                        let label_ids: Vec<String> = selected_labels.iter().map(|label| label.id.clone()).collect();
+                        selected_value_id = label_ids.join(",");
+                        issue_update.label_ids = Some(label_ids);
+                    }
                     _ => match fetch_selected_value(self) {
                         Some(obj) => match obj {
                             IssueFieldObject::State(state) => {
@@ -803,6 +1288,22 @@ impl<'a> App<'a> {
                                 issue_update.cycle_id = Some(cycle.id.clone());
                                 selected_value_id = cycle.id.clone();
                             }
+                            IssueFieldObject::Team(team) => {
+                                issue_update.team_id = Some(team.id.clone());
+                                selected_value_id = team.id.clone();
+                            }
+                            IssueFieldObject::Priority(priority) => {
+                                issue_update.priority = Some(priority.value);
+                                selected_value_id = priority.value.to_string();
+                            }
+                            IssueFieldObject::Estimate(estimate) => {
+                                issue_update.estimate = Some(estimate.value);
+                                selected_value_id = estimate.value.to_string();
+                            }
+                            // Labels are multi-select
+                            IssueFieldObject::Label(_) => {
+                                return;
+                            }
                         },
                         _ => {
                             return;
@@ -810,14 +1311,22 @@ impl<'a> App<'a> {
                     },
                 }
 
-                let linear_client_handle = self.linear_client.clone();
+                let profile = self.selected_issue_profile();
+                let backend_handle = self.profile_backend(&profile);
 
                 let view_panel_list_arc = self.linear_dashboard_view_panel_list.clone();
+                let search_results_arc = self.issue_search.issue_table_data.clone();
+                let notifications = self.notification_center.notifications.clone();
+                let issue_history = self.issue_history_log.history.clone();
 
                 // Spawn task to issue command to update issue
                 let _t3 = tokio::spawn(async move {
-                    let res = if let Some(client) = &*linear_client_handle.lock().await {
-                        client.update_issue(&issue_id, issue_update).await
+                    let res = if let Some(client) = backend::current(&backend_handle).await {
+                        // The issue's workflow state must belong to the destination team
+                        if let Some(team_id) = &issue_update.team_id {
+                            issue_update.state_id = client.destination_team_state(team_id, &current_state.id, &current_state.type_).await;
+                        }
+                        client.update_issue_clearing(&issue_id, issue_update, &cleared_fields).await
                     } else {
                         return;
                     };
@@ -825,45 +1334,659 @@ impl<'a> App<'a> {
                     // then can match linear_issue_display.issue_table_data using selected_issue["id"]
                     // and update linear_issue_display.issue_table_data[x]["state"] with selected_workflow_state
 
-                    if let Ok(Some(_query_response)) = res {
-                        // If update succeeded, iterate over all Issues in all ViewPanels
-                        // and set issue["state" | "assignee" | ...] = state_obj
-                        //     where id matches 'issue_id'
-
-                        let view_panel_list_handle = view_panel_list_arc.lock().unwrap();
-                        for view_panel in view_panel_list_handle.iter() {
-                            // Iterate over ViewPanel Issues
-                            let mut issue_list_handle = view_panel.issue_table_data.lock().unwrap();
-
-                            for issue_obj in issue_list_handle.iter_mut() {
-                                if issue_obj.id == issue_id {
-                                    match current_op {
-                                        IssueModificationOp::Title => {
-                                            issue_obj.title = selected_value_id.clone()
-                                        }
-                                        _ => {
-                                            if let Some(issue_field_obj) = &issue_obj_opt {
-                                                match issue_field_obj {
-                                                    IssueFieldObject::State(state) => {
-                                                        issue_obj.state = serde_json::from_value(serde_json::to_value(state.clone()).unwrap()).unwrap();
-                                                    }
-                                                    IssueFieldObject::TeamMember(assignee) => {
-                                                        issue_obj.assignee = serde_json::from_value(serde_json::to_value(assignee.clone()).unwrap()).unwrap();
-                                                    }
-                                                    IssueFieldObject::Project(project) => {
-                                                        issue_obj.project = serde_json::from_value(serde_json::to_value(project.clone()).unwrap()).unwrap();
-                                                    }
-                                                    IssueFieldObject::Cycle(cycle) => {
-                                                        issue_obj.cycle = serde_json::from_value(serde_json::to_value(cycle.clone()).unwrap()).unwrap();
+                    let query_response = match res {
+                        Ok(Some(query_response)) if query_response.issue_update.success => query_response,
+                        Err(err) => {
+                            notifications.error("Updating Issue", &err);
+                            return;
+                        }
+                        _ => {
+                            notifications.error("Updating Issue", &"issueUpdate was not successful");
+                            return;
+                        }
+                    };
+
+                    // After a team change, the issue's team-scoped fields (state, cycle, project) are taken from the response
+                    let updated_issue = query_response.issue_update.issue;
+                    if current_op == IssueModificationOp::Team && updated_issue.is_none() {
+                        notifications.error("Updating Issue", &"issueUpdate returned no issue for the team move");
+                        return;
+                    }
+
+                    // If update succeeded, iterate over all Issues in all ViewPanels
+                    // and set issue["state" | "assignee" | ...] = state_obj
+                    //     where id matches 'issue_id'
+
+                    // Search results may include the Issue as well
+                    let view_panel_list_handle = view_panel_list_arc.lock().unwrap();
+                    for view_panel in view_panel_list_handle.iter() {
+                        view_panel.mark_modified(&issue_id);
+                    }
+                    let issue_lists: Vec<Arc<Mutex<Vec<Issue>>>> = view_panel_list_handle
+                        .iter()
+                        .map(|view_panel| view_panel.issue_table_data.clone())
+                        .chain(std::iter::once(search_results_arc))
+                        .collect();
+                    drop(view_panel_list_handle);
+
+                    // The Issue as patched, to redo the modification
+                    let mut issue_after: Option<Issue> = None;
+
+                    for issue_list in issue_lists.iter() {
+                        // Iterate over ViewPanel Issues
+                        let mut issue_list_handle = issue_list.lock().unwrap();
+
+                        for issue_obj in issue_list_handle.iter_mut() {
+                            if issue_obj.id == issue_id {
+                                match current_op {
+                                    IssueModificationOp::Title => {
+                                        issue_obj.title = selected_value_id.clone()
+                                    }
+                                    IssueModificationOp::DueDate => {
+                                        issue_obj.due_date = Some(selected_value_id.clone()).filter(|x| !x.is_empty());
+                                    }
+                                    IssueModificationOp::Labels => {
+                                        issue_obj.labels = IssueLabelConnection { nodes: selected_labels.iter().cloned().map(Into::into).collect() };
+                                    }
+                                    _ => {
+                                        if let Some(issue_field_obj) = &issue_obj_opt {
+                                            match issue_field_obj {
+                                                IssueFieldObject::State(state) => {
+                                                    issue_obj.state = serde_json::from_value(serde_json::to_value(state.clone()).unwrap()).unwrap();
+                                                }
+                                                IssueFieldObject::TeamMember(assignee) => {
+                                                    issue_obj.assignee = serde_json::from_value(serde_json::to_value(assignee.clone()).unwrap()).unwrap();
+                                                }
+                                                IssueFieldObject::Project(project) => {
+                                                    issue_obj.project = serde_json::from_value(serde_json::to_value(project.clone()).unwrap()).unwrap();
+                                                }
+                                                IssueFieldObject::Cycle(cycle) => {
+                                                    issue_obj.cycle = serde_json::from_value(serde_json::to_value(cycle.clone()).unwrap()).unwrap();
+                                                }
+                                                IssueFieldObject::Team(_) => {
+                                                    if let Some(updated_issue) = &updated_issue {
+                                                        issue_obj.team = updated_issue.team.clone().into();
+                                                        issue_obj.state = updated_issue.state.clone().into();
+                                                        issue_obj.cycle = updated_issue.cycle.clone().map(Into::into);
+                                                        issue_obj.project = updated_issue.project.clone().map(Into::into);
                                                     }
                                                 }
+                                                IssueFieldObject::Priority(priority) => {
+                                                    issue_obj.priority = priority.value as _;
+                                                }
+                                                IssueFieldObject::Estimate(estimate) => {
+                                                    issue_obj.estimate = Some(estimate.value as _);
+                                                }
+                                                IssueFieldObject::Label(_) => {}
                                             }
                                         }
                                     }
                                 }
+                                if issue_after.is_none() {
+                                    issue_after = Some(issue_obj.clone());
+                                }
+                            }
+                        }
+                    }
+
+                    if let Some(issue_after) = issue_after {
+                        issue_history.record(IssueModification {
+                            issue_id,
+                            profile,
+                            op: current_op,
+                            before: issue_before,
+                            after: issue_after,
+                            time: chrono::Local::now(),
+                        });
+                    }
+                });
+            }
+            AppEvent::CreateIssue => {
+                let issue_create = match self.linear_issue_create_form.validated_create_input() {
+                    Some(x) => x,
+                    None => return,
+                };
+                if !backend::supports_create(&self.selected_issue_platform()) {
+                    return;
+                }
+
+                // Created in the selected View Panel's workspace
+                let profile = self.selected_issue_profile();
+                let backend_handle = self.profile_backend(&profile);
+
+                // Collect each ViewPanel's Custom View & Issues in the same workspace, to insert the new Issue into
+                let view_panel_list_lock = self.linear_dashboard_view_panel_list.lock().unwrap();
+                let view_panels: Vec<(CustomView, Arc<Mutex<Vec<Issue>>>)> = view_panel_list_lock
+                    .iter()
+                    .filter(|e| self.panel_profile(&e.view) == profile)
+                    .map(|e| (e.view.clone(), e.issue_table_data.clone()))
+                    .collect();
+                drop(view_panel_list_lock);
+
+                let notifications = self.notification_center.notifications.clone();
+
+                let _t1 = tokio::spawn(async move {
+                    let client = if let Some(client) = backend::current(&backend_handle).await { client } else { return; };
+
+                    let res = client.create_issue(issue_create).await;
+
+                    // IssueCreateMut selects the same Issue fields as IssuesQuery
+                    let created_issue: Issue = match res {
+                        Ok(Some(resp)) if resp.issue_create.success => {
+                            match resp.issue_create.issue.map(|issue| serde_json::from_value(serde_json::to_value(issue).unwrap())) {
+                                Some(Ok(issue)) => issue,
+                                Some(Err(err)) => {
+                                    notifications.error("Reading created Issue", &err);
+                                    return;
+                                }
+                                None => {
+                                    notifications.error("Reading created Issue", &"no Issue returned");
+                                    return;
+                                }
+                            }
+                        },
+                        Err(err) => {
+                            notifications.error("Creating Issue", &err);
+                            return;
+                        }
+                        _ => {
+                            notifications.error("Creating Issue", &"issueCreate was not successful");
+                            return;
+                        }
+                    };
+
+                    info!("AppEvent::CreateIssue - created Issue: {:?}", created_issue.id);
+
+                    // Insert the Issue into each ViewPanel whose Custom View includes it
+                    for (view, issue_list_handle) in view_panels.into_iter() {
+                        match client.view_contains_issue(&view, &created_issue.id).await {
+                            Ok(true) => {
+                                let mut issue_list_lock = issue_list_handle.lock().unwrap();
+                                if !issue_list_lock.iter().any(|e| e.id == created_issue.id) {
+                                    issue_list_lock.insert(0, created_issue.clone());
+                                }
+                            },
+                            Ok(false) => {},
+                            Err(err) => {
+                                error!("AppEvent::CreateIssue - view_contains_issue '{}' failed: {:?}", view.id, err);
+                            },
+                        }
+                    }
+                });
+            },
+            AppEvent::LoadIssueComments => {
+                let loading_handle = self.issue_comments_pane.loading.clone();
+
+                // If already loading, don't try again
+                if loading_handle.load(Ordering::Relaxed) {
+                    return;
+                }
+
+                let issue_id: String = match &*self.issue_comments_pane.issue_id.lock().unwrap() {
+                    Some(x) => x.clone(),
+                    None => return,
+                };
+
+                let cursor: GraphQLCursor = self.issue_comments_pane.cursor.lock().unwrap().clone();
+                // Nothing left to load
+                if cursor.end_cursor.is_some() && !cursor.has_next_page {
+                    return;
+                }
+
+                let backend_handle = self.selected_issue_backend();
+                let pane_issue_id_handle = self.issue_comments_pane.issue_id.clone();
+                let comments_handle = self.issue_comments_pane.comments.clone();
+                let cursor_handle = self.issue_comments_pane.cursor.clone();
+                let notifications = self.notification_center.notifications.clone();
+
+                loading_handle.store(true, Ordering::Relaxed);
+
+                let _t1 = tokio::spawn(async move {
+                    let res = match backend::current(&backend_handle).await {
+                        Some(client) => client.issue_comments(&issue_id, Some(cursor)).await,
+                        None => {
+                            loading_handle.store(false, Ordering::Relaxed);
+                            return;
+                        }
+                    };
+
+                    // Discard page if a different issue was expanded in the meantime
+                    if pane_issue_id_handle.lock().unwrap().as_deref() == Some(issue_id.as_str()) {
+                        match res {
+                            Ok(Some(data)) => {
+                                let comments = data.issue.comments;
+                                comments_handle.lock().unwrap().extend(comments.nodes);
+                                *cursor_handle.lock().unwrap() = GraphQLCursor {
+                                    platform: Platform::Linear,
+                                    has_next_page: comments.page_info.has_next_page,
+                                    end_cursor: comments.page_info.end_cursor
+                                };
+                            },
+                            Ok(None) => {},
+                            Err(err) => {
+                                notifications.error("Loading comments", &err);
                             }
                         }
                     }
+
+                    loading_handle.store(false, Ordering::Relaxed);
+                });
+            },
+            AppEvent::SearchIssues => {
+                let loading_handle = self.issue_search.loading.clone();
+
+                // If already loading a page of the current query, don't try again
+                if loading_handle.load(Ordering::Relaxed) {
+                    return;
+                }
+
+                let query: String = self.issue_search.query.lock().unwrap().clone();
+                if query.is_empty() {
+                    return;
+                }
+
+                let cursor: GraphQLCursor = self.issue_search.cursor.lock().unwrap().clone();
+                // Nothing left to load
+                if cursor.end_cursor.is_some() && !cursor.has_next_page {
+                    return;
+                }
+
+                let backend_handle = self.backend.clone();
+                let generation_handle = self.issue_search.generation.clone();
+                let generation = generation_handle.load(Ordering::Relaxed);
+                let issue_table_data_handle = self.issue_search.issue_table_data.clone();
+                let cursor_handle = self.issue_search.cursor.clone();
+                let notifications = self.notification_center.notifications.clone();
+
+                loading_handle.store(true, Ordering::Relaxed);
+
+                let _t1 = tokio::spawn(async move {
+                    let res = match backend::current(&backend_handle).await {
+                        Some(client) => client.issue_search(&query, Some(cursor)).await,
+                        None => {
+                            loading_handle.store(false, Ordering::Relaxed);
+                            return;
+                        }
+                    };
+
+                    // Discard page if a new query was submitted in the meantime,
+                    // 'loading' then belongs to the new query's request
+                    // Checked under the results lock, which submitting a query clears the results with
+                    let mut issue_table_data_lock = issue_table_data_handle.lock().unwrap();
+                    if generation_handle.load(Ordering::Relaxed) != generation {
+                        return;
+                    }
+
+                    match res {
+                        Ok(Some(data)) => {
+                            // IssueSearchQuery selects the same Issue fields as IssuesQuery
+                            let issues: Vec<Issue> = data.issue_search.nodes
+                                .into_iter()
+                                .filter_map(|issue| serde_json::from_value(serde_json::to_value(issue).ok()?).ok())
+                                .collect();
+
+                            issue_table_data_lock.extend(issues);
+                            *cursor_handle.lock().unwrap() = GraphQLCursor {
+                                platform: Platform::Linear,
+                                has_next_page: data.issue_search.page_info.has_next_page,
+                                end_cursor: data.issue_search.page_info.end_cursor
+                            };
+                        },
+                        Ok(None) => {},
+                        Err(err) => {
+                            notifications.error("Searching Issues", &err);
+                        }
+                    }
+
+                    loading_handle.store(false, Ordering::Relaxed);
+                });
+            },
+            AppEvent::ExportViewPanel => {
+                // If already exporting, don't start another export
+                if self.panel_export_prompt.is_exporting() {
+                    return;
+                }
+
+                let (path, format) = match self.panel_export_prompt.target.clone() {
+                    Some(x) => x,
+                    None => return,
+                };
+
+                let view_panel = match self.linear_dashboard_view_panel_list.lock().unwrap().get(self.view_panel_to_export) {
+                    Some(x) => x.clone(),
+                    None => return,
+                };
+
+                let profile = self.panel_profile(&view_panel.view);
+                let backend_handle = self.profile_backend(&profile);
+                let export_state_handle = self.panel_export_prompt.state.clone();
+
+                *export_state_handle.lock().unwrap() = PanelExportState::Exporting(0);
+
+                let _t1 = tokio::spawn(async move {
+                    // Fetch all pages of the panel's view, rather than only the loaded ones
+                    let res: anyhow::Result<usize> = async {
+                        let client = backend::current(&backend_handle).await
+                            .ok_or_else(|| anyhow!("profile '{}' has no valid token", profile))?;
+
+                        let mut issues: Vec<Issue> = Vec::new();
+                        let mut cursor: Option<GraphQLCursor> = None;
+
+                        loop {
+                            let data = client.view_issues(&view_panel.view, cursor).await?
+                                .ok_or_else(|| anyhow!("no issues returned"))?;
+                            issues.extend(data.issues.nodes);
+                            *export_state_handle.lock().unwrap() = PanelExportState::Exporting(issues.len());
+
+                            if !data.issues.page_info.has_next_page {
+                                break;
+                            }
+                            cursor = Some(GraphQLCursor {
+                                platform: client.platform(),
+                                has_next_page: data.issues.page_info.has_next_page,
+                                end_cursor: data.issues.page_info.end_cursor,
+                            });
+                        }
+
+                        // Keep the panel's sort & grouping
+                        view_panel.order.lock().unwrap().apply(&mut issues);
+
+                        let text = export::render(format, &issues, &view_panel.columns)?;
+                        tokio::fs::write(&path, text).await?;
+                        Ok(issues.len())
+                    }.await;
+
+                    *export_state_handle.lock().unwrap() = match res {
+                        Ok(count) => PanelExportState::Exported(count, path),
+                        Err(err) => {
+                            error!("AppEvent::ExportViewPanel - export to {:?} failed: {:?}", path, err);
+                            PanelExportState::Failed(err.to_string())
+                        }
+                    };
+                });
+            },
+            AppEvent::AutoRefreshViewPanels => {
+                let selected_panel_idx: Option<usize> = self.linear_dashboard_view_panel_selected.and_then(|x| x.checked_sub(1));
+
+                let mut view_panel_list_lock = self.linear_dashboard_view_panel_list.lock().unwrap();
+
+                // Merge completed refreshes & webhook changes, keeping the selected issue selected
+                for (idx, view_panel) in view_panel_list_lock.iter_mut().enumerate() {
+                    let is_selected = selected_panel_idx == Some(idx);
+                    let mut selected_issue_idx = if is_selected {
+                        self.view_panel_issue_selected.as_ref().and_then(|x| x.selected())
+                    } else {
+                        None
+                    };
+
+                    let has_result = view_panel.refresh_result.lock().unwrap().is_some();
+                    if has_result {
+                        selected_issue_idx = view_panel.apply_refresh_result(selected_issue_idx);
+                    }
+                    let has_changes = !view_panel.issue_changes.lock().unwrap().is_empty();
+                    if has_changes {
+                        selected_issue_idx = view_panel.apply_issue_changes(selected_issue_idx);
+                    }
+
+                    if let (true, Some(table_state)) = (is_selected && (has_result || has_changes), self.view_panel_issue_selected.as_mut()) {
+                        if table_state.selected().is_some() {
+                            table_state.select(selected_issue_idx);
+                        }
+                    }
+                }
+
+                // Only refresh while the dashboard is shown
+                if *self.route.lock().unwrap() != Route::ActionSelect {
+                    return;
+                }
+
+                let view_panels_to_refresh: Vec<DashboardViewPanel> = view_panel_list_lock.iter()
+                    .filter(|view_panel| view_panel.refresh_due())
+                    .cloned()
+                    .collect();
+                drop(view_panel_list_lock);
+
+                for view_panel in view_panels_to_refresh.into_iter() {
+                    // Stays loading until the result is merged, so manual refreshes & pagination can't interleave
+                    view_panel.loading.store(true, Ordering::Relaxed);
+                    *view_panel.refreshed_at.lock().unwrap() = Instant::now();
+
+                    let profile = self.panel_profile(&view_panel.view);
+                    let backend_handle = self.profile_backend(&profile);
+                    let notifications = self.notification_center.notifications.clone();
+
+                    let _t1 = tokio::spawn(async move {
+                        // Fetch as many pages as are loaded, so refreshing doesn't drop loaded issues
+                        let loaded_count = view_panel.issue_table_data.lock().unwrap().len();
+
+                        let res: ClientResult<RefreshResult> = async {
+                            let client = backend::current(&backend_handle).await
+                                .ok_or_else(|| ClientError::Config(format!("profile '{}' has no valid token", profile)))?;
+
+                            let mut issues: Vec<Issue> = Vec::new();
+                            let mut cursor: Option<GraphQLCursor> = None;
+
+                            loop {
+                                let data = match client.view_issues(&view_panel.view, cursor.clone()).await? {
+                                    Some(x) => x,
+                                    None => break,
+                                };
+                                issues.extend(data.issues.nodes);
+                                cursor = Some(GraphQLCursor {
+                                    platform: client.platform(),
+                                    has_next_page: data.issues.page_info.has_next_page,
+                                    end_cursor: data.issues.page_info.end_cursor,
+                                });
+
+                                if !data.issues.page_info.has_next_page || issues.len() >= loaded_count {
+                                    break;
+                                }
+                            }
+
+                            view_panel.order.lock().unwrap().apply(&mut issues);
+                            Ok((issues, cursor))
+                        }.await;
+
+                        match res {
+                            Ok(result) => {
+                                *view_panel.refresh_result.lock().unwrap() = Some(result);
+                            },
+                            Err(err) => {
+                                view_panel.loading.store(false, Ordering::Relaxed);
+                                notifications.error(&format!("Refreshing '{}'", view_panel.view.name), &err);
+                            }
+                        }
+                    });
+                }
+            },
+            AppEvent::ApplyWebhookEvents => {
+                let webhook_events = self.webhook_events.drain();
+                if webhook_events.is_empty() {
+                    return;
+                }
+
+                let view_panels: Vec<DashboardViewPanel> = self.linear_dashboard_view_panel_list.lock().unwrap().clone();
+                let profile_viewers = self.profile_viewers.lock().unwrap().clone();
+
+                for view_panel in view_panels.iter() {
+                    // Only Linear panels of the issue's organization, if the profile's organization is known
+                    let profile = self.panel_profile(&view_panel.view);
+                    let is_linear = self.profile_platform(&profile) == Platform::Linear;
+                    let organization_id = profile_viewers.get(&profile).map(|viewer| viewer.organization.id.clone());
+
+                    let mut issue_ids: Vec<String> = Vec::new();
+                    for event in webhook_events.iter() {
+                        // Removed issues are dropped from every panel without a request
+                        if event.action == WebhookAction::Remove {
+                            view_panel.issue_changes.lock().unwrap().push((event.issue_id.clone(), None));
+                            continue;
+                        }
+
+                        let same_organization = match (&event.organization_id, &organization_id) {
+                            (Some(event_org), Some(panel_org)) => event_org == panel_org,
+                            _ => true,
+                        };
+                        if is_linear && same_organization && !issue_ids.contains(&event.issue_id) {
+                            issue_ids.push(event.issue_id.clone());
+                        }
+                    }
+                    if issue_ids.is_empty() {
+                        continue;
+                    }
+
+                    let backend_handle = self.profile_backend(&profile);
+                    let view = view_panel.view.clone();
+                    let issue_changes_handle = view_panel.issue_changes.clone();
+                    let notifications = self.notification_center.notifications.clone();
+
+                    // Fetched through the view's filters in one request per panel, to decide which issues the panel still includes
+                    let _t1 = tokio::spawn(async move {
+                        let res = match backend::current(&backend_handle).await {
+                            Some(client) => client.view_issues_by_id(&view, &issue_ids).await,
+                            None => return,
+                        };
+
+                        match res {
+                            Ok(issues) => {
+                                let mut issue_changes_lock = issue_changes_handle.lock().unwrap();
+                                for issue_id in issue_ids.into_iter() {
+                                    let issue = issues.iter().find(|issue| issue.id == issue_id).cloned();
+                                    issue_changes_lock.push((issue_id, issue));
+                                }
+                            },
+                            Err(err) => notifications.error(&format!("Applying webhook to '{}'", view.name), &err),
+                        }
+                    });
+                }
+            },
+            // Restore the modified field(s) of the most recently modified (or undone) Issue
+            AppEvent::UndoIssueModification | AppEvent::RedoIssueModification => {
+                let undo = matches!(event, AppEvent::UndoIssueModification);
+                let issue_history = self.issue_history_log.history.clone();
+                let notifications = self.notification_center.notifications.clone();
+
+                // Wait for the previous undo or redo to apply
+                if issue_history.is_pending() {
+                    return;
+                }
+
+                let modification = match if undo { issue_history.pop_undo() } else { issue_history.pop_redo() } {
+                    Some(x) => x,
+                    None => {
+                        notifications.info(if undo { "Nothing to undo" } else { "Nothing to redo" });
+                        return;
+                    }
+                };
+
+                // Undo restores the Issue as it was before the modification, redo as it was after
+                let snapshot: Issue = if undo { modification.before.clone() } else { modification.after.clone() };
+                let (issue_update, cleared) = issue_history::restore_input(&snapshot, modification.op);
+
+                let backend_handle = self.profile_backend(&modification.profile);
+                let view_panel_list_arc = self.linear_dashboard_view_panel_list.clone();
+                let search_results_arc = self.issue_search.issue_table_data.clone();
+
+                issue_history.pending.store(true, Ordering::Relaxed);
+
+                let _t1 = tokio::spawn(async move {
+                    let res = match backend::current(&backend_handle).await {
+                        Some(client) => client.update_issue_clearing(&modification.issue_id, issue_update, &cleared).await,
+                        None => Err(ClientError::Config(format!("profile '{}' has no valid token", modification.profile))),
+                    };
+
+                    let action = if undo { "Undo" } else { "Redo" };
+                    match res {
+                        Ok(Some(resp)) if resp.issue_update.success => {
+                            let view_panel_list_handle = view_panel_list_arc.lock().unwrap();
+                            for view_panel in view_panel_list_handle.iter() {
+                                view_panel.mark_modified(&modification.issue_id);
+                            }
+                            let issue_lists: Vec<Arc<Mutex<Vec<Issue>>>> = view_panel_list_handle
+                                .iter()
+                                .map(|view_panel| view_panel.issue_table_data.clone())
+                                .chain(std::iter::once(search_results_arc))
+                                .collect();
+                            drop(view_panel_list_handle);
+
+                            for issue_list in issue_lists.iter() {
+                                for issue_obj in issue_list.lock().unwrap().iter_mut().filter(|x| x.id == modification.issue_id) {
+                                    issue_history::restore_fields(issue_obj, &snapshot, modification.op);
+                                }
+                            }
+
+                            notifications.info(format!("{}: {}", action, modification.describe()));
+                            if undo {
+                                issue_history.push_redo(modification);
+                            } else {
+                                issue_history.push_undo(modification);
+                            }
+                        },
+                        res => {
+                            let context = format!("{} {:?} of #{}", action, modification.op, modification.before.number);
+                            match res {
+                                Err(err) => notifications.error(&context, &err),
+                                _ => notifications.error(&context, &"issueUpdate was not successful"),
+                            }
+                            // Kept, to retry
+                            if undo {
+                                issue_history.push_undo(modification);
+                            } else {
+                                issue_history.push_redo(modification);
+                            }
+                        }
+                    }
+
+                    issue_history.pending.store(false, Ordering::Relaxed);
+                });
+            },
+            AppEvent::CreateComment => {
+                let body: String = self.issue_comments_pane.reply_input.input.trim().to_string();
+                if body.is_empty() {
+                    return;
+                }
+
+                let issue_id: String = match &*self.issue_comments_pane.issue_id.lock().unwrap() {
+                    Some(x) => x.clone(),
+                    None => return,
+                };
+
+                self.issue_comments_pane.reply_input.set_input(String::new());
+
+                let backend_handle = self.selected_issue_backend();
+                let pane_issue_id_handle = self.issue_comments_pane.issue_id.clone();
+                let comments_handle = self.issue_comments_pane.comments.clone();
+                let cursor_handle = self.issue_comments_pane.cursor.clone();
+                let notifications = self.notification_center.notifications.clone();
+
+                let _t1 = tokio::spawn(async move {
+                    let client = if let Some(client) = backend::current(&backend_handle).await { client } else { return; };
+
+                    match client.create_comment(&issue_id, &body).await {
+                        Ok(Some(resp)) if resp.comment_create.success => {
+                            info!("AppEvent::CreateComment - created Comment: {:?}", resp.comment_create.comment.id);
+                        },
+                        Err(err) => {
+                            notifications.error("Creating comment", &err);
+                            return;
+                        }
+                        _ => {
+                            notifications.error("Creating comment", &"commentCreate was not successful");
+                            return;
+                        }
+                    };
+
+                    // Reload the thread so the new comment is shown in order
+                    if let Ok(Some(data)) = client.issue_comments(&issue_id, None).await {
+                        if pane_issue_id_handle.lock().unwrap().as_deref() == Some(issue_id.as_str()) {
+                            let comments = data.issue.comments;
+                            *comments_handle.lock().unwrap() = comments.nodes;
+                            *cursor_handle.lock().unwrap() = GraphQLCursor {
+                                platform: Platform::Linear,
+                                has_next_page: comments.page_info.has_next_page,
+                                end_cursor: comments.page_info.end_cursor
+                            };
+                        }
+                    }
                 });
             }
         }
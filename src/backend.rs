@@ -0,0 +1,230 @@
+// Issue backends a profile's View Panels are loaded & modified with.
+// Responses use the Linear schema types, other platforms convert into them.
+
+use std::sync::Arc;
+
+use futures::future::BoxFuture;
+use tokio::sync::Mutex as tMutex;
+
+use crate::app::Platform;
+use crate::config::ConfigFile;
+use crate::error::{ ClientError, ClientResult };
+use crate::constants::IssueModificationOp;
+
+use crate::github::{ GithubClient, GithubConfig };
+
+use crate::linear::{
+    client::LinearClient,
+    schema::{
+        CustomView,
+        CustomViewResponseData,
+        Issue,
+        IssuesResponseData,
+        CyclesResponseData,
+        ProjectsResponseData,
+        TeamMembersResponseData,
+        StatesResponseData,
+        TeamsResponseData,
+        TeamLabelsResponseData,
+        TeamEstimationResponseData,
+        IssueSearchResponseData,
+        IssueDescriptionResponseData,
+        IssueCommentsResponseData,
+        CommentCreateResponseData,
+        IssueCreateInput,
+        IssueCreateResponseData,
+        IssueUpdateInput,
+        IssueUpdateResponseData,
+    },
+    LinearCache,
+    LinearConfig,
+    RateLimitHandle,
+};
+
+use crate::util::GraphQLCursor;
+
+// A profile's backend, None if the profile has no valid token
+// Only locked to replace or clone the backend, so its requests run concurrently, paced by its own scheduler
+pub type BackendHandle = Arc<tMutex<Option<Arc<dyn IssueBackend>>>>;
+
+// The handle's backend, without holding its lock while requests are in flight
+pub async fn current(handle: &BackendHandle) -> Option<Arc<dyn IssueBackend>> {
+    handle.lock().await.clone()
+}
+
+fn unsupported<'a, T: Send + 'a>(platform: Platform, request: &'static str) -> BoxFuture<'a, ClientResult<T>> {
+    Box::pin(async move { Err(ClientError::Unsupported(format!("{} is not supported by {:?}", request, platform))) })
+}
+
+// Views, issues, issue field options & updates
+// Teams are the unit field options are scoped to, e.g. a repository on GitHub
+pub trait IssueBackend: Send + Sync {
+    fn platform(&self) -> Platform;
+
+    // Remaining API quota, None if the platform's quota isn't tracked
+    fn rate_limit(&self) -> Option<RateLimitHandle> {
+        None
+    }
+
+    fn custom_views(&self, cursor_opt: Option<GraphQLCursor>) -> BoxFuture<'_, ClientResult<Option<CustomViewResponseData>>>;
+    fn view_issues<'a>(&'a self, view: &'a CustomView, cursor_opt: Option<GraphQLCursor>) -> BoxFuture<'a, ClientResult<Option<IssuesResponseData>>>;
+
+    fn view_contains_issue<'a>(&'a self, _view: &'a CustomView, _issue_id: &'a str) -> BoxFuture<'a, ClientResult<bool>> {
+        Box::pin(async { Ok(false) })
+    }
+    // The issues among 'issue_ids' which match the view's filters
+    fn view_issues_by_id<'a>(&'a self, _view: &'a CustomView, _issue_ids: &'a [String]) -> BoxFuture<'a, ClientResult<Vec<Issue>>> {
+        unsupported(self.platform(), "issue lookup by id")
+    }
+
+    // Field options
+    fn team_cycles<'a>(&'a self, _team_id: &'a str, _cursor: Option<GraphQLCursor>) -> BoxFuture<'a, ClientResult<Option<CyclesResponseData>>> {
+        unsupported(self.platform(), "cycles")
+    }
+    fn team_projects<'a>(&'a self, _team_id: &'a str, _cursor: Option<GraphQLCursor>) -> BoxFuture<'a, ClientResult<Option<ProjectsResponseData>>> {
+        unsupported(self.platform(), "projects")
+    }
+    fn team_members<'a>(&'a self, team_id: &'a str, cursor: Option<GraphQLCursor>) -> BoxFuture<'a, ClientResult<Option<TeamMembersResponseData>>>;
+    fn team_states<'a>(&'a self, team_id: &'a str, cursor: Option<GraphQLCursor>) -> BoxFuture<'a, ClientResult<Option<StatesResponseData>>>;
+    fn teams(&self, _cursor: Option<GraphQLCursor>) -> BoxFuture<'_, ClientResult<Option<TeamsResponseData>>> {
+        unsupported(self.platform(), "teams")
+    }
+    fn team_labels<'a>(&'a self, team_id: &'a str, cursor: Option<GraphQLCursor>) -> BoxFuture<'a, ClientResult<Option<TeamLabelsResponseData>>>;
+    fn team_estimation<'a>(&'a self, _team_id: &'a str) -> BoxFuture<'a, ClientResult<Option<TeamEstimationResponseData>>> {
+        unsupported(self.platform(), "estimates")
+    }
+
+    // Workflow state of the destination team matching the issue's current state, None to keep the state as is
+    fn destination_team_state<'a>(&'a self, _team_id: &'a str, _state_id: &'a str, _state_type: &'a str) -> BoxFuture<'a, Option<String>> {
+        Box::pin(async { None })
+    }
+
+    fn issue_search<'a>(&'a self, _query: &'a str, _cursor: Option<GraphQLCursor>) -> BoxFuture<'a, ClientResult<Option<IssueSearchResponseData>>> {
+        unsupported(self.platform(), "issue search")
+    }
+    fn issue_description<'a>(&'a self, issue_id: &'a str) -> BoxFuture<'a, ClientResult<Option<IssueDescriptionResponseData>>>;
+    fn issue_comments<'a>(&'a self, issue_id: &'a str, cursor: Option<GraphQLCursor>) -> BoxFuture<'a, ClientResult<Option<IssueCommentsResponseData>>>;
+    fn create_comment<'a>(&'a self, issue_id: &'a str, body: &'a str) -> BoxFuture<'a, ClientResult<Option<CommentCreateResponseData>>>;
+
+    fn create_issue(&self, _input: IssueCreateInput) -> BoxFuture<'_, ClientResult<Option<IssueCreateResponseData>>> {
+        unsupported(self.platform(), "issue creation")
+    }
+    fn update_issue<'a>(&'a self, issue_id: &'a str, update: IssueUpdateInput) -> BoxFuture<'a, ClientResult<Option<IssueUpdateResponseData>>>;
+    // As update_issue, also unsetting the fields of 'cleared', e.g. to restore an unassigned Issue
+    fn update_issue_clearing<'a>(&'a self, issue_id: &'a str, update: IssueUpdateInput, cleared: &'a [IssueModificationOp]) -> BoxFuture<'a, ClientResult<Option<IssueUpdateResponseData>>> {
+        if cleared.is_empty() {
+            self.update_issue(issue_id, update)
+        } else {
+            unsupported(self.platform(), "clearing issue fields")
+        }
+    }
+}
+
+impl IssueBackend for LinearClient {
+    fn platform(&self) -> Platform {
+        Platform::Linear
+    }
+
+    fn rate_limit(&self) -> Option<RateLimitHandle> {
+        Some(self.scheduler.quota.clone())
+    }
+
+    fn custom_views(&self, cursor_opt: Option<GraphQLCursor>) -> BoxFuture<'_, ClientResult<Option<CustomViewResponseData>>> {
+        Box::pin(LinearClient::custom_views(self, cursor_opt))
+    }
+    fn view_issues<'a>(&'a self, view: &'a CustomView, cursor_opt: Option<GraphQLCursor>) -> BoxFuture<'a, ClientResult<Option<IssuesResponseData>>> {
+        Box::pin(LinearClient::view_issues(self, view, cursor_opt))
+    }
+    fn view_contains_issue<'a>(&'a self, view: &'a CustomView, issue_id: &'a str) -> BoxFuture<'a, ClientResult<bool>> {
+        Box::pin(LinearClient::view_contains_issue(self, view, issue_id))
+    }
+    fn view_issues_by_id<'a>(&'a self, view: &'a CustomView, issue_ids: &'a [String]) -> BoxFuture<'a, ClientResult<Vec<Issue>>> {
+        Box::pin(LinearClient::view_issues_by_id(self, view, issue_ids))
+    }
+
+    fn team_cycles<'a>(&'a self, team_id: &'a str, cursor: Option<GraphQLCursor>) -> BoxFuture<'a, ClientResult<Option<CyclesResponseData>>> {
+        Box::pin(LinearClient::team_cycles(self, team_id, cursor))
+    }
+    fn team_projects<'a>(&'a self, team_id: &'a str, cursor: Option<GraphQLCursor>) -> BoxFuture<'a, ClientResult<Option<ProjectsResponseData>>> {
+        Box::pin(LinearClient::team_projects(self, team_id, cursor))
+    }
+    fn team_members<'a>(&'a self, team_id: &'a str, cursor: Option<GraphQLCursor>) -> BoxFuture<'a, ClientResult<Option<TeamMembersResponseData>>> {
+        Box::pin(LinearClient::team_members(self, team_id, cursor))
+    }
+    fn team_states<'a>(&'a self, team_id: &'a str, cursor: Option<GraphQLCursor>) -> BoxFuture<'a, ClientResult<Option<StatesResponseData>>> {
+        Box::pin(LinearClient::team_states(self, team_id, cursor))
+    }
+    fn teams(&self, cursor: Option<GraphQLCursor>) -> BoxFuture<'_, ClientResult<Option<TeamsResponseData>>> {
+        Box::pin(LinearClient::teams(self, cursor))
+    }
+    fn team_labels<'a>(&'a self, team_id: &'a str, cursor: Option<GraphQLCursor>) -> BoxFuture<'a, ClientResult<Option<TeamLabelsResponseData>>> {
+        Box::pin(LinearClient::team_labels(self, team_id, cursor))
+    }
+    fn team_estimation<'a>(&'a self, team_id: &'a str) -> BoxFuture<'a, ClientResult<Option<TeamEstimationResponseData>>> {
+        Box::pin(LinearClient::team_estimation(self, team_id))
+    }
+    fn destination_team_state<'a>(&'a self, team_id: &'a str, state_id: &'a str, state_type: &'a str) -> BoxFuture<'a, Option<String>> {
+        Box::pin(LinearClient::destination_team_state(self, team_id, state_id, state_type))
+    }
+
+    fn issue_search<'a>(&'a self, query: &'a str, cursor: Option<GraphQLCursor>) -> BoxFuture<'a, ClientResult<Option<IssueSearchResponseData>>> {
+        Box::pin(LinearClient::issue_search(self, query, cursor))
+    }
+    fn issue_description<'a>(&'a self, issue_id: &'a str) -> BoxFuture<'a, ClientResult<Option<IssueDescriptionResponseData>>> {
+        Box::pin(LinearClient::issue_description(self, issue_id))
+    }
+    fn issue_comments<'a>(&'a self, issue_id: &'a str, cursor: Option<GraphQLCursor>) -> BoxFuture<'a, ClientResult<Option<IssueCommentsResponseData>>> {
+        Box::pin(LinearClient::issue_comments(self, issue_id, cursor))
+    }
+    fn create_comment<'a>(&'a self, issue_id: &'a str, body: &'a str) -> BoxFuture<'a, ClientResult<Option<CommentCreateResponseData>>> {
+        Box::pin(LinearClient::create_comment(self, issue_id, body))
+    }
+
+    fn create_issue(&self, input: IssueCreateInput) -> BoxFuture<'_, ClientResult<Option<IssueCreateResponseData>>> {
+        Box::pin(LinearClient::create_issue(self, input))
+    }
+    fn update_issue<'a>(&'a self, issue_id: &'a str, update: IssueUpdateInput) -> BoxFuture<'a, ClientResult<Option<IssueUpdateResponseData>>> {
+        Box::pin(LinearClient::update_issue(self, issue_id, update))
+    }
+    fn update_issue_clearing<'a>(&'a self, issue_id: &'a str, update: IssueUpdateInput, cleared: &'a [IssueModificationOp]) -> BoxFuture<'a, ClientResult<Option<IssueUpdateResponseData>>> {
+        Box::pin(LinearClient::update_issue_clearing(self, issue_id, update, cleared))
+    }
+}
+
+// Issue modifications each platform supports
+pub fn supports_op(platform: &Platform, op: IssueModificationOp) -> bool {
+    match platform {
+        Platform::Github => matches!(op,
+            IssueModificationOp::Title |
+            IssueModificationOp::WorkflowState |
+            IssueModificationOp::Assignee |
+            IssueModificationOp::Labels
+        ),
+        _ => true,
+    }
+}
+
+// Platforms issues can be created on
+pub fn supports_create(platform: &Platform) -> bool {
+    *platform != Platform::Github
+}
+
+// Backend of the named profile from config.toml, None if it has no valid token
+pub fn connect(profile: &str, cache: Option<LinearCache>) -> Option<Arc<dyn IssueBackend>> {
+    let config_file = ConfigFile::load().ok()?;
+    let profile_settings = config_file.profile(profile)?;
+
+    let backend: ClientResult<Arc<dyn IssueBackend>> = match profile_settings.platform() {
+        Platform::Github => GithubClient::with_config(GithubConfig::with_profile(profile_settings))
+            .map(|client| Arc::new(client) as Arc<dyn IssueBackend>),
+        _ => LinearConfig::load_profile(profile)
+            .ok_or_else(|| ClientError::Config(String::from("profile has no token")))
+            .and_then(LinearClient::with_config)
+            .map(|mut client| {
+                client.cache = cache;
+                Arc::new(client) as Arc<dyn IssueBackend>
+            }),
+    };
+
+    backend.map_err(|err| error!("backend::connect() - profile {:?}: {:?}", profile, err)).ok()
+}
@@ -0,0 +1,98 @@
+// Command line arguments
+
+use clap::Clap;
+
+#[derive(Clap, Debug)]
+#[clap(name = "rust-cli", about = "Terminal dashboards for Linear")]
+pub struct Opts {
+    /// Name of the dashboard to open, defaults to the last one switched to
+    #[clap(short, long)]
+    pub dashboard: Option<String>,
+
+    /// Workspace profile to use, defaults to the last one switched to, a new profile asks for its access token
+    #[clap(short, long, global = true)]
+    pub profile: Option<String>,
+
+    /// Print subcommand output as JSON instead of a table
+    #[clap(long, global = true)]
+    pub json: bool,
+
+    /// Run a single command & exit instead of opening the dashboard
+    #[clap(subcommand)]
+    pub subcmd: Option<SubCommand>,
+}
+
+#[derive(Clap, Debug)]
+pub enum SubCommand {
+    /// List the issues of a Custom View
+    Issues(IssuesOpts),
+    /// Show or update a single issue
+    Issue(IssueOpts),
+    /// Custom Views of the workspace
+    Views(ViewsOpts),
+}
+
+#[derive(Clap, Debug)]
+pub struct IssuesOpts {
+    /// Name or id of the Custom View
+    #[clap(long)]
+    pub view: String,
+
+    /// Comma-separated View Panel column ids, e.g. number,title,state
+    #[clap(long)]
+    pub columns: Option<String>,
+}
+
+#[derive(Clap, Debug)]
+pub struct IssueOpts {
+    #[clap(subcommand)]
+    pub subcmd: IssueSubCommand,
+}
+
+#[derive(Clap, Debug)]
+pub enum IssueSubCommand {
+    /// Show an issue by identifier, e.g. ENG-123
+    Show(IssueShowOpts),
+    /// Update an issue's fields by identifier, e.g. ENG-123
+    Update(IssueUpdateOpts),
+}
+
+#[derive(Clap, Debug)]
+pub struct IssueShowOpts {
+    /// Team key & issue number, e.g. ENG-123
+    pub identifier: String,
+}
+
+#[derive(Clap, Debug)]
+pub struct IssueUpdateOpts {
+    /// Team key & issue number, e.g. ENG-123
+    pub identifier: String,
+
+    /// Workflow state name of the issue's team
+    #[clap(long)]
+    pub state: Option<String>,
+
+    /// Team member name, display name or "me"
+    #[clap(long)]
+    pub assignee: Option<String>,
+
+    /// Priority label, e.g. "High", or value 0-4
+    #[clap(long)]
+    pub priority: Option<String>,
+
+    /// New issue title
+    #[clap(long)]
+    pub title: Option<String>,
+}
+
+#[derive(Clap, Debug)]
+pub struct ViewsOpts {
+    #[clap(subcommand)]
+    pub subcmd: ViewsSubCommand,
+}
+
+#[derive(Clap, Debug)]
+pub enum ViewsSubCommand {
+    /// List the workspace's Custom Views
+    List,
+}
@@ -3,18 +3,29 @@
 use crossterm::event::KeyCode;
 use unicode_segmentation::UnicodeSegmentation;
 
+use crossterm::{
+    event::{ DisableMouseCapture, EnableMouseCapture },
+    execute,
+    terminal::{ disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen },
+};
+
+use std::io;
+use std::fs;
 use std::sync::atomic::{ Ordering };
 
-use crate::app::{App, Platform, AppEvent, Route, InputMode};
+use crate::app::{App, Platform, AppEvent, Route, InputMode, DescriptionEditState};
+use crate::backend;
 use crate::util::{
     table_state,
-    dashboard::{ fetch_selected_view_panel_issue, fetch_selected_view_panel_idx, },
+    editor::edit_in_external_editor,
+    keybindings,
+    dashboard::{ fetch_selected_view_panel_issue, fetch_selected_view_panel_idx, fetch_selected_value, compact_view_list },
     event_crossterm::{ Events },
 };
 
 use crate::linear::{
     config::LinearConfig,
-    schema::CustomView,
+    schema::{ CustomView, Team, Label, IssueUpdateInput },
     client::{ IssueFieldObject }
 };
 
@@ -23,10 +34,17 @@ use crate::constants::{
     IssueModificationOp
 };
 
-use crate::components::{ InputComponent, token_entry::{ TokenValidationState }, title_entry::{ TitleValidationState } };
+use crate::components::{ InputComponent, token_entry::{ TokenValidationState }, title_entry::{ TitleValidationState },
+    dashboard_name_entry::DashboardNameOp,
+    linear_issue_create_form::{ LinearIssueCreateForm, IssueCreateField },
+    linear_issue_op_interface::LinearIssueOpInterface,
+    user_input::UserInput,
+};
 
 use tui::{
+    backend::CrosstermBackend,
     widgets::{ TableState },
+    Terminal,
 };
 
 
@@ -39,6 +57,10 @@ pub enum Command {
     ScrollUp,
     Confirm,
 
+    // Page Key Commands
+    ScrollCommentsDown,
+    ScrollCommentsUp,
+
     // User Input related Commands
     
     EditorEnter,
@@ -56,14 +78,34 @@ pub enum Command {
     Quit,
     Delete,
     SelectViewPanel(usize),
+    OpenPanelJumpPrompt,
+    ToggleZoomPanel,
+    OpenPanelExportPrompt,
 
     RefreshViewPanel,
     ExpandIssue,
 
+    SortViewPanel,
+    GroupViewPanel,
+
     SelectDashboardViewList,
     SelectCustomViewSelect,
 
+    OpenDashboardNameEntry(DashboardNameOp),
+    SwitchDashboard(bool),
+    SwitchProfile,
+
+    ToggleErrorLog,
+
+    UndoIssueModification,
+    RedoIssueModification,
+    ToggleIssueHistory,
+
     OpenIssueOpInterface(IssueModificationOp),
+    OpenIssueCreateForm,
+    ToggleIssueOpSelection,
+    OpenIssueSearch,
+    EditIssueDescription,
 
     // OpenLinearWorkflowStateSelection,
     // OpenLinearAssigneeSelection,
@@ -109,9 +151,17 @@ pub fn get_cmd(cmd_str: &mut String, input: KeyCode, current_route: &Route, inpu
         KeyCode::Right => {
             Some(Command::Confirm)
         },
+        KeyCode::PageDown => {
+            Some(Command::ScrollCommentsDown)
+        },
+        KeyCode::PageUp => {
+            Some(Command::ScrollCommentsUp)
+        },
 
         // Contextual User commands
         KeyCode::Char(ch) => {
+            // Match on the default key of rebound actions
+            let ch = keybindings::translate_key(ch)?;
             cmd_str.push(ch);
             match cmd_str.as_str() {
                 // Quit Command
@@ -133,6 +183,13 @@ pub fn get_cmd(cmd_str: &mut String, input: KeyCode, current_route: &Route, inpu
                 "f" => {
                     Some(Command::ExpandIssue)  
                 },
+                // Sort & Group Commands
+                "S" => {
+                    Some(Command::SortViewPanel)
+                },
+                "G" => {
+                    Some(Command::GroupViewPanel)
+                },
                 // Modify Command
                 "t" => {
                     Some(Command::OpenIssueOpInterface(IssueModificationOp::Title))
@@ -149,6 +206,71 @@ pub fn get_cmd(cmd_str: &mut String, input: KeyCode, current_route: &Route, inpu
                 "c" => {
                     Some(Command::OpenIssueOpInterface(IssueModificationOp::Cycle))
                 },
+                "l" => {
+                    Some(Command::OpenIssueOpInterface(IssueModificationOp::Labels))
+                },
+                "m" => {
+                    Some(Command::OpenIssueOpInterface(IssueModificationOp::Team))
+                },
+                "i" => {
+                    Some(Command::OpenIssueOpInterface(IssueModificationOp::Priority))
+                },
+                "s" => {
+                    Some(Command::OpenIssueOpInterface(IssueModificationOp::Estimate))
+                },
+                "u" => {
+                    Some(Command::OpenIssueOpInterface(IssueModificationOp::DueDate))
+                },
+                "o" => {
+                    Some(Command::EditIssueDescription)
+                },
+                // Multi-select toggle
+                " " => {
+                    Some(Command::ToggleIssueOpSelection)
+                },
+                // Search Command
+                "/" => {
+                    Some(Command::OpenIssueSearch)
+                },
+                // Create Command
+                "n" => {
+                    Some(Command::OpenIssueCreateForm)
+                },
+
+                // Dashboard Commands
+                "N" => {
+                    Some(Command::OpenDashboardNameEntry(DashboardNameOp::Create))
+                },
+                "R" => {
+                    Some(Command::OpenDashboardNameEntry(DashboardNameOp::Rename))
+                },
+                "D" => {
+                    Some(Command::OpenDashboardNameEntry(DashboardNameOp::Duplicate))
+                },
+                "[" => {
+                    Some(Command::SwitchDashboard(false))
+                },
+                "]" => {
+                    Some(Command::SwitchDashboard(true))
+                },
+                // Profile Command
+                "W" => {
+                    Some(Command::SwitchProfile)
+                },
+                // Notification Command
+                "E" => {
+                    Some(Command::ToggleErrorLog)
+                },
+                // Issue History Commands
+                "U" => {
+                    Some(Command::UndoIssueModification)
+                },
+                "Y" => {
+                    Some(Command::RedoIssueModification)
+                },
+                "H" => {
+                    Some(Command::ToggleIssueHistory)
+                },
 
                 // View Panel Selection Shortcuts
                 "1" => {
@@ -183,6 +305,25 @@ pub fn get_cmd(cmd_str: &mut String, input: KeyCode, current_route: &Route, inpu
                 "6" => {
                     Some(Command::SelectViewPanel(6))
                 },
+                "7" => {
+                    Some(Command::SelectViewPanel(7))
+                },
+                "8" => {
+                    Some(Command::SelectViewPanel(8))
+                },
+                "9" => {
+                    Some(Command::SelectViewPanel(9))
+                },
+                // Panels past 9 are selected by number
+                "J" => {
+                    Some(Command::OpenPanelJumpPrompt)
+                },
+                "z" => {
+                    Some(Command::ToggleZoomPanel)
+                },
+                "x" => {
+                    Some(Command::OpenPanelExportPrompt)
+                },
 
                 _ => {
                     None
@@ -203,33 +344,38 @@ pub fn exec_editor_focus_cmd(app: &mut App<'_>, events: &mut Events) {
         app.input_mode = InputMode::Edit;
 }
 
-pub fn exec_editor_input_cmd(app: &mut App<'_>, ch: &char) {
-    // Verify user is entering access token
+// Accepts:
+//     app
+// Returns:
+//     the UserInput currently accepting editor commands, None if the Issue Create Form has no text field selected
+fn active_user_input<'a>(app: &'a mut App<'_>) -> Option<&'a mut UserInput> {
     match app.active_input {
-        InputComponent::TokenEntry => { app.token_entry.input.insert(*ch) },
-        InputComponent::TitleEntry => { app.title_entry.input.insert(*ch) },
+        InputComponent::TokenEntry => { Some(&mut app.token_entry.input) },
+        InputComponent::TitleEntry => { Some(&mut app.title_entry.input) },
+        InputComponent::IssueCreateForm => { app.linear_issue_create_form.input_mut() },
+        InputComponent::DueDatePicker => { Some(&mut app.due_date_picker.input) },
+        InputComponent::CommentEntry => { Some(&mut app.issue_comments_pane.reply_input) },
+        InputComponent::SearchQuery => { Some(&mut app.issue_search.input) },
+        InputComponent::DashboardName => { Some(&mut app.dashboard_name_entry.input) },
+        InputComponent::PanelJump => { Some(&mut app.panel_jump_prompt.input) },
+        InputComponent::PanelExport => { Some(&mut app.panel_export_prompt.input) },
     }
 }
 
+pub fn exec_editor_input_cmd(app: &mut App<'_>, ch: &char) {
+    if let Some(input) = active_user_input(app) { input.insert(*ch) }
+}
+
 pub fn exec_editor_move_forward_cmd(app: &mut App<'_>) {
-    match app.active_input {
-        InputComponent::TokenEntry => { app.token_entry.input.move_cursor_forwards() },
-        InputComponent::TitleEntry => { app.title_entry.input.move_cursor_forwards() },
-    }
+    if let Some(input) = active_user_input(app) { input.move_cursor_forwards() }
 }
 
 pub fn exec_editor_move_back_cmd(app: &mut App<'_>) {
-    match app.active_input {
-        InputComponent::TokenEntry => { app.token_entry.input.move_cursor_back() },
-        InputComponent::TitleEntry => { app.title_entry.input.move_cursor_back() },
-    }
+    if let Some(input) = active_user_input(app) { input.move_cursor_back() }
 }
 
 pub fn exec_editor_delete_cmd(app: &mut App<'_>) {
-    match app.active_input {
-        InputComponent::TokenEntry => { app.token_entry.input.delete() },
-        InputComponent::TitleEntry => { app.title_entry.input.delete() },
-    }
+    if let Some(input) = active_user_input(app) { input.delete() }
 }
 
 pub fn exec_editor_submit_cmd(app: &mut App<'_>, events: &mut Events) {
@@ -257,11 +403,38 @@ pub fn exec_editor_submit_cmd(app: &mut App<'_>, events: &mut Events) {
                     info!("exec_editor_submit_cmd - dispatching 'update_issue' event");
                     app.dispatch_event(AppEvent::UpdateIssue);
                     app.modifying_issue = false;
+                    app.linear_issue_op_interface.reset_op();
                 } else {
                     let mut title_validation_state_lock = app.title_entry.title_validation_state.lock().unwrap();
                     *title_validation_state_lock = TitleValidationState::Invalid;
                 }
-            }
+            },
+            InputComponent::IssueCreateForm => {
+                // Field values are kept on the form until the issue is created
+            },
+            InputComponent::DueDatePicker => {
+                // Only moves the picker selection, the update is sent on confirm
+                app.due_date_picker.apply_input();
+            },
+            InputComponent::CommentEntry => {
+                info!("exec_editor_submit_cmd - dispatching 'create_comment' event");
+                app.dispatch_event(AppEvent::CreateComment);
+            },
+            InputComponent::SearchQuery => {
+                if app.issue_search.submit_query() {
+                    info!("exec_editor_submit_cmd - dispatching 'search_issues' event");
+                    app.dispatch_event(AppEvent::SearchIssues);
+                }
+            },
+            InputComponent::DashboardName => {
+                exec_submit_dashboard_name_cmd(app);
+            },
+            InputComponent::PanelJump => {
+                exec_submit_panel_jump_cmd(app);
+            },
+            InputComponent::PanelExport => {
+                exec_submit_panel_export_cmd(app);
+            },
         }
     }
 
@@ -270,14 +443,35 @@ pub fn exec_editor_submit_cmd(app: &mut App<'_>, events: &mut Events) {
 
 pub fn exec_editor_exit_cmd(app: &mut App<'_>, events: &mut Events) {
     events.enable_exit_key();
+    let was_editing = app.input_mode == InputMode::Edit;
     app.input_mode = InputMode::Normal;
 
+    // Close the error log, issue history, dashboard name entry, panel jump or panel export prompt
+    if app.notification_center.log_open {
+        app.notification_center.toggle_log();
+    }
+    else if app.issue_history_log.open {
+        app.issue_history_log.toggle();
+    }
+    else if app.dashboard_name_entry.op.is_some() {
+        app.dashboard_name_entry.close();
+    }
+    else if app.panel_jump_prompt.open {
+        app.panel_jump_prompt.close();
+    }
+    else if app.panel_export_prompt.open {
+        app.panel_export_prompt.close();
+    }
     // If editing the title, close the modal as well
-    if app.modifying_issue {
+    else if app.modifying_issue {
         app.input_mode = InputMode::Normal;
         app.modifying_issue = false;
         app.linear_issue_op_interface.reset_op();
     }
+    // If not editing a field of the Issue Create Form, close the form (or its open selection)
+    else if app.creating_issue && !was_editing {
+        exec_move_back_cmd(app);
+    }
 }
 
 
@@ -318,20 +512,10 @@ pub async fn exec_delete_cmd(app: &mut App<'_>) {
                 app.linear_dashboard_view_list[view_idx] = None;
 
                 // Sort app.linear_dashboard_view_list so that all Some's are first
-                // e.g. ["View 1", "Empty Slot", "View 2", ...] -> [ "View 1", "View 2", "Empty Slot" ]
-                app.linear_dashboard_view_list = app.linear_dashboard_view_list
-                .iter()
-                .filter_map(|e| {
-                    e.as_ref().map(|_| e.clone())
-                })
-                .collect();
-
-                while app.linear_dashboard_view_list.len() < 6 {
-                    app.linear_dashboard_view_list.push(None);
-                }
+                compact_view_list(&mut app.linear_dashboard_view_list);
 
                 // Serialize new Custom View List
-                LinearConfig::save_view_list(app.linear_dashboard_view_list.clone());
+                LinearConfig::save_view_list(&app.dashboard_name, app.linear_dashboard_view_list.clone());
             }
         }
     }
@@ -347,7 +531,7 @@ pub fn exec_select_view_panel_cmd(app: &mut App<'_>, view_panel_idx: usize) {
         // &&
         // Verify issue modification not in progress
         let view_panel_list_handle = app.linear_dashboard_view_panel_list.lock().unwrap();
-        if view_panel_idx <= view_panel_list_handle.len() && !app.modifying_issue {
+        if view_panel_idx >= 1 && view_panel_idx <= view_panel_list_handle.len() && !app.modifying_issue && !app.creating_issue {
 
             // if so, update app.linear_dashboard_view_panel_selected to Some(view_panel_idx)
             app.linear_dashboard_view_panel_selected = Some(view_panel_idx);
@@ -378,6 +562,74 @@ pub fn exec_select_view_panel_cmd(app: &mut App<'_>, view_panel_idx: usize) {
     }
 }
 
+// Whether panel navigation commands may run: on the ActionSelect route with no issue modal, modification or creation open
+fn panel_navigation_allowed(app: &App) -> bool {
+    *app.route.lock().unwrap() == Route::ActionSelect &&
+        app.issue_to_expand.is_none() && !app.modifying_issue && !app.creating_issue
+}
+
+pub fn exec_open_panel_jump_prompt_cmd(app: &mut App, events: &mut Events) {
+    if !panel_navigation_allowed(app) || app.linear_dashboard_view_panel_list.lock().unwrap().is_empty() {
+        return;
+    }
+
+    app.panel_jump_prompt.open();
+    app.active_input = InputComponent::PanelJump;
+    exec_editor_focus_cmd(app, events);
+}
+
+fn exec_submit_panel_jump_cmd(app: &mut App) {
+    let num_panels = app.linear_dashboard_view_panel_list.lock().unwrap().len();
+
+    // Keep the prompt open to show the error
+    if let Some(view_panel_idx) = app.panel_jump_prompt.validated_panel_num(num_panels) {
+        app.panel_jump_prompt.close();
+        exec_select_view_panel_cmd(app, view_panel_idx);
+    }
+}
+
+// Toggle rendering only the selected View Panel
+pub fn exec_toggle_zoom_panel_cmd(app: &mut App) {
+    if !panel_navigation_allowed(app) {
+        return;
+    }
+
+    if app.view_panel_zoomed || app.linear_dashboard_view_panel_selected.is_some() {
+        app.view_panel_zoomed = !app.view_panel_zoomed;
+    }
+}
+
+// Export all issues of the selected View Panel to a file
+pub fn exec_open_panel_export_prompt_cmd(app: &mut App, events: &mut Events) {
+    if !panel_navigation_allowed(app) {
+        return;
+    }
+
+    let view_panel_idx = match fetch_selected_view_panel_idx(app) {
+        Some(x) => x,
+        None => return,
+    };
+
+    let view_name = match app.linear_dashboard_view_panel_list.lock().unwrap().get(view_panel_idx) {
+        Some(view_panel) => view_panel.view.name.clone(),
+        None => return,
+    };
+
+    app.view_panel_to_export = view_panel_idx;
+    app.panel_export_prompt.open(&view_name);
+    app.active_input = InputComponent::PanelExport;
+    exec_editor_focus_cmd(app, events);
+}
+
+fn exec_submit_panel_export_cmd(app: &mut App) {
+    // Keep the prompt open to show the error or export progress
+    app.panel_export_prompt.error = None;
+    if let Some(target) = app.panel_export_prompt.validated_path() {
+        app.panel_export_prompt.target = Some(target);
+        app.dispatch_event(AppEvent::ExportViewPanel);
+    }
+}
+
 pub fn exec_select_dashboard_view_list_cmd(app: &mut App) {
     app.linear_dashboard_view_list_selected = true;
     app.linear_custom_view_select.view_table_state = TableState::default();
@@ -448,14 +700,393 @@ pub fn exec_refresh_view_panel_cmd(app: &mut App) {
     }
 }
 
+// Accepts:
+//     app
+//     cycle_sort: cycle the sort key if true, otherwise the group-by key
+pub fn exec_order_view_panel_cmd(app: &mut App, cycle_sort: bool) {
+    // Execute command if:
+    //     on the ActionSelect route &&
+    //     view panel is selected &&
+    //     no issue modal, modification or creation open
+
+    if *app.route.lock().unwrap() != Route::ActionSelect ||
+        app.issue_to_expand.is_some() || app.modifying_issue || app.creating_issue {
+        return;
+    }
+
+    let idx = match fetch_selected_view_panel_idx(app) {
+        Some(idx) => idx,
+        None => return,
+    };
+
+    let selected_issue_id = fetch_selected_view_panel_issue(app).map(|issue| issue.id);
+
+    let view_panel_list_lock = app.linear_dashboard_view_panel_list.lock().unwrap();
+    let view_panel = match view_panel_list_lock.get(idx) {
+        Some(x) => x.clone(),
+        None => return,
+    };
+    drop(view_panel_list_lock);
+
+    let mut order_lock = view_panel.order.lock().unwrap();
+    if cycle_sort {
+        order_lock.cycle_sort();
+    } else {
+        order_lock.cycle_group();
+    }
+    let order = *order_lock;
+    drop(order_lock);
+
+    app.linear_dashboard_panel_orders.insert(view_panel.view.id.clone(), order);
+    LinearConfig::save_panel_orders(&app.dashboard_name, &app.linear_dashboard_panel_orders);
+
+    // Issues have been reordered in place, reload to restore API order
+    if order.is_default() {
+        exec_refresh_view_panel_cmd(app);
+        return;
+    }
+
+    let mut issue_table_data_lock = view_panel.issue_table_data.lock().unwrap();
+    order.apply(&mut issue_table_data_lock);
+
+    // Keep the same Issue selected
+    if let Some(issue_id) = selected_issue_id {
+        if let Some(issue_idx) = issue_table_data_lock.iter().position(|issue| issue.id == issue_id) {
+            let mut table_state = TableState::default();
+            table_state.select(Some(issue_idx));
+            app.view_panel_issue_selected = Some(table_state);
+        }
+    }
+}
+
+// Accepts:
+//     app
+//     op: whether the entered name creates, renames or duplicates the active dashboard
+pub fn exec_open_dashboard_name_entry_cmd(app: &mut App, events: &mut Events, op: DashboardNameOp) {
+    // Execute command if on the DashboardViewDisplay route
+    if *app.route.lock().unwrap() != Route::DashboardViewDisplay || app.dashboard_name_entry.op.is_some() {
+        return;
+    }
+
+    let name_init = match op {
+        DashboardNameOp::Create => String::new(),
+        DashboardNameOp::Rename => app.dashboard_name.clone(),
+        DashboardNameOp::Duplicate => format!("{} copy", app.dashboard_name),
+    };
+    app.dashboard_name_entry.open(op, name_init);
+
+    app.active_input = InputComponent::DashboardName;
+    exec_editor_focus_cmd(app, events);
+}
+
+fn exec_submit_dashboard_name_cmd(app: &mut App) {
+    let op = match app.dashboard_name_entry.op {
+        Some(op) => op,
+        None => return,
+    };
+    let name = match app.dashboard_name_entry.validated_name(&app.dashboard_names, &app.dashboard_name) {
+        Some(name) => name,
+        // Keep the entry open to show the error
+        None => return,
+    };
+    app.dashboard_name_entry.close();
+
+    match op {
+        DashboardNameOp::Create => {
+            LinearConfig::create_dashboard(&name);
+        },
+        DashboardNameOp::Rename => {
+            LinearConfig::rename_dashboard(&app.dashboard_name, &name);
+            app.dashboard_names = LinearConfig::read_dashboard_names();
+            app.dashboard_name = name;
+            return;
+        },
+        DashboardNameOp::Duplicate => {
+            LinearConfig::duplicate_dashboard(&app.dashboard_name, &name);
+        },
+    }
+
+    // Open the new dashboard
+    app.switch_dashboard(name);
+}
+
+// Accepts:
+//     app
+//     forward: switch to the next dashboard if true, otherwise the previous one
+pub fn exec_switch_dashboard_cmd(app: &mut App, forward: bool) {
+    // Execute command if:
+    //     on the ActionSelect or DashboardViewDisplay route &&
+    //     no issue modal, modification or creation open &&
+    //     more than one dashboard exists
+
+    let cur_route: Route = app.route.lock().unwrap().clone();
+    if !(cur_route == Route::ActionSelect || cur_route == Route::DashboardViewDisplay) ||
+        app.issue_to_expand.is_some() || app.modifying_issue || app.creating_issue ||
+        app.dashboard_names.len() < 2 {
+        return;
+    }
+
+    let len = app.dashboard_names.len();
+    let idx = app.dashboard_names.iter().position(|x| *x == app.dashboard_name).unwrap_or(0);
+    let next_idx = if forward { (idx + 1) % len } else { (idx + len - 1) % len };
+
+    let name = app.dashboard_names[next_idx].clone();
+    app.switch_dashboard(name);
+}
+
+// Switch to the next workspace profile
+pub fn exec_switch_profile_cmd(app: &mut App) {
+    // Execute command if:
+    //     on the ActionSelect or DashboardViewDisplay route &&
+    //     no issue modal, modification or creation open &&
+    //     no dashboard name being entered &&
+    //     more than one profile exists
+
+    let cur_route: Route = app.route.lock().unwrap().clone();
+    if !(cur_route == Route::ActionSelect || cur_route == Route::DashboardViewDisplay) ||
+        app.issue_to_expand.is_some() || app.modifying_issue || app.creating_issue ||
+        app.dashboard_name_entry.op.is_some() || app.profile_names.len() < 2 {
+        return;
+    }
+
+    let idx = app.profile_names.iter().position(|x| *x == app.profile_name).unwrap_or(0);
+    let name = app.profile_names[(idx + 1) % app.profile_names.len()].clone();
+    app.switch_profile(name);
+}
+
+// Show or hide the log of all notifications & errors
+pub fn exec_toggle_error_log_cmd(app: &mut App) {
+    // Execute command if:
+    //     no text input is focused
+    if app.input_mode == InputMode::Edit {
+        return;
+    }
+
+    app.notification_center.toggle_log();
+}
+
+// Undo (or redo) the most recent Issue modification
+pub fn exec_undo_issue_modification_cmd(app: &mut App, undo: bool) {
+    // Execute command if:
+    //     on the ActionSelect or IssueSearch route &&
+    //     no text input is focused &&
+    //     no issue modification or creation open
+
+    let cur_route: Route = app.route.lock().unwrap().clone();
+    if (cur_route != Route::ActionSelect && cur_route != Route::IssueSearch) ||
+        app.input_mode == InputMode::Edit || app.modifying_issue || app.creating_issue {
+        return;
+    }
+
+    if undo {
+        app.dispatch_event(AppEvent::UndoIssueModification);
+    } else {
+        app.dispatch_event(AppEvent::RedoIssueModification);
+    }
+}
+
+// Show or hide the list of undoable & redoable Issue modifications
+pub fn exec_toggle_issue_history_cmd(app: &mut App) {
+    // Execute command if:
+    //     no text input is focused
+    if app.input_mode == InputMode::Edit {
+        return;
+    }
+
+    app.issue_history_log.toggle();
+}
+
 pub fn exec_expand_issue_cmd(app: &mut App) {
     // Execute command if:
-    //     view panel issue is selected
+    //     view panel issue is selected &&
+    //     issue create form not open
+
+    if app.creating_issue {
+        return;
+    }
 
     if let Some(issue_obj) = fetch_selected_view_panel_issue(app) {
+        // Load the comments thread if a different issue is expanded
+        if app.issue_comments_pane.reset_for_issue(&issue_obj.id) {
+            app.dispatch_event(AppEvent::LoadIssueComments);
+        }
+        app.active_input = InputComponent::CommentEntry;
+
         app.issue_to_expand = Some(issue_obj.clone());
     } else {
         app.issue_to_expand = None;
+        app.issue_comments_pane.clear();
+    }
+}
+
+pub fn exec_open_issue_search_cmd(app: &mut App, events: &mut Events) {
+    let cur_route: Route = app.route.lock().unwrap().clone();
+
+    // Open Issue Search if no issue modal is open
+    if Route::ActionSelect == cur_route && !app.creating_issue && !app.modifying_issue && app.issue_to_expand.is_none() {
+        app.change_route(Route::IssueSearch);
+        // Search Route starts with the query input focused
+        events.disable_exit_key();
+    }
+}
+
+pub fn exec_scroll_comments_down_cmd(app: &mut App) {
+    if app.issue_to_expand.is_none() {
+        return;
+    }
+
+    // Load next page of comments once the last loaded comment is reached
+    if app.issue_comments_pane.scroll_down() {
+        app.dispatch_event(AppEvent::LoadIssueComments);
+    }
+}
+
+pub fn exec_scroll_comments_up_cmd(app: &mut App) {
+    if app.issue_to_expand.is_some() {
+        app.issue_comments_pane.scroll_up();
+    }
+}
+
+
+// Hand the terminal over to an external program
+fn suspend_tui(terminal: &mut Terminal<CrosstermBackend<io::Stdout>>) -> io::Result<()> {
+    disable_raw_mode()?;
+    execute!(terminal.backend_mut(), LeaveAlternateScreen, DisableMouseCapture)
+}
+
+// Every step is attempted, so the TUI is restored as far as possible even if one fails
+fn resume_tui(terminal: &mut Terminal<CrosstermBackend<io::Stdout>>) -> io::Result<()> {
+    let entered = execute!(terminal.backend_mut(), EnterAlternateScreen, EnableMouseCapture);
+    let raw_mode = enable_raw_mode();
+    let cleared = terminal.clear();
+    entered.and(raw_mode).and(cleared)
+}
+
+// Suspends the TUI and opens the selected Issue's description in $EDITOR,
+// the update is not sent if the remote Issue was updated while editing
+pub async fn exec_edit_issue_description_cmd(app: &mut App<'_>,
+    terminal: &mut Terminal<CrosstermBackend<io::Stdout>>,
+    events: &mut Events) {
+
+    // Execute command if:
+    //     Route::ActionSelect &&
+    //     view panel issue is selected &&
+    //     issue op interface and issue create form not open
+
+    let cur_route: Route = app.route.lock().unwrap().clone();
+    if (cur_route != Route::ActionSelect && cur_route != Route::IssueSearch) || app.modifying_issue || app.creating_issue {
+        return;
+    }
+
+    let issue = match fetch_selected_view_panel_issue(app) {
+        Some(x) => x,
+        None => return,
+    };
+
+    // The Issue's workspace client
+    let client_handle = app.selected_issue_backend();
+
+    // Fetch the current remote description, falling back to the View Panel copy if unavailable
+    // An unchanged remote 'updatedAt' skips comparing descriptions when checking for conflicts
+    let (base_description, base_updated_at) = {
+        let remote = match backend::current(&client_handle).await {
+            Some(client) => client.issue_description(&issue.id).await,
+            None => return,
+        };
+        match remote {
+            Ok(Some(data)) => (data.issue.description.unwrap_or_default(), Some(data.issue.updated_at)),
+            _ => (issue.description.clone().unwrap_or_default(), None),
+        }
+    };
+
+    // Hand the terminal over to the editor, & always take it back
+    events.pause_input();
+    let file_name = format!("linear-{}-{}.md", issue.id, std::process::id());
+    let edit_result = suspend_tui(terminal)
+        .and_then(|_| edit_in_external_editor(&file_name, &base_description));
+    let resume_result = resume_tui(terminal);
+    events.resume_input();
+
+    if let Err(err) = resume_result {
+        error!("exec_edit_issue_description_cmd - failed to restore the terminal: {:?}", err);
+    }
+
+    let edited = match edit_result {
+        Ok(x) => x,
+        Err(err) => {
+            error!("exec_edit_issue_description_cmd - editor failed: {:?}", err);
+            app.description_edit_state = DescriptionEditState::Failed(err.to_string());
+            return;
+        }
+    };
+
+    if edited.content == base_description {
+        let _ = fs::remove_file(&edited.path);
+        app.description_edit_state = DescriptionEditState::Null;
+        return;
+    }
+
+    let client = match backend::current(&client_handle).await {
+        Some(client) => client,
+        None => return,
+    };
+
+    // Detect a conflicting remote edit
+    match client.issue_description(&issue.id).await {
+        Ok(Some(data)) => {
+            // Other fields' edits also bump updatedAt, so only a changed description conflicts
+            let unchanged = base_updated_at.as_ref() == Some(&data.issue.updated_at);
+            let conflict = !unchanged && data.issue.description.unwrap_or_default() != base_description;
+            if conflict {
+                warn!("exec_edit_issue_description_cmd - {} was updated remotely", issue.id);
+                app.description_edit_state = DescriptionEditState::Conflict(edited.path);
+                return;
+            }
+        },
+        Ok(None) | Err(_) => {
+            app.description_edit_state = DescriptionEditState::Failed(format!("unable to verify remote description, edit saved to {}", edited.path.display()));
+            return;
+        }
+    }
+
+    let issue_update = IssueUpdateInput {
+        description: Some(edited.content.clone()),
+        ..IssueUpdateInput::default()
+    };
+
+    match client.update_issue(&issue.id, issue_update).await {
+        Ok(Some(_)) => {
+            // Update Issue in all ViewPanels & search results
+            let view_panel_list_handle = app.linear_dashboard_view_panel_list.lock().unwrap();
+            for view_panel in view_panel_list_handle.iter() {
+                view_panel.mark_modified(&issue.id);
+                for issue_obj in view_panel.issue_table_data.lock().unwrap().iter_mut() {
+                    if issue_obj.id == issue.id {
+                        issue_obj.description = Some(edited.content.clone());
+                    }
+                }
+            }
+            drop(view_panel_list_handle);
+
+            for issue_obj in app.issue_search.issue_table_data.lock().unwrap().iter_mut() {
+                if issue_obj.id == issue.id {
+                    issue_obj.description = Some(edited.content.clone());
+                }
+            }
+
+            if let Some(expanded) = &mut app.issue_to_expand {
+                if expanded.id == issue.id {
+                    expanded.description = Some(edited.content.clone());
+                }
+            }
+
+            let _ = fs::remove_file(&edited.path);
+            app.description_edit_state = DescriptionEditState::Updated;
+        },
+        _ => {
+            error!("exec_edit_issue_description_cmd - update_issue failed for {}", issue.id);
+            app.description_edit_state = DescriptionEditState::Failed(format!("update failed, edit saved to {}", edited.path.display()));
+        }
     }
 }
 
@@ -466,7 +1097,7 @@ pub fn exec_open_issue_op_interface_cmd(app: &mut App, op: IssueModificationOp)
 
     let cur_route: Route = app.route.lock().unwrap().clone();
 
-    if Route::ActionSelect == cur_route {
+    if Route::ActionSelect == cur_route || Route::IssueSearch == cur_route {
 
         // If matching op interface modal is open, close it
         if app.linear_issue_op_interface.current_op == Some(op) {
@@ -474,8 +1105,10 @@ pub fn exec_open_issue_op_interface_cmd(app: &mut App, op: IssueModificationOp)
         }
 
         // Enable drawing of issue op interface if:
-        //     expanded issue modal not open
-        else if app.issue_to_expand.is_none() {
+        //     expanded issue modal not open &&
+        //     issue create form not open &&
+        //     the selected Issue's platform supports the op
+        else if app.issue_to_expand.is_none() && !app.creating_issue && backend::supports_op(&app.selected_issue_platform(), op) {
             app.linear_issue_op_interface.current_op = Some(op);
             app.modifying_issue = true;
 
@@ -492,11 +1125,127 @@ pub fn exec_open_issue_op_interface_cmd(app: &mut App, op: IssueModificationOp)
                 }
             }
 
+            // If IssueModificationOp::DueDate,
+            // start the picker at the issue's due date
+            if op == IssueModificationOp::DueDate {
+                if let Some(issue_obj) = fetch_selected_view_panel_issue(app) {
+                    app.due_date_picker.init(issue_obj.due_date.as_deref());
+                    app.active_input = InputComponent::DueDatePicker;
+                }
+            }
+
+            // If IssueModificationOp::Labels,
+            // start with the issue's current labels toggled
+            if op == IssueModificationOp::Labels {
+                if let Some(issue_obj) = fetch_selected_view_panel_issue(app) {
+                    let issue_labels: Vec<Label> = issue_obj.labels.nodes
+                        .into_iter()
+                        .filter_map(|label| serde_json::from_value(serde_json::to_value(label).ok()?).ok())
+                        .collect();
+                    app.linear_issue_op_interface.init_label_selection(issue_labels);
+                }
+            }
+
             app.dispatch_event(AppEvent::LoadIssueOpData);
         }
     }
 }
 
+pub fn exec_open_issue_create_form_cmd(app: &mut App) {
+
+    let cur_route: Route = app.route.lock().unwrap().clone();
+
+    // Open the issue create form if:
+    //     a view panel is selected &&
+    //     no issue modal is open
+    if Route::ActionSelect == cur_route && !app.creating_issue && !app.modifying_issue && app.issue_to_expand.is_none() {
+
+        let view_panel_idx = match fetch_selected_view_panel_idx(app) {
+            Some(idx) => idx,
+            None => return,
+        };
+
+        if !backend::supports_create(&app.selected_issue_platform()) {
+            return;
+        }
+
+        // Default to the team of the selected view panel's Custom View, or else the selected issue's team
+        let view_panel_list_lock = app.linear_dashboard_view_panel_list.lock().unwrap();
+        let view_team: Option<Team> = view_panel_list_lock
+            .get(view_panel_idx)
+            .and_then(|panel| panel.view.team.clone())
+            .and_then(|team| serde_json::from_value(serde_json::to_value(team).ok()?).ok());
+        drop(view_panel_list_lock);
+
+        let team: Option<Team> = view_team.or_else(|| {
+            fetch_selected_view_panel_issue(app).map(|issue| Team {
+                id: issue.team.id,
+                name: issue.team.name,
+                key: String::new(),
+                description: None,
+            })
+        });
+
+        app.linear_issue_create_form = LinearIssueCreateForm::with_team(team);
+        app.creating_issue = true;
+    }
+}
+
+// Confirm the selected field of the Issue Create Form:
+//     select fields: open (or confirm) the matching issue op interface table
+//     text fields: enter the editor
+fn exec_confirm_issue_create_form_cmd(app: &mut App<'_>, events: &mut Events) {
+
+    // Store toggled labels from multi-select issue op interface
+    if app.linear_issue_op_interface.current_op == Some(IssueModificationOp::Labels) {
+        let labels = app.linear_issue_op_interface.selected_labels();
+        app.linear_issue_create_form.set_labels(labels);
+        app.linear_issue_op_interface.reset_op();
+        return;
+    }
+
+    // Store value selected from issue op interface
+    if app.linear_issue_op_interface.current_op.is_some() {
+        if let Some(obj) = fetch_selected_value(app) {
+            app.linear_issue_create_form.set_field_value(obj);
+            app.linear_issue_op_interface.reset_op();
+        }
+        return;
+    }
+
+    let field = app.linear_issue_create_form.selected_field();
+
+    if let Some(op) = field.selection_op() {
+        if field != IssueCreateField::Team && app.linear_issue_create_form.team.is_none() {
+            app.linear_issue_create_form.validation_msg = Some("Select a Team first".to_string());
+            return;
+        }
+        app.linear_issue_op_interface.current_op = Some(op);
+        if op == IssueModificationOp::Labels {
+            let labels = app.linear_issue_create_form.labels.clone();
+            app.linear_issue_op_interface.init_label_selection(labels);
+        }
+        app.dispatch_event(AppEvent::LoadIssueOpData);
+    }
+    else if field.is_text() {
+        app.active_input = InputComponent::IssueCreateForm;
+        exec_editor_focus_cmd(app, events);
+    }
+    else if field == IssueCreateField::Submit && app.linear_issue_create_form.validated_create_input().is_some() {
+        app.dispatch_event(AppEvent::CreateIssue);
+        app.creating_issue = false;
+    }
+}
+
+
+pub fn exec_toggle_issue_op_selection_cmd(app: &mut App) {
+    // Only multi-select ops support toggling
+    if let Some(op) = app.linear_issue_op_interface.current_op {
+        if LinearIssueOpInterface::is_multi_select(&op) {
+            app.linear_issue_op_interface.toggle_selected_label();
+        }
+    }
+}
 
 
 pub fn exec_move_back_cmd(app: &mut App) {
@@ -510,7 +1259,7 @@ pub fn exec_move_back_cmd(app: &mut App) {
         },
 
         // Unselect from List of Actions
-        Route::ActionSelect => {
+        Route::ActionSelect | Route::IssueSearch => {
 
             // If state change cancelled, reset
             if app.modifying_issue {
@@ -521,13 +1270,33 @@ pub fn exec_move_back_cmd(app: &mut App) {
                 app.input_mode = InputMode::Normal;
             }
 
+            // If selecting a value for the Issue Create Form, close the selection
+            // otherwise close the form
+            else if app.creating_issue {
+                if app.linear_issue_op_interface.current_op.is_some() {
+                    app.linear_issue_op_interface.reset_op();
+                } else {
+                    app.creating_issue = false;
+                }
+            }
+
             // If expanded Issue view is open, close modal
             else if app.issue_to_expand.is_some() {
                 app.issue_to_expand = None;
+                app.issue_comments_pane.clear();
             }
 
             // If a View Panel is selected, unselect it, reset app.linear_dashboard_view_panel_selected to None and
             // select app.actions()
+            // Leave Issue Search, Dashboard View Panels are still loaded
+            else if cur_route == Route::IssueSearch {
+                *app.route.lock().unwrap() = Route::ActionSelect;
+                app.input_mode = InputMode::Normal;
+                if app.linear_dashboard_view_panel_selected.is_none() {
+                    app.actions.next();
+                }
+            }
+
             else if app.linear_dashboard_view_panel_selected.is_some() {
                 app.linear_dashboard_view_panel_selected = None;
                 app.actions.next();
@@ -546,21 +1315,25 @@ pub fn exec_move_back_cmd(app: &mut App) {
     }
 }
 
-pub async fn exec_confirm_cmd(app: &mut App<'_>) {
+pub async fn exec_confirm_cmd(app: &mut App<'_>, events: &mut Events) {
 
     let cur_route: Route = app.route.lock().unwrap().clone();
 
     match cur_route {
         // Unlike exec_editor_submit_cmd(), this does not submit a new access token,
         Route::ConfigInterface => {},
-        Route::ActionSelect => {
+        Route::ActionSelect | Route::IssueSearch => {
 
             let valid_selection: bool = app.linear_issue_op_interface.is_valid_selection_for_update(&app.title_entry.input.input);
 
+            if app.creating_issue {
+                exec_confirm_issue_create_form_cmd(app, events);
+            }
             // If a state change is confirmed, dispatch & reset
-            if app.modifying_issue && valid_selection {
+            else if app.modifying_issue && valid_selection {
                 app.dispatch_event(AppEvent::UpdateIssue);
                 app.modifying_issue = false;
+                app.linear_issue_op_interface.reset_op();
             }
             // If user has chosen the "Modify Dashboard" action
             // only allow if timezone load is complete
@@ -568,6 +1341,9 @@ pub async fn exec_confirm_cmd(app: &mut App<'_>) {
                 if i == 0 {
                     app.change_route( Route::DashboardViewDisplay)
                 }
+                else if i == 1 {
+                    exec_open_issue_search_cmd(app, events);
+                }
             }
         },
         // Select Custom View Select
@@ -601,20 +1377,17 @@ pub async fn exec_confirm_cmd(app: &mut App<'_>) {
 
                 if let Some(slot_idx) = slot_idx_option {
                     info!("Updated linear_dashboard_view_list[{:?}] with selected_view: {:?}", slot_idx, selected_view);
+
+                    // Views are listed from the active profile's workspace
+                    app.linear_dashboard_panel_profiles.insert(selected_view.id.clone(), app.profile_name.clone());
                     app.linear_dashboard_view_list[slot_idx] = Some(selected_view);
 
                     // Sort app.linear_dashboard_view_list so that all Some's are first
-                    app.linear_dashboard_view_list = app.linear_dashboard_view_list
-                                                        .iter()
-                                                        .filter_map(|e| { e.as_ref().map(|_| e.clone()) })
-                                                        .collect();
-
-                    while app.linear_dashboard_view_list.len() < 6 {
-                        app.linear_dashboard_view_list.push(None);
-                    }
+                    compact_view_list(&mut app.linear_dashboard_view_list);
 
                     // Serialize new Custom View List
-                    LinearConfig::save_view_list(app.linear_dashboard_view_list.clone());
+                    LinearConfig::save_view_list(&app.dashboard_name, app.linear_dashboard_view_list.clone());
+                    LinearConfig::save_panel_profiles(&app.dashboard_name, &app.linear_dashboard_panel_profiles);
                 };
 
                 drop(custom_view_data_lock);
@@ -628,6 +1401,16 @@ pub async fn exec_confirm_cmd(app: &mut App<'_>) {
 
 pub fn exec_scroll_down_cmd(app: &mut App) {
 
+    // The error log is drawn over every route
+    if app.notification_center.log_open {
+        app.notification_center.scroll_down();
+        return;
+    }
+    if app.issue_history_log.open {
+        app.issue_history_log.scroll_down();
+        return;
+    }
+
     let cur_route: Route = app.route.lock().unwrap().clone();
 
     match cur_route {
@@ -637,15 +1420,20 @@ pub fn exec_scroll_down_cmd(app: &mut App) {
         },
 
         // Select next Action
-        Route::ActionSelect => {
+        Route::ActionSelect | Route::IssueSearch => {
             let mut load_paginated = false;
 
             // Don't scroll down if entering issue title
             if app.modifying_issue && app.linear_issue_op_interface.current_op == Some(IssueModificationOp::Title) {
                 return;
             }
+            // Move Due Date Picker selection to the next day
+            else if app.modifying_issue && app.linear_issue_op_interface.current_op == Some(IssueModificationOp::DueDate) {
+                app.due_date_picker.shift_days(1);
+                return;
+            }
             // If the issue op interface is open, scroll down on modal
-            else if app.modifying_issue {
+            else if app.modifying_issue || app.linear_issue_op_interface.current_op.is_some() {
 
                 debug!("Attempting to scroll down on IssueOpInterface");
 
@@ -698,6 +1486,18 @@ pub fn exec_scroll_down_cmd(app: &mut App) {
                 }
             }
 
+            // If the issue create form is open, select next field
+            else if app.creating_issue {
+                app.linear_issue_create_form.next_field();
+            }
+
+            // Select next search result, load next page once the last result is reached
+            else if cur_route == Route::IssueSearch {
+                if app.issue_search.select_next() {
+                    app.dispatch_event(AppEvent::SearchIssues);
+                }
+            }
+
             // If a ViewPanel is selected, scroll down on the View Panel
             else if let Some(view_panel_selected_idx) = app.linear_dashboard_view_panel_selected {
                 // debug!("exec_scroll_down_cmd() view panel is selected");
@@ -814,19 +1614,33 @@ pub fn exec_scroll_down_cmd(app: &mut App) {
 
 pub fn exec_scroll_up_cmd(app: &mut App) {
 
+    if app.notification_center.log_open {
+        app.notification_center.scroll_up();
+        return;
+    }
+    if app.issue_history_log.open {
+        app.issue_history_log.scroll_up();
+        return;
+    }
+
     let cur_route: Route = app.route.lock().unwrap().clone();
 
     match cur_route {
         Route::ConfigInterface => {
             // nothing to scroll
         },
-        Route::ActionSelect => {
+        Route::ActionSelect | Route::IssueSearch => {
 
             // Don't scroll up if entering issue title
             if app.modifying_issue && app.linear_issue_op_interface.current_op == Some(IssueModificationOp::Title) { }
 
+            // Move Due Date Picker selection to the previous day
+            else if app.modifying_issue && app.linear_issue_op_interface.current_op == Some(IssueModificationOp::DueDate) {
+                app.due_date_picker.shift_days(-1);
+            }
+
             // If the issue op interface is open, scroll down on modal
-            else if app.modifying_issue {
+            else if app.modifying_issue || app.linear_issue_op_interface.current_op.is_some() {
 
 
                 let obj_vec: Vec<IssueFieldObject> = match app.linear_issue_op_interface.table_data_from_op() {
@@ -841,6 +1655,16 @@ pub fn exec_scroll_up_cmd(app: &mut App) {
                 app.linear_issue_op_interface.selected_idx = app.linear_issue_op_interface.data_state.selected();
             }
 
+            // If the issue create form is open, select previous field
+            else if app.creating_issue {
+                app.linear_issue_create_form.previous_field();
+            }
+
+            // Select previous search result
+            else if cur_route == Route::IssueSearch {
+                app.issue_search.select_previous();
+            }
+
             // If a ViewPanel is selected and no issue modal open, scroll up on the View Panel
             else if let Some(view_panel_selected_idx) = app.linear_dashboard_view_panel_selected {
 
@@ -32,9 +32,30 @@ pub struct CommandBar<'a> {
     modify_assignee_active: bool,
     modify_project_active: bool,
     modify_cycle_active: bool,
+    modify_labels_active: bool,
+    modify_team_active: bool,
+    modify_priority_active: bool,
+    modify_estimate_active: bool,
+
+    modify_due_date_active: bool,
+    modify_description_active: bool,
+    create_issue_active: bool,
+
+    order_panel_active: bool,
+
+    zoom_panel_active: bool,
+    jump_to_panel_active: bool,
+
+    export_panel_active: bool,
+
+    undo_modification_active: bool,
+    redo_modification_active: bool,
 
     // View List Command States
     remove_view_active: bool,
+    edit_dashboard_active: bool,
+    switch_dashboard_active: bool,
+    switch_profile_active: bool,
 }
 
 impl<'a> CommandBar<'a> {
@@ -54,9 +75,30 @@ impl<'a> CommandBar<'a> {
             modify_assignee_active: false,
             modify_project_active: false,
             modify_cycle_active: false,
+            modify_labels_active: false,
+            modify_team_active: false,
+            modify_priority_active: false,
+            modify_estimate_active: false,
+
+            modify_due_date_active: false,
+            modify_description_active: false,
+            create_issue_active: false,
+
+            order_panel_active: false,
+
+            zoom_panel_active: false,
+            jump_to_panel_active: false,
+
+            export_panel_active: false,
+
+            undo_modification_active: false,
+            redo_modification_active: false,
 
             // View List Command States
             remove_view_active: false,
+            edit_dashboard_active: false,
+            switch_dashboard_active: false,
+            switch_profile_active: false,
         }
     }
 
@@ -138,6 +180,150 @@ impl<'a> CommandBar<'a> {
         }
     }
 
+    pub fn set_modify_labels_active(&mut self, state: bool) {
+        match self.command_bar_type {
+            CommandBarType::Dashboard => {
+                self.modify_labels_active = state;
+            },
+            _ => {
+                error_panic!("'set_modify_labels_active' called on CommandBar with invalid CommandBarType: {:?}", self.command_bar_type);
+            },
+        }
+    }
+
+    pub fn set_modify_team_active(&mut self, state: bool) {
+        match self.command_bar_type {
+            CommandBarType::Dashboard => {
+                self.modify_team_active = state;
+            },
+            _ => {
+                error_panic!("'set_modify_team_active' called on CommandBar with invalid CommandBarType: {:?}", self.command_bar_type);
+            },
+        }
+    }
+
+    pub fn set_modify_priority_active(&mut self, state: bool) {
+        match self.command_bar_type {
+            CommandBarType::Dashboard => {
+                self.modify_priority_active = state;
+            },
+            _ => {
+                error_panic!("'set_modify_priority_active' called on CommandBar with invalid CommandBarType: {:?}", self.command_bar_type);
+            },
+        }
+    }
+
+    pub fn set_modify_estimate_active(&mut self, state: bool) {
+        match self.command_bar_type {
+            CommandBarType::Dashboard => {
+                self.modify_estimate_active = state;
+            },
+            _ => {
+                error_panic!("'set_modify_estimate_active' called on CommandBar with invalid CommandBarType: {:?}", self.command_bar_type);
+            },
+        }
+    }
+
+    pub fn set_modify_due_date_active(&mut self, state: bool) {
+        match self.command_bar_type {
+            CommandBarType::Dashboard => {
+                self.modify_due_date_active = state;
+            },
+            _ => {
+                error_panic!("'set_modify_due_date_active' called on CommandBar with invalid CommandBarType: {:?}", self.command_bar_type);
+            },
+        }
+    }
+
+    pub fn set_modify_description_active(&mut self, state: bool) {
+        match self.command_bar_type {
+            CommandBarType::Dashboard => {
+                self.modify_description_active = state;
+            },
+            _ => {
+                error_panic!("'set_modify_description_active' called on CommandBar with invalid CommandBarType: {:?}", self.command_bar_type);
+            },
+        }
+    }
+
+    pub fn set_create_issue_active(&mut self, state: bool) {
+        match self.command_bar_type {
+            CommandBarType::Dashboard => {
+                self.create_issue_active = state;
+            },
+            _ => {
+                error_panic!("'set_create_issue_active' called on CommandBar with invalid CommandBarType: {:?}", self.command_bar_type);
+            },
+        }
+    }
+
+
+    // Sort & Group Panel commands share a state
+    pub fn set_order_panel_active(&mut self, state: bool) {
+        match self.command_bar_type {
+            CommandBarType::Dashboard => {
+                self.order_panel_active = state;
+            },
+            _ => {
+                error_panic!("'set_order_panel_active' called on CommandBar with invalid CommandBarType: {:?}", self.command_bar_type);
+            },
+        }
+    }
+
+    pub fn set_zoom_panel_active(&mut self, state: bool) {
+        match self.command_bar_type {
+            CommandBarType::Dashboard => {
+                self.zoom_panel_active = state;
+            },
+            _ => {
+                error_panic!("'set_zoom_panel_active' called on CommandBar with invalid CommandBarType: {:?}", self.command_bar_type);
+            },
+        }
+    }
+
+    pub fn set_jump_to_panel_active(&mut self, state: bool) {
+        match self.command_bar_type {
+            CommandBarType::Dashboard => {
+                self.jump_to_panel_active = state;
+            },
+            _ => {
+                error_panic!("'set_jump_to_panel_active' called on CommandBar with invalid CommandBarType: {:?}", self.command_bar_type);
+            },
+        }
+    }
+
+    pub fn set_export_panel_active(&mut self, state: bool) {
+        match self.command_bar_type {
+            CommandBarType::Dashboard => {
+                self.export_panel_active = state;
+            },
+            _ => {
+                error_panic!("'set_export_panel_active' called on CommandBar with invalid CommandBarType: {:?}", self.command_bar_type);
+            },
+        }
+    }
+
+    pub fn set_undo_modification_active(&mut self, state: bool) {
+        match self.command_bar_type {
+            CommandBarType::Dashboard => {
+                self.undo_modification_active = state;
+            },
+            _ => {
+                error_panic!("'set_undo_modification_active' called on CommandBar with invalid CommandBarType: {:?}", self.command_bar_type);
+            },
+        }
+    }
+
+    pub fn set_redo_modification_active(&mut self, state: bool) {
+        match self.command_bar_type {
+            CommandBarType::Dashboard => {
+                self.redo_modification_active = state;
+            },
+            _ => {
+                error_panic!("'set_redo_modification_active' called on CommandBar with invalid CommandBarType: {:?}", self.command_bar_type);
+            },
+        }
+    }
 
     // View List Command Setters
     pub fn set_remove_view_active(&mut self, state: bool) {
@@ -151,6 +337,39 @@ impl<'a> CommandBar<'a> {
         };
     }
 
+    pub fn set_edit_dashboard_active(&mut self, state: bool) {
+        match self.command_bar_type {
+            CommandBarType::ViewList => {
+                self.edit_dashboard_active = state;
+            },
+            _ => {
+                error_panic!("'set_edit_dashboard_active' called on CommandBar with invalid CommandBarType: {:?}", self.command_bar_type);
+            },
+        };
+    }
+
+    pub fn set_switch_dashboard_active(&mut self, state: bool) {
+        match self.command_bar_type {
+            CommandBarType::ViewList => {
+                self.switch_dashboard_active = state;
+            },
+            _ => {
+                error_panic!("'set_switch_dashboard_active' called on CommandBar with invalid CommandBarType: {:?}", self.command_bar_type);
+            },
+        };
+    }
+
+    pub fn set_switch_profile_active(&mut self, state: bool) {
+        match self.command_bar_type {
+            CommandBarType::ViewList => {
+                self.switch_profile_active = state;
+            },
+            _ => {
+                error_panic!("'set_switch_profile_active' called on CommandBar with invalid CommandBarType: {:?}", self.command_bar_type);
+            },
+        };
+    }
+
     // Determine if a Command should be styled as active or not
     pub fn get_command_style(&self, cmd: &Command) -> Style {
         match self.command_bar_type {
@@ -206,6 +425,104 @@ impl<'a> CommandBar<'a> {
                                 } else {
                                     Style::default().add_modifier(Modifier::DIM).fg(colors::MODIFY_CYCLE_CMD_INACTIVE)
                                 }
+                            },
+                            DashboardCommand::ModifyLabels => {
+                                if self.modify_labels_active {
+                                    Style::default().add_modifier(Modifier::BOLD).fg(colors::MODIFY_LABELS_CMD_ACTIVE)
+                                } else {
+                                    Style::default().add_modifier(Modifier::DIM).fg(colors::MODIFY_LABELS_CMD_INACTIVE)
+                                }
+                            },
+                            DashboardCommand::ModifyTeam => {
+                                if self.modify_team_active {
+                                    Style::default().add_modifier(Modifier::BOLD).fg(colors::MODIFY_TEAM_CMD_ACTIVE)
+                                } else {
+                                    Style::default().add_modifier(Modifier::DIM).fg(colors::MODIFY_TEAM_CMD_INACTIVE)
+                                }
+                            },
+                            DashboardCommand::ModifyPriority => {
+                                if self.modify_priority_active {
+                                    Style::default().add_modifier(Modifier::BOLD).fg(colors::MODIFY_PRIORITY_CMD_ACTIVE)
+                                } else {
+                                    Style::default().add_modifier(Modifier::DIM).fg(colors::MODIFY_PRIORITY_CMD_INACTIVE)
+                                }
+                            },
+                            DashboardCommand::ModifyEstimate => {
+                                if self.modify_estimate_active {
+                                    Style::default().add_modifier(Modifier::BOLD).fg(colors::MODIFY_ESTIMATE_CMD_ACTIVE)
+                                } else {
+                                    Style::default().add_modifier(Modifier::DIM).fg(colors::MODIFY_ESTIMATE_CMD_INACTIVE)
+                                }
+                            },
+                            DashboardCommand::ModifyDueDate => {
+                                if self.modify_due_date_active {
+                                    Style::default().add_modifier(Modifier::BOLD).fg(colors::MODIFY_DUE_DATE_CMD_ACTIVE)
+                                } else {
+                                    Style::default().add_modifier(Modifier::DIM).fg(colors::MODIFY_DUE_DATE_CMD_INACTIVE)
+                                }
+                            },
+                            DashboardCommand::ModifyDescription => {
+                                if self.modify_description_active {
+                                    Style::default().add_modifier(Modifier::BOLD).fg(colors::MODIFY_DESCRIPTION_CMD_ACTIVE)
+                                } else {
+                                    Style::default().add_modifier(Modifier::DIM).fg(colors::MODIFY_DESCRIPTION_CMD_INACTIVE)
+                                }
+                            },
+                            DashboardCommand::CreateIssue => {
+                                if self.create_issue_active {
+                                    Style::default().add_modifier(Modifier::BOLD).fg(colors::CREATE_ISSUE_CMD_ACTIVE)
+                                } else {
+                                    Style::default().add_modifier(Modifier::DIM).fg(colors::CREATE_ISSUE_CMD_INACTIVE)
+                                }
+                            },
+                            DashboardCommand::SortPanel => {
+                                if self.order_panel_active {
+                                    Style::default().add_modifier(Modifier::BOLD).fg(colors::SORT_PANEL_CMD_ACTIVE)
+                                } else {
+                                    Style::default().add_modifier(Modifier::DIM).fg(colors::SORT_PANEL_CMD_INACTIVE)
+                                }
+                            },
+                            DashboardCommand::GroupPanel => {
+                                if self.order_panel_active {
+                                    Style::default().add_modifier(Modifier::BOLD).fg(colors::GROUP_PANEL_CMD_ACTIVE)
+                                } else {
+                                    Style::default().add_modifier(Modifier::DIM).fg(colors::GROUP_PANEL_CMD_INACTIVE)
+                                }
+                            },
+                            DashboardCommand::ZoomPanel => {
+                                if self.zoom_panel_active {
+                                    Style::default().add_modifier(Modifier::BOLD).fg(colors::ZOOM_PANEL_CMD_ACTIVE)
+                                } else {
+                                    Style::default().add_modifier(Modifier::DIM).fg(colors::ZOOM_PANEL_CMD_INACTIVE)
+                                }
+                            },
+                            DashboardCommand::JumpToPanel => {
+                                if self.jump_to_panel_active {
+                                    Style::default().add_modifier(Modifier::BOLD).fg(colors::JUMP_TO_PANEL_CMD_ACTIVE)
+                                } else {
+                                    Style::default().add_modifier(Modifier::DIM).fg(colors::JUMP_TO_PANEL_CMD_INACTIVE)
+                                }
+                            },
+                            DashboardCommand::ExportPanel => {
+                                if self.export_panel_active {
+                                    Style::default().add_modifier(Modifier::BOLD).fg(colors::EXPORT_PANEL_CMD_ACTIVE)
+                                } else {
+                                    Style::default().add_modifier(Modifier::DIM).fg(colors::EXPORT_PANEL_CMD_INACTIVE)
+                                }
+                            },
+                            DashboardCommand::UndoModification => {
+                                if self.undo_modification_active {
+                                    Style::default().add_modifier(Modifier::BOLD).fg(colors::UNDO_MODIFICATION_CMD_ACTIVE)
+                                } else {
+                                    Style::default().add_modifier(Modifier::DIM).fg(colors::UNDO_MODIFICATION_CMD_INACTIVE)
+                                }
+                            },
+                            DashboardCommand::RedoModification => {
+                                if self.redo_modification_active {
+                                    Style::default().add_modifier(Modifier::BOLD).fg(colors::REDO_MODIFICATION_CMD_ACTIVE)
+                                } else {
+                                    Style::default().add_modifier(Modifier::DIM).fg(colors::REDO_MODIFICATION_CMD_INACTIVE)
+                                }
                             }
                         }
                     },
@@ -224,7 +541,42 @@ impl<'a> CommandBar<'a> {
                                 } else {
                                     Style::default().add_modifier(Modifier::DIM).fg(colors::DELETE_VIEW_CMD_INACTIVE)
                                 }
-                            }
+                            },
+                            ViewListCommand::NewDashboard => {
+                                if self.edit_dashboard_active {
+                                    Style::default().add_modifier(Modifier::BOLD).fg(colors::NEW_DASHBOARD_CMD_ACTIVE)
+                                } else {
+                                    Style::default().add_modifier(Modifier::DIM).fg(colors::NEW_DASHBOARD_CMD_INACTIVE)
+                                }
+                            },
+                            ViewListCommand::RenameDashboard => {
+                                if self.edit_dashboard_active {
+                                    Style::default().add_modifier(Modifier::BOLD).fg(colors::RENAME_DASHBOARD_CMD_ACTIVE)
+                                } else {
+                                    Style::default().add_modifier(Modifier::DIM).fg(colors::RENAME_DASHBOARD_CMD_INACTIVE)
+                                }
+                            },
+                            ViewListCommand::DuplicateDashboard => {
+                                if self.edit_dashboard_active {
+                                    Style::default().add_modifier(Modifier::BOLD).fg(colors::DUPLICATE_DASHBOARD_CMD_ACTIVE)
+                                } else {
+                                    Style::default().add_modifier(Modifier::DIM).fg(colors::DUPLICATE_DASHBOARD_CMD_INACTIVE)
+                                }
+                            },
+                            ViewListCommand::PreviousDashboard | ViewListCommand::NextDashboard => {
+                                if self.switch_dashboard_active {
+                                    Style::default().add_modifier(Modifier::BOLD).fg(colors::SWITCH_DASHBOARD_CMD_ACTIVE)
+                                } else {
+                                    Style::default().add_modifier(Modifier::DIM).fg(colors::SWITCH_DASHBOARD_CMD_INACTIVE)
+                                }
+                            },
+                            ViewListCommand::SwitchProfile => {
+                                if self.switch_profile_active {
+                                    Style::default().add_modifier(Modifier::BOLD).fg(colors::SWITCH_PROFILE_CMD_ACTIVE)
+                                } else {
+                                    Style::default().add_modifier(Modifier::DIM).fg(colors::SWITCH_PROFILE_CMD_INACTIVE)
+                                }
+                            },
                         }
                     },
                     // Error
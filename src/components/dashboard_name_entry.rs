@@ -0,0 +1,146 @@
+use tui::{
+    backend::Backend,
+    layout::{ Constraint, Direction, Rect, Layout },
+    style::{Modifier, Style},
+    text::{Span, Spans, Text},
+    widgets::{Paragraph, Block, Borders },
+    Frame,
+};
+
+use crate::components::user_input::UserInput;
+
+use crate::constants::colors::RED;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DashboardNameOp {
+    Create,
+    Rename,
+    Duplicate,
+}
+
+impl DashboardNameOp {
+    pub fn label(&self) -> &'static str {
+        match self {
+            DashboardNameOp::Create => "create dashboard",
+            DashboardNameOp::Rename => "rename dashboard",
+            DashboardNameOp::Duplicate => "duplicate dashboard",
+        }
+    }
+}
+
+// Name input for creating, renaming & duplicating dashboards
+#[derive(Debug)]
+pub struct DashboardNameEntry {
+    pub input: UserInput,
+    // None if the entry is closed
+    pub op: Option<DashboardNameOp>,
+    pub error: Option<String>,
+}
+
+impl DashboardNameEntry {
+
+    pub fn open(&mut self, op: DashboardNameOp, name_init: String) {
+        self.input.set_input(name_init);
+        self.op = Some(op);
+        self.error = None;
+    }
+
+    pub fn close(&mut self) {
+        self.input.set_input(String::new());
+        self.op = None;
+        self.error = None;
+    }
+
+    // Accepts:
+    //     names: names of all dashboards
+    //     current: name of the active dashboard, may be kept when renaming
+    // Returns:
+    //     the trimmed name, or sets self.error if it's empty or taken
+    pub fn validated_name(&mut self, names: &[String], current: &str) -> Option<String> {
+        let name = self.input.input.trim().to_string();
+
+        if name.is_empty() {
+            self.error = Some(String::from("Dashboard name can't be empty"));
+            return None;
+        }
+        if names.contains(&name) && !(self.op == Some(DashboardNameOp::Rename) && name == current) {
+            self.error = Some(format!("Dashboard '{}' already exists", name));
+            return None;
+        }
+        Some(name)
+    }
+
+    pub fn gen_help_msg<'a>(&self) -> Paragraph<'a> {
+        let label = self.op.map(|op| op.label()).unwrap_or("");
+
+        let text = Text::from(Spans::from(vec![
+            Span::raw("Press "),
+            Span::styled("Enter", Style::default().add_modifier(Modifier::BOLD)),
+            Span::raw(format!(" to {}", label)),
+        ]));
+
+        Paragraph::new(text)
+    }
+
+    pub fn gen_status_msg<'a>(&self) -> Paragraph<'a> {
+        let span = match &self.error {
+            Some(error) => Span::styled(error.clone(), Style::default().fg(RED)),
+            None => Span::from(String::from("")),
+        };
+
+        Paragraph::new(Text::from(Spans::from(span)))
+    }
+
+    pub fn render<B>(&self, f: &mut Frame<B>, area: Rect)
+    where B: Backend,
+    {
+        // Split into two rows (top ==> help/status msg, bottom ==> input box)
+        let row_layout = Layout::default()
+            .direction(Direction::Vertical)
+            .margin(2)
+            .constraints(
+                [
+                    Constraint::Length(3),
+                    Constraint::Length(3),
+                ]
+                .as_ref(),
+            )
+            .split(area);
+
+        let msg_col_layout = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints(
+                [
+                    Constraint::Percentage(50),
+                    Constraint::Percentage(50),
+                ]
+                .as_ref(),
+            )
+            .split(row_layout[0]);
+
+        f.render_widget(
+            self.gen_help_msg()
+                .block(Block::default().borders(Borders::ALL)),
+            msg_col_layout[0]
+        );
+
+        f.render_widget(
+            self.gen_status_msg()
+                .block(Block::default().borders(Borders::ALL)),
+            msg_col_layout[1]
+        );
+
+        // render input box
+        self.input.render(f, row_layout[1]);
+    }
+}
+
+impl Default for DashboardNameEntry {
+    fn default() -> DashboardNameEntry {
+        DashboardNameEntry {
+            input: UserInput::new(false),
+            op: None,
+            error: None,
+        }
+    }
+}
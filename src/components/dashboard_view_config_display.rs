@@ -3,11 +3,12 @@ use std::cmp::max;
 
 use tui::{
     layout::{Constraint},
-    style::{Color, Modifier, Style},
+    style::{Modifier, Style},
     widgets::{Block, Borders, Cell, Row, Table, TableState},
 };
 
 use crate::util::{
+    theme::theme,
     table::{ TableStyle, gen_table_title_spans, 
         empty_str_to_fallback, format_cell_fields,
         get_row_height, colored_cell, row_min_render_height
@@ -33,10 +34,10 @@ impl DashboardViewConfigDisplay {
         let bottom_margin = table_style.row_bottom_margin.unwrap_or(0);
 
         let selected_style = Style::default().add_modifier(Modifier::REVERSED);
-        let normal_style = Style::default().bg(Color::DarkGray);
+        let normal_style = Style::default().bg(theme().table_header_bg);
         let header_cells: Vec<Cell> = DASHBOARD_VIEW_CONFIG_COLUMNS
             .iter()
-            .map(|h| Cell::from(&*h.label).style(Style::default().fg(Color::LightGreen)))
+            .map(|h| Cell::from(&*h.label).style(Style::default().fg(theme().table_header)))
             .collect();
 
         let header = Row::new(header_cells)
@@ -119,7 +120,7 @@ impl DashboardViewConfigDisplay {
             .header(header)
             .block(Block::default()
                 .borders(Borders::ALL)
-                .border_style(Style::default().fg(if table_style.highlight_table { Color::Yellow } else { Color::White }))
+                .border_style(Style::default().fg(theme().border(table_style.highlight_table)))
                 .title( gen_table_title_spans(table_style) )
             )
             .highlight_style(selected_style);
@@ -1,15 +1,17 @@
 
-use std::cmp::{ max };
+use std::cmp::{ max, min };
+use std::collections::HashMap;
+use std::time::{ Duration, Instant };
 
 use std::sync::{
     Arc,
     Mutex,
-    atomic::AtomicBool,
+    atomic::{ AtomicBool, Ordering },
 };
 
 use tui::{
     layout::{ Constraint },
-    style::{Color, Modifier, Style},
+    style::{Modifier, Style},
     widgets::{Block, Borders, Cell, Row, Table},
 };
 
@@ -19,15 +21,27 @@ use crate::linear::{
 };
 
 use crate::util::{
+    theme::theme,
+    date::today,
+    issue_order::PanelOrder,
     table::{ empty_str_to_fallback, format_cell_fields,
-        row_min_render_height, get_row_height, colored_cell,
+        row_min_render_height, get_row_height,
         TableStyle, gen_table_title_spans
     },
     GraphQLCursor,
 };
 
-use crate::constants::table_columns::{ VIEW_PANEL_COLUMNS };
+use crate::constants::{
+    table_columns::TableColumn,
+    view_panel_columns::{ PanelColumn, CellContext, default_panel_columns, panel_table_columns },
+};
+
+
+// Issues & cursor of an automatic refresh, covering the pages loaded when it started
+pub type RefreshResult = (Vec<Issue>, Option<GraphQLCursor>);
 
+// Issue id & the issue received via webhook, None if it left the view
+pub type IssueChange = (String, Option<Issue>);
 
 #[derive(Debug, Clone)]
 pub struct DashboardViewPanel {
@@ -35,28 +49,167 @@ pub struct DashboardViewPanel {
     pub issue_table_data: Arc<Mutex<Vec<Issue>>>,
     pub view_cursor: Arc<Mutex<Option<GraphQLCursor>>>,
     pub loading: Arc<AtomicBool>,
+
+    // Client-side sort & grouping, persisted per view
+    pub order: Arc<Mutex<PanelOrder>>,
+
+    // Columns from the registry, in display order
+    pub columns: Vec<PanelColumn>,
+
+    // Time between automatic refreshes, None if only refreshed manually
+    pub refresh_interval: Option<Duration>,
+    // When the panel's first page was last fetched
+    pub refreshed_at: Arc<Mutex<Instant>>,
+    // Merged on the next tick
+    pub refresh_result: Arc<Mutex<Option<RefreshResult>>>,
+    // When issues were last patched by a modification, keyed by issue id
+    pub modified_at: Arc<Mutex<HashMap<String, Instant>>>,
+    // Merged on the next tick
+    pub issue_changes: Arc<Mutex<Vec<IssueChange>>>,
+    // Issues added or changed by a refresh, highlighted until the Instant
+    pub highlighted_until: HashMap<String, Instant>,
 }
 
+// How long rows changed by a refresh stay highlighted
+const REFRESH_HIGHLIGHT_DURATION: Duration = Duration::from_secs(3);
+
 impl DashboardViewPanel {
     pub fn with_view(f: CustomView) -> DashboardViewPanel {
         DashboardViewPanel {
             view: f,
-            issue_table_data: Arc::new(Mutex::new(Vec::new())),
-            view_cursor: Arc::new(Mutex::new(None)),
-            loading: Arc::new(AtomicBool::new(false)),
+            ..DashboardViewPanel::default()
+        }
+    }
+
+    // Returns:
+    //     true if the panel has an interval that has passed & isn't already loading
+    pub fn refresh_due(&self) -> bool {
+        match self.refresh_interval {
+            Some(interval) => !self.loading.load(Ordering::Relaxed) && self.refreshed_at.lock().unwrap().elapsed() >= interval,
+            None => false,
+        }
+    }
+
+    // A refresh started before now keeps the issue as patched, rather than what it fetched
+    pub fn mark_modified(&self, issue_id: &str) {
+        self.modified_at.lock().unwrap().insert(issue_id.to_string(), Instant::now());
+    }
+
+    // Replace the panel's issues with those of a completed automatic refresh, highlighting new & changed issues
+    // Issues modified after the refresh started may have been fetched before the modification, so are kept as patched
+    // Accepts:
+    //     selected_idx: the selected row, if this panel is selected
+    // Returns:
+    //     the row of the previously selected issue, or the nearest row if it was removed
+    pub fn apply_refresh_result(&mut self, selected_idx: Option<usize>) -> Option<usize> {
+        let (issues, cursor) = self.refresh_result.lock().unwrap().take()?;
+
+        let mut issue_table_data_lock = self.issue_table_data.lock().unwrap();
+
+        let now = Instant::now();
+        self.highlighted_until.retain(|_, until| *until > now);
+
+        // Modifications before the refresh started are included in its result
+        let refreshed_at = *self.refreshed_at.lock().unwrap();
+        let mut modified_at_lock = self.modified_at.lock().unwrap();
+        modified_at_lock.retain(|_, modified_at| *modified_at > refreshed_at);
+
+        let issues: Vec<Issue> = issues.into_iter()
+            .map(|issue| {
+                if modified_at_lock.contains_key(&issue.id) {
+                    issue_table_data_lock.iter().find(|x| x.id == issue.id).cloned().unwrap_or(issue)
+                } else {
+                    issue
+                }
+            })
+            .collect();
+        drop(modified_at_lock);
+
+        // Issues don't implement PartialEq, so compare their serialized fields
+        let previous: HashMap<&str, serde_json::Value> = issue_table_data_lock.iter()
+            .map(|issue| (issue.id.as_str(), serde_json::to_value(issue).unwrap_or_default()))
+            .collect();
+        for issue in issues.iter() {
+            let changed = previous.get(issue.id.as_str())
+                .map(|prev| *prev != serde_json::to_value(issue).unwrap_or_default())
+                .unwrap_or(true);
+            if changed {
+                self.highlighted_until.insert(issue.id.clone(), now + REFRESH_HIGHLIGHT_DURATION);
+            }
         }
+
+        let selected_id: Option<String> = selected_idx
+            .and_then(|idx| issue_table_data_lock.get(idx))
+            .map(|issue| issue.id.clone());
+
+        *issue_table_data_lock = issues;
+        *self.view_cursor.lock().unwrap() = cursor;
+        self.loading.store(false, Ordering::Relaxed);
+
+        DashboardViewPanel::reselect(&issue_table_data_lock, selected_id, selected_idx)
     }
 
-    pub fn render<'a>(table_data: &[Issue], widths: &[Constraint], table_style: TableStyle) -> Result<Table<'a>, &'static str> {
+    // Insert, replace or remove the issues received via webhook, highlighting inserted & replaced issues
+    // Accepts:
+    //     selected_idx: the selected row, if this panel is selected
+    // Returns:
+    //     the row of the previously selected issue, or the nearest row if it was removed
+    pub fn apply_issue_changes(&mut self, selected_idx: Option<usize>) -> Option<usize> {
+        let changes = std::mem::take(&mut *self.issue_changes.lock().unwrap());
+
+        let mut issue_table_data_lock = self.issue_table_data.lock().unwrap();
+
+        let selected_id: Option<String> = selected_idx
+            .and_then(|idx| issue_table_data_lock.get(idx))
+            .map(|issue| issue.id.clone());
+
+        let now = Instant::now();
+        self.highlighted_until.retain(|_, until| *until > now);
+
+        for (issue_id, issue) in changes.into_iter() {
+            let existing_idx = issue_table_data_lock.iter().position(|x| x.id == issue_id);
+            match (issue, existing_idx) {
+                (Some(issue), Some(idx)) => {
+                    issue_table_data_lock[idx] = issue;
+                    self.highlighted_until.insert(issue_id, now + REFRESH_HIGHLIGHT_DURATION);
+                },
+                // New issues are shown first, unless the panel is sorted
+                (Some(issue), None) => {
+                    issue_table_data_lock.insert(0, issue);
+                    self.highlighted_until.insert(issue_id, now + REFRESH_HIGHLIGHT_DURATION);
+                },
+                (None, Some(idx)) => {
+                    issue_table_data_lock.remove(idx);
+                },
+                (None, None) => {},
+            }
+        }
+
+        self.order.lock().unwrap().apply(&mut issue_table_data_lock);
+
+        DashboardViewPanel::reselect(&issue_table_data_lock, selected_id, selected_idx)
+    }
+
+    // Row of the issue with 'selected_id', or the row nearest 'selected_idx' if it was removed
+    fn reselect(issues: &[Issue], selected_id: Option<String>, selected_idx: Option<usize>) -> Option<usize> {
+        let selected_idx = selected_idx?;
+        selected_id
+            .and_then(|id| issues.iter().position(|issue| issue.id == id))
+            .or_else(|| issues.len().checked_sub(1).map(|last| min(selected_idx, last)))
+    }
+
+    pub fn render<'a>(table_data: &[Issue], columns: &[PanelColumn], widths: &[Constraint], table_style: TableStyle, due_soon_day_threshold: u32, order: &PanelOrder, highlighted_until: &HashMap<String, Instant>) -> Result<Table<'a>, &'static str> {
+
+        let table_columns: Vec<TableColumn> = panel_table_columns(columns);
 
         let bottom_margin = table_style.row_bottom_margin.unwrap_or(0);
 
         let selected_style = Style::default().add_modifier(Modifier::REVERSED);
-        let normal_style = Style::default().bg(Color::DarkGray);
+        let normal_style = Style::default().bg(theme().table_header_bg);
 
-        let header_cells: Vec<Cell> = VIEW_PANEL_COLUMNS
+        let header_cells: Vec<Cell> = table_columns
             .iter()
-            .map(|h| Cell::from(&*h.label).style(Style::default().fg(Color::LightGreen)))
+            .map(|h| Cell::from(&*h.label).style(Style::default().fg(theme().table_header)))
             .collect();
 
         let header = Row::new(header_cells)
@@ -76,42 +229,48 @@ impl DashboardViewPanel {
             .iter()
             .map(|issue| {
 
-                let cell_fields: Vec<String> = 
+                let cell_values: Vec<String> = columns.iter().map(|x| (x.def.cell_value)(issue)).collect();
+                let cell_fields: Vec<String> =
                     empty_str_to_fallback(
-                        &[  &issue.number.to_string().clone(),
-                            &issue.title.clone(),
-                            &issue.state.name.clone(),
-                            issue.description.as_deref().unwrap_or(""),
-                            &issue.created_at.clone()
-                        ],
-                        &VIEW_PANEL_COLUMNS
+                        &cell_values.iter().map(|x| x.as_str()).collect::<Vec<&str>>(),
+                        &table_columns
                     );
 
                 cell_fields_list.push(cell_fields.clone());
 
-                row_min_render_height(&cell_fields, widths, &VIEW_PANEL_COLUMNS)
+                row_min_render_height(&cell_fields, widths, &table_columns)
             })
             .max();
 
+        let cell_ctx = CellContext { today: today(), due_soon_day_threshold };
+
         let mut rows: Vec<Row> = table_data.iter()
             .enumerate()
             .map(|(idx, issue)| {
 
                 // Get the formatted Strings for each cell field
-                let cell_fields_formatted: Vec<String> = format_cell_fields(&cell_fields_list[idx], widths, &VIEW_PANEL_COLUMNS, max_row_size_opt);
+                let cell_fields_formatted: Vec<String> = format_cell_fields(&cell_fields_list[idx], widths, &table_columns, max_row_size_opt);
 
                 max_seen_row_size = max(get_row_height(&cell_fields_formatted), max_seen_row_size);
 
-                let mut cells: Vec<Cell> = cell_fields_formatted.iter().map(|c| Cell::from(c.clone())).collect();
-
-                let name = cell_fields_formatted[2].clone();
-
-                // Insert new "state" cell, and remove unformatted version
-                cells.insert(2, colored_cell(name, &issue.state.color.clone()));
-                cells.remove(3);
-
-                Row::new(cells)
-                    .bottom_margin(bottom_margin)
+                // Color each cell as specified by its column
+                let cells: Vec<Cell> = cell_fields_formatted.iter()
+                    .zip(columns.iter())
+                    .map(|(field, column)| {
+                        match (column.def.cell_color)(issue, &cell_ctx) {
+                            Some(color) => Cell::from(field.clone()).style(Style::default().fg(color)),
+                            None => Cell::from(field.clone()),
+                        }
+                    })
+                    .collect();
+
+                let row = Row::new(cells)
+                    .bottom_margin(bottom_margin);
+
+                match highlighted_until.get(&issue.id) {
+                    Some(until) if *until > Instant::now() => row.style(Style::default().bg(theme().refreshed_row)),
+                    _ => row,
+                }
             })
             .collect();
 
@@ -127,10 +286,31 @@ impl DashboardViewPanel {
             })
             .collect();
 
+        // Insert a header row above each group, see PanelOrder::display_row_idx()
+        if order.group.is_some() {
+            let group_style = Style::default().add_modifier(Modifier::BOLD).fg(theme().group_header);
+            let mut grouped_rows: Vec<Row> = Vec::with_capacity(rows.len());
+
+            for (idx, row) in rows.into_iter().enumerate() {
+                let group_label = order.group_label(&table_data[idx]);
+                if idx == 0 || group_label != order.group_label(&table_data[idx-1]) {
+                    let group_size = table_data[idx..].iter()
+                        .take_while(|issue| order.group_label(issue) == group_label)
+                        .count();
+                    grouped_rows.push(
+                        Row::new(vec![Cell::from(format!("{} ({})", group_label.unwrap_or_default(), group_size))])
+                            .style(group_style)
+                            .height(1)
+                    );
+                }
+                grouped_rows.push(row);
+            }
+            rows = grouped_rows;
+        }
 
         let table_block = Block::default()
                                     .borders(Borders::ALL)
-                                    .border_style(Style::default().fg(if table_style.highlight_table { Color::Yellow } else { Color::White }))
+                                    .border_style(Style::default().fg(theme().border(table_style.highlight_table)))
                                     .title( gen_table_title_spans(table_style) );
 
         let t = Table::new(rows)
@@ -155,6 +335,14 @@ impl Default for DashboardViewPanel {
             issue_table_data: Arc::new(Mutex::new(Vec::new())),
             view_cursor: Arc::new(Mutex::new(None)),
             loading: Arc::new(AtomicBool::new(false)),
+            order: Arc::new(Mutex::new(PanelOrder::default())),
+            columns: default_panel_columns(),
+            refresh_interval: None,
+            refreshed_at: Arc::new(Mutex::new(Instant::now())),
+            refresh_result: Arc::new(Mutex::new(None)),
+            modified_at: Arc::new(Mutex::new(HashMap::new())),
+            issue_changes: Arc::new(Mutex::new(Vec::new())),
+            highlighted_until: HashMap::new(),
         }
     }
 }
@@ -0,0 +1,199 @@
+use tui::{
+    backend::Backend,
+    layout::{ Alignment, Constraint, Direction, Rect, Layout },
+    style::{ Modifier, Style },
+    text::{ Span, Spans, Text },
+    widgets::{ Block, Borders, Clear, Paragraph, Wrap },
+    Frame,
+};
+
+use chrono::{ Datelike, Duration, NaiveDate };
+
+use crate::components::user_input::UserInput;
+
+use crate::constants::colors;
+
+use crate::util::date::{ today, parse_timeless_date, format_timeless_date, parse_relative_date };
+
+// Entered to remove the Issue's due date
+const CLEAR_INPUTS: [&str; 2] = ["none", "clear"];
+
+// Trailing space matches the day cell separators, keeping centered rows aligned
+const WEEKDAY_HEADER: &str = "Mo Tu We Th Fr Sa Su ";
+
+
+#[derive(Debug)]
+pub struct DueDatePicker {
+    pub selected: NaiveDate,
+    // Issue's due date when the picker was opened
+    pub original: Option<NaiveDate>,
+    pub input: UserInput,
+    pub input_invalid: bool,
+    // Remove the due date instead of setting 'selected'
+    pub cleared: bool,
+}
+
+impl DueDatePicker {
+
+    // Start from the Issue's current due date, or today if unset
+    pub fn init(&mut self, due_date: Option<&str>) {
+        self.original = due_date.and_then(parse_timeless_date);
+        self.selected = self.original.unwrap_or_else(today);
+        self.input.set_input(String::new());
+        self.input_invalid = false;
+        self.cleared = false;
+    }
+
+    pub fn shift_days(&mut self, days: i64) {
+        if let Some(date) = self.selected.checked_add_signed(Duration::days(days)) {
+            self.selected = date;
+        }
+        self.cleared = false;
+    }
+
+    // Apply the entered date expression, relative expressions are resolved from today
+    pub fn apply_input(&mut self) -> bool {
+        if CLEAR_INPUTS.contains(&self.input.input.trim().to_lowercase().as_str()) {
+            self.cleared = true;
+            self.input.set_input(String::new());
+            self.input_invalid = false;
+            return true;
+        }
+
+        match parse_relative_date(&self.input.input, today()) {
+            Some(date) => {
+                self.selected = date;
+                self.cleared = false;
+                self.input.set_input(String::new());
+                self.input_invalid = false;
+                true
+            },
+            None => {
+                self.input_invalid = true;
+                false
+            }
+        }
+    }
+
+    // TimelessDate to send as IssueUpdateInput.due_date
+    pub fn selected_timeless_date(&self) -> String {
+        format_timeless_date(&self.selected)
+    }
+
+    fn month_lines<'a>(&self) -> Vec<Spans<'a>> {
+        let first_of_month = NaiveDate::from_ymd(self.selected.year(), self.selected.month(), 1);
+        let today = today();
+
+        let mut lines: Vec<Spans> = vec![
+            Spans::from(Span::styled(first_of_month.format("%B %Y").to_string(), Style::default().add_modifier(Modifier::BOLD))),
+            Spans::from(Span::styled(WEEKDAY_HEADER, Style::default().fg(colors::DUE_DATE_PICKER_WEEKDAY))),
+        ];
+
+        // Each day is a cell span followed by a separator span,
+        // pad the first week up to the weekday of the 1st
+        let blank_day = [Span::raw("  "), Span::raw(" ")];
+        let mut week: Vec<Span> = blank_day.iter().cloned().cycle()
+            .take(2 * first_of_month.weekday().num_days_from_monday() as usize)
+            .collect();
+
+        let mut day = first_of_month;
+        while day.month() == first_of_month.month() {
+            let mut style = Style::default();
+            if Some(day) == self.original {
+                style = style.fg(colors::DUE_DATE_PICKER_CURRENT);
+            }
+            if day == today {
+                style = style.add_modifier(Modifier::UNDERLINED);
+            }
+            if day == self.selected && !self.cleared {
+                style = style.add_modifier(Modifier::REVERSED | Modifier::BOLD);
+            }
+
+            week.push(Span::styled(format!("{:>2}", day.day()), style));
+            week.push(Span::raw(" "));
+
+            if week.len() >= 14 {
+                lines.push(Spans::from(week));
+                week = Vec::new();
+            }
+            day = day.succ();
+        }
+
+        if !week.is_empty() {
+            while week.len() < 14 {
+                week.extend(blank_day.iter().cloned());
+            }
+            lines.push(Spans::from(week));
+        }
+
+        lines
+    }
+
+    pub fn gen_help_msg<'a>(&self) -> Paragraph<'a> {
+        let bold = Style::default().add_modifier(Modifier::BOLD);
+
+        let mut lines = vec![
+            Spans::from(vec![
+                Span::styled("Up/Down", bold), Span::raw(" change day, "),
+                Span::styled("Right", bold), Span::raw(" to confirm "),
+                Span::raw(if self.cleared { String::from("no due date") } else { self.selected_timeless_date() }),
+            ]),
+            Spans::from(vec![
+                Span::styled("e", bold), Span::raw(" to enter a date: 2022-03-21, +3d, -1w, +1m, tomorrow, next fri, or none to remove it"),
+            ]),
+        ];
+
+        if self.input_invalid {
+            lines.push(Spans::from(Span::styled("Unrecognized date", Style::default().fg(colors::RED))));
+        }
+
+        Paragraph::new(Text::from(lines)).wrap(Wrap { trim: true })
+    }
+
+    pub fn render<B>(&self, f: &mut Frame<B>, area: Rect)
+    where B: Backend,
+    {
+        f.render_widget(Clear, area);
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(Span::styled("Select Due Date", Style::default().fg(colors::ISSUE_MODIFICATION_TABLE_TITLE)));
+        let inner = block.inner(area);
+        f.render_widget(block, area);
+
+        // Calendar, help/status msg, input box
+        let row_layout = Layout::default()
+            .direction(Direction::Vertical)
+            .margin(1)
+            .constraints(
+                [
+                    Constraint::Length(9),
+                    Constraint::Length(4),
+                    Constraint::Length(3),
+                ]
+                .as_ref(),
+            )
+            .split(inner);
+
+        f.render_widget(
+            Paragraph::new(Text::from(self.month_lines())).alignment(Alignment::Center),
+            row_layout[0]
+        );
+
+        f.render_widget(self.gen_help_msg(), row_layout[1]);
+
+        self.input.render(f, row_layout[2]);
+    }
+}
+
+impl Default for DueDatePicker {
+    fn default() -> DueDatePicker {
+        DueDatePicker {
+            selected: today(),
+            original: None,
+            input: UserInput::new(false),
+            input_invalid: false,
+            cleared: false,
+        }
+    }
+}
@@ -0,0 +1,281 @@
+use std::sync::{ Arc, Mutex };
+use std::sync::atomic::{ AtomicBool, Ordering };
+
+use chrono::{ DateTime, Local };
+
+use tui::{
+    backend::Backend,
+    layout::Rect,
+    style::{ Modifier, Style },
+    text::{ Span, Spans },
+    widgets::{ Block, Borders, Clear, List, ListItem, ListState },
+    Frame,
+};
+
+use crate::constants::{ IssueModificationOp, ISSUE_PRIORITY_LABELS };
+use crate::constants::colors::GREEN;
+
+use crate::linear::schema::{ Issue, IssueUpdateInput };
+
+use crate::util::{
+    list_state,
+    keybindings::display_key,
+};
+
+// Oldest modifications can't be undone past this
+const MAX_MODIFICATIONS: usize = 100;
+
+// A successful modification of one Issue field, with the Issue as cached before & after
+#[derive(Debug, Clone)]
+pub struct IssueModification {
+    pub issue_id: String,
+    // Profile whose backend the Issue was modified with
+    pub profile: String,
+    pub op: IssueModificationOp,
+    pub before: Issue,
+    pub after: Issue,
+    pub time: DateTime<Local>,
+}
+
+impl IssueModification {
+
+    // e.g. "Assignee of #42 'Fix login': Alice -> Bob"
+    pub fn describe(&self) -> String {
+        format!(
+            "{:?} of #{} '{}': {} -> {}",
+            self.op,
+            self.before.number,
+            self.after.title,
+            field_summary(&self.before, self.op),
+            field_summary(&self.after, self.op),
+        )
+    }
+}
+
+// The modified field of an Issue, as shown in the history list
+fn field_summary(issue: &Issue, op: IssueModificationOp) -> String {
+    let none = || String::from("None");
+    match op {
+        IssueModificationOp::Title => format!("'{}'", issue.title),
+        IssueModificationOp::WorkflowState => issue.state.name.clone(),
+        IssueModificationOp::Assignee => issue.assignee.as_ref().map(|x| x.display_name.clone()).unwrap_or_else(none),
+        IssueModificationOp::Project => issue.project.as_ref().map(|x| x.name.clone()).unwrap_or_else(none),
+        IssueModificationOp::Cycle => issue.cycle.as_ref().and_then(|x| x.name.clone()).unwrap_or_else(none),
+        IssueModificationOp::Team => issue.team.name.clone(),
+        IssueModificationOp::Labels => {
+            let names: Vec<&str> = issue.labels.nodes.iter().map(|x| x.name.as_str()).collect();
+            if names.is_empty() { none() } else { names.join(", ") }
+        },
+        IssueModificationOp::Priority => ISSUE_PRIORITY_LABELS.get(issue.priority as usize)
+            .map(|x| x.to_string())
+            .unwrap_or_else(none),
+        IssueModificationOp::Estimate => issue.estimate.map(|x| x.to_string()).unwrap_or_else(none),
+        IssueModificationOp::DueDate => issue.due_date.clone().unwrap_or_else(none),
+    }
+}
+
+// The update setting the 'op' field(s) of an Issue to their value in 'snapshot'
+// Returns:
+//     (update, fields which are unset in 'snapshot' & have to be cleared)
+pub fn restore_input(snapshot: &Issue, op: IssueModificationOp) -> (IssueUpdateInput, Vec<IssueModificationOp>) {
+    let mut update = IssueUpdateInput::default();
+    let mut cleared: Vec<IssueModificationOp> = Vec::new();
+
+    match op {
+        IssueModificationOp::Title => {
+            update.title = Some(snapshot.title.clone());
+        },
+        IssueModificationOp::WorkflowState => {
+            update.state_id = Some(snapshot.state.id.clone());
+        },
+        IssueModificationOp::Assignee => match &snapshot.assignee {
+            Some(assignee) => update.assignee_id = Some(assignee.id.clone()),
+            None => cleared.push(op),
+        },
+        IssueModificationOp::Project => match &snapshot.project {
+            Some(project) => update.project_id = Some(project.id.clone()),
+            None => cleared.push(op),
+        },
+        IssueModificationOp::Cycle => match &snapshot.cycle {
+            Some(cycle) => update.cycle_id = Some(cycle.id.clone()),
+            None => cleared.push(op),
+        },
+        // The workflow state, cycle & project are scoped to the team, so are restored with it
+        IssueModificationOp::Team => {
+            update.team_id = Some(snapshot.team.id.clone());
+            update.state_id = Some(snapshot.state.id.clone());
+            match &snapshot.cycle {
+                Some(cycle) => update.cycle_id = Some(cycle.id.clone()),
+                None => cleared.push(IssueModificationOp::Cycle),
+            }
+            match &snapshot.project {
+                Some(project) => update.project_id = Some(project.id.clone()),
+                None => cleared.push(IssueModificationOp::Project),
+            }
+        },
+        IssueModificationOp::Labels => {
+            update.label_ids = Some(snapshot.labels.nodes.iter().map(|x| x.id.clone()).collect());
+        },
+        IssueModificationOp::Priority => {
+            update.priority = Some(snapshot.priority as i64);
+        },
+        IssueModificationOp::Estimate => match snapshot.estimate {
+            Some(estimate) => update.estimate = Some(estimate as i64),
+            None => cleared.push(op),
+        },
+        IssueModificationOp::DueDate => match &snapshot.due_date {
+            Some(due_date) => update.due_date = Some(due_date.clone()),
+            None => cleared.push(op),
+        },
+    }
+
+    (update, cleared)
+}
+
+// Copy the 'op' field(s) of 'snapshot' into a cached Issue
+pub fn restore_fields(issue: &mut Issue, snapshot: &Issue, op: IssueModificationOp) {
+    match op {
+        IssueModificationOp::Title => issue.title = snapshot.title.clone(),
+        IssueModificationOp::WorkflowState => issue.state = snapshot.state.clone(),
+        IssueModificationOp::Assignee => issue.assignee = snapshot.assignee.clone(),
+        IssueModificationOp::Project => issue.project = snapshot.project.clone(),
+        IssueModificationOp::Cycle => issue.cycle = snapshot.cycle.clone(),
+        IssueModificationOp::Team => {
+            issue.team = snapshot.team.clone();
+            issue.state = snapshot.state.clone();
+            issue.cycle = snapshot.cycle.clone();
+            issue.project = snapshot.project.clone();
+        },
+        IssueModificationOp::Labels => issue.labels = snapshot.labels.clone(),
+        IssueModificationOp::Priority => issue.priority = snapshot.priority,
+        IssueModificationOp::Estimate => issue.estimate = snapshot.estimate,
+        IssueModificationOp::DueDate => issue.due_date = snapshot.due_date.clone(),
+    }
+}
+
+// Undo & redo stacks shared with background tasks, clones push to the same stacks
+#[derive(Debug, Clone, Default)]
+pub struct IssueHistory {
+    undo: Arc<Mutex<Vec<IssueModification>>>,
+    redo: Arc<Mutex<Vec<IssueModification>>>,
+    // Set while an undo or redo is in flight, so they apply in order
+    pub pending: Arc<AtomicBool>,
+}
+
+impl IssueHistory {
+
+    // A new modification, invalidates the redo stack
+    pub fn record(&self, modification: IssueModification) {
+        self.push_undo(modification);
+        self.redo.lock().unwrap().clear();
+    }
+
+    pub fn push_undo(&self, modification: IssueModification) {
+        let mut undo_lock = self.undo.lock().unwrap();
+        undo_lock.push(modification);
+
+        if undo_lock.len() > MAX_MODIFICATIONS {
+            let excess = undo_lock.len() - MAX_MODIFICATIONS;
+            undo_lock.drain(..excess);
+        }
+    }
+
+    pub fn push_redo(&self, modification: IssueModification) {
+        self.redo.lock().unwrap().push(modification);
+    }
+
+    pub fn pop_undo(&self) -> Option<IssueModification> {
+        self.undo.lock().unwrap().pop()
+    }
+
+    pub fn pop_redo(&self) -> Option<IssueModification> {
+        self.redo.lock().unwrap().pop()
+    }
+
+    pub fn can_undo(&self) -> bool {
+        !self.is_pending() && !self.undo.lock().unwrap().is_empty()
+    }
+
+    pub fn can_redo(&self) -> bool {
+        !self.is_pending() && !self.redo.lock().unwrap().is_empty()
+    }
+
+    pub fn is_pending(&self) -> bool {
+        self.pending.load(Ordering::Relaxed)
+    }
+
+    // Newest first, undoable modifications are preceded by redoable ones
+    // Returns:
+    //     Vec<(modification, whether it's on the redo stack)>
+    pub fn entries(&self) -> Vec<(IssueModification, bool)> {
+        let undo_lock = self.undo.lock().unwrap();
+        let redo_lock = self.redo.lock().unwrap();
+
+        redo_lock.iter().map(|x| (x.clone(), true))
+            .chain(undo_lock.iter().rev().map(|x| (x.clone(), false)))
+            .collect()
+    }
+}
+
+// A scrollable list of the undo & redo stacks
+#[derive(Debug, Default)]
+pub struct IssueHistoryLog {
+    pub history: IssueHistory,
+    pub open: bool,
+    pub list_state: ListState,
+}
+
+impl IssueHistoryLog {
+
+    pub fn toggle(&mut self) {
+        self.open = !self.open;
+        self.list_state = ListState::default();
+        if self.open && !self.history.entries().is_empty() {
+            self.list_state.select(Some(0));
+        }
+    }
+
+    pub fn scroll_down(&mut self) {
+        let entries = self.history.entries();
+        if !entries.is_empty() {
+            list_state::next(&mut self.list_state, &entries);
+        }
+    }
+
+    pub fn scroll_up(&mut self) {
+        let entries = self.history.entries();
+        if !entries.is_empty() {
+            list_state::previous(&mut self.list_state, &entries);
+        }
+    }
+
+    pub fn render<B>(&self, f: &mut Frame<B>, area: Rect)
+    where B: Backend,
+    {
+        let entries = self.history.entries();
+
+        // Undone modifications are dimmed until redone
+        let items: Vec<ListItem> = entries.iter()
+            .map(|(x, undone)| {
+                let style = if *undone { Style::default().add_modifier(Modifier::DIM) } else { Style::default().fg(GREEN) };
+                ListItem::new(Spans::from(vec![
+                    Span::styled(x.time.format("%H:%M:%S ").to_string(), Style::default().add_modifier(Modifier::DIM)),
+                    Span::styled(x.describe(), style),
+                ]))
+            })
+            .collect();
+
+        let title = format!(
+            "Issue History ({}) - '{}': Undo, '{}': Redo",
+            entries.len(), display_key('U'), display_key('Y'),
+        );
+
+        let list = List::new(items)
+            .block(Block::default().borders(Borders::ALL).title(title))
+            .highlight_style(Style::default().add_modifier(Modifier::REVERSED));
+
+        let mut list_state = self.list_state.clone();
+        f.render_widget(Clear, area);
+        f.render_stateful_widget(list, area, &mut list_state);
+    }
+}
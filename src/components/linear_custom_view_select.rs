@@ -2,7 +2,7 @@ use std::cmp::max;
 
 use tui::{
     layout::{Constraint},
-    style::{Color, Modifier, Style},
+    style::{Modifier, Style},
     widgets::{Block, Borders, Cell, Row, Table, TableState},
 };
 
@@ -17,6 +17,7 @@ use crate::linear::{
 };
 
 use crate::util::{
+    theme::theme,
     table::{ empty_str_to_fallback, format_cell_fields,
         row_min_render_height, get_row_height, colored_cell,
         TableStyle, gen_table_title_spans
@@ -42,10 +43,10 @@ impl LinearCustomViewSelect {
         let bottom_margin = table_style.row_bottom_margin.unwrap_or(0);
 
         let selected_style = Style::default().add_modifier(Modifier::REVERSED);
-        let normal_style = Style::default().bg(Color::DarkGray);
+        let normal_style = Style::default().bg(theme().table_header_bg);
         let header_cells: Vec<Cell> = CUSTOM_VIEW_SELECT_COLUMNS
             .iter()
-            .map(|h| Cell::from(&*h.label).style(Style::default().fg(Color::LightGreen)))
+            .map(|h| Cell::from(&*h.label).style(Style::default().fg(theme().table_header)))
             .collect();
 
 
@@ -125,7 +126,7 @@ impl LinearCustomViewSelect {
 
         let table_block = Block::default()
             .borders(Borders::ALL)
-            .border_style(Style::default().fg(if table_style.highlight_table { Color::Yellow } else { Color::White }))
+            .border_style(Style::default().fg(theme().border(table_style.highlight_table)))
             .title( gen_table_title_spans(table_style) );
 
 
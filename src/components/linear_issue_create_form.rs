@@ -0,0 +1,374 @@
+use tui::{
+    backend::Backend,
+    layout::{ Constraint, Direction, Layout, Rect },
+    style::{ Modifier, Style },
+    text::{ Span, Spans, Text },
+    widgets::{ Block, Borders, Cell, Paragraph, Row, Table, TableState },
+    Frame,
+};
+
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::components::user_input::UserInput;
+
+use crate::linear::{
+    client::{ IssueFieldObject },
+    schema::{ Team, State, TeamMember, Label, Project, Cycle, IssueCreateInput },
+};
+
+use crate::util::{
+    theme::theme,
+    table::{ empty_str_to_fallback, format_cell_fields, row_min_render_height, colored_cell },
+    layout::{ widths_from_rect },
+    table_state,
+};
+
+use crate::constants::{
+    IssueModificationOp,
+    ISSUE_PRIORITY_LABELS,
+    colors::{ RED },
+    table_columns::{ ISSUE_CREATE_FORM_COLUMNS },
+};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum IssueCreateField {
+    Team,
+    Title,
+    Description,
+    State,
+    Assignee,
+    Priority,
+    Labels,
+    Project,
+    Cycle,
+    Submit,
+}
+
+pub const ISSUE_CREATE_FIELDS: [IssueCreateField; 10] = [
+    IssueCreateField::Team,
+    IssueCreateField::Title,
+    IssueCreateField::Description,
+    IssueCreateField::State,
+    IssueCreateField::Assignee,
+    IssueCreateField::Priority,
+    IssueCreateField::Labels,
+    IssueCreateField::Project,
+    IssueCreateField::Cycle,
+    IssueCreateField::Submit,
+];
+
+impl IssueCreateField {
+    pub fn label(&self) -> &'static str {
+        match self {
+            IssueCreateField::Team => "Team",
+            IssueCreateField::Title => "Title",
+            IssueCreateField::Description => "Description",
+            IssueCreateField::State => "State",
+            IssueCreateField::Assignee => "Assignee",
+            IssueCreateField::Priority => "Priority",
+            IssueCreateField::Labels => "Labels",
+            IssueCreateField::Project => "Project",
+            IssueCreateField::Cycle => "Cycle",
+            IssueCreateField::Submit => "Create Issue",
+        }
+    }
+
+    // The LinearIssueOpInterface table used to select a value for this field, if any
+    pub fn selection_op(&self) -> Option<IssueModificationOp> {
+        match self {
+            IssueCreateField::Team => Some(IssueModificationOp::Team),
+            IssueCreateField::State => Some(IssueModificationOp::WorkflowState),
+            IssueCreateField::Assignee => Some(IssueModificationOp::Assignee),
+            IssueCreateField::Priority => Some(IssueModificationOp::Priority),
+            IssueCreateField::Labels => Some(IssueModificationOp::Labels),
+            IssueCreateField::Project => Some(IssueModificationOp::Project),
+            IssueCreateField::Cycle => Some(IssueModificationOp::Cycle),
+            _ => None,
+        }
+    }
+
+    pub fn is_text(&self) -> bool {
+        matches!(self, IssueCreateField::Title | IssueCreateField::Description)
+    }
+}
+
+
+#[derive(Debug)]
+pub struct LinearIssueCreateForm {
+    pub field_state: TableState,
+
+    pub team: Option<Team>,
+    pub title_input: UserInput,
+    pub description_input: UserInput,
+    pub state: Option<State>,
+    pub assignee: Option<TeamMember>,
+    // index into ISSUE_PRIORITY_LABELS, matches Linear's priority values
+    pub priority: usize,
+    pub labels: Vec<Label>,
+    pub project: Option<Project>,
+    pub cycle: Option<Cycle>,
+
+    pub validation_msg: Option<String>,
+}
+
+impl LinearIssueCreateForm {
+
+    pub fn with_team(team: Option<Team>) -> LinearIssueCreateForm {
+        LinearIssueCreateForm {
+            team,
+            ..LinearIssueCreateForm::default()
+        }
+    }
+
+    pub fn selected_field(&self) -> IssueCreateField {
+        ISSUE_CREATE_FIELDS[self.field_state.selected().unwrap_or(0)]
+    }
+
+    pub fn next_field(&mut self) {
+        table_state::next(&mut self.field_state, &ISSUE_CREATE_FIELDS);
+    }
+
+    pub fn previous_field(&mut self) {
+        table_state::previous(&mut self.field_state, &ISSUE_CREATE_FIELDS);
+    }
+
+    // Input of the selected text field, if a text field is selected
+    pub fn input_mut(&mut self) -> Option<&mut UserInput> {
+        match self.selected_field() {
+            IssueCreateField::Title => Some(&mut self.title_input),
+            IssueCreateField::Description => Some(&mut self.description_input),
+            _ => None,
+        }
+    }
+
+    // Store a value selected from the LinearIssueOpInterface,
+    // Labels are toggled, since an issue can have multiple
+    pub fn set_field_value(&mut self, obj: IssueFieldObject) {
+        match obj {
+            IssueFieldObject::Team(team) => {
+                // All other selectable fields are scoped to the team
+                if self.team.as_ref().map(|e| e.id != team.id).unwrap_or(true) {
+                    self.state = None;
+                    self.assignee = None;
+                    self.labels = Vec::new();
+                    self.project = None;
+                    self.cycle = None;
+                }
+                self.team = Some(team);
+            },
+            IssueFieldObject::State(state) => { self.state = Some(state); },
+            IssueFieldObject::TeamMember(member) => { self.assignee = Some(member); },
+            IssueFieldObject::Label(label) => {
+                if let Some(idx) = self.labels.iter().position(|e| e.id == label.id) {
+                    self.labels.remove(idx);
+                } else {
+                    self.labels.push(label);
+                }
+            },
+            IssueFieldObject::Project(project) => { self.project = Some(project); },
+            IssueFieldObject::Cycle(cycle) => { self.cycle = Some(cycle); },
+            IssueFieldObject::Priority(priority) => { self.priority = priority.value as usize; },
+            // Estimate is not part of the form
+            IssueFieldObject::Estimate(_) => {},
+        }
+        self.validation_msg = None;
+    }
+
+    // Set Labels toggled in the multi-select IssueModificationOp::Labels table
+    pub fn set_labels(&mut self, labels: Vec<Label>) {
+        self.labels = labels;
+        self.validation_msg = None;
+    }
+
+    // Returns:
+    //     IssueCreateInput if a team & title have been provided,
+    //     otherwise sets validation_msg and returns None
+    pub fn validated_create_input(&mut self) -> Option<IssueCreateInput> {
+        let team_id = match &self.team {
+            Some(team) => team.id.clone(),
+            None => {
+                self.validation_msg = Some("A Team is required".to_string());
+                return None;
+            }
+        };
+
+        if self.title_input.input.graphemes(true).count() == 0 {
+            self.validation_msg = Some("A Title is required".to_string());
+            return None;
+        }
+
+        Some(IssueCreateInput {
+            id: None,
+            title: self.title_input.input.clone(),
+            description: if self.description_input.input.is_empty() { None } else { Some(self.description_input.input.clone()) },
+            description_data: None,
+            assignee_id: self.assignee.as_ref().map(|e| e.id.clone()),
+            parent_id: None,
+            priority: Some(self.priority as i64),
+            estimate: None,
+            subscriber_ids: None,
+            label_ids: if self.labels.is_empty() { None } else { Some(self.labels.iter().map(|e| e.id.clone()).collect()) },
+            team_id,
+            cycle_id: self.cycle.as_ref().map(|e| e.id.clone()),
+            project_id: self.project.as_ref().map(|e| e.id.clone()),
+            project_milestone_id: None,
+            state_id: self.state.as_ref().map(|e| e.id.clone()),
+            reference_comment_id: None,
+            board_order: None,
+            sort_order: None,
+            sub_issue_sort_order: None,
+            due_date: None,
+            create_as_user: None,
+            display_icon_url: None,
+            created_at: None,
+        })
+    }
+
+    // ( display value, color hex str ) for a field
+    fn field_value(&self, field: &IssueCreateField) -> (String, Option<String>) {
+        match field {
+            IssueCreateField::Team => (self.team.as_ref().map(|e| e.name.clone()).unwrap_or_default(), None),
+            IssueCreateField::Title => (self.title_input.input.clone(), None),
+            IssueCreateField::Description => (self.description_input.input.clone(), None),
+            IssueCreateField::State => match &self.state {
+                Some(state) => (state.name.clone(), Some(state.color.clone())),
+                None => (String::new(), None),
+            },
+            IssueCreateField::Assignee => (self.assignee.as_ref().map(|e| e.display_name.clone()).unwrap_or_default(), None),
+            IssueCreateField::Priority => (ISSUE_PRIORITY_LABELS[self.priority].to_string(), None),
+            IssueCreateField::Labels => (self.labels.iter().map(|e| e.name.as_str()).collect::<Vec<&str>>().join(", "), None),
+            IssueCreateField::Project => match &self.project {
+                Some(project) => (project.name.clone(), Some(project.color.clone())),
+                None => (String::new(), None),
+            },
+            IssueCreateField::Cycle => (self.cycle.as_ref().and_then(|e| e.name.clone()).unwrap_or_default(), None),
+            IssueCreateField::Submit => (String::new(), None),
+        }
+    }
+
+    pub fn gen_help_msg<'a>(&self, editing: bool) -> Paragraph<'a> {
+        let msg = if editing {
+            vec![
+                Span::raw("Press "),
+                Span::styled("Enter", Style::default().add_modifier(Modifier::BOLD)),
+                Span::raw(" to save field"),
+            ]
+        } else {
+            match &self.validation_msg {
+                Some(msg) => vec![Span::styled(msg.clone(), Style::default().fg(RED))],
+                None => vec![
+                    Span::styled("Right", Style::default().add_modifier(Modifier::BOLD)),
+                    Span::raw(" to edit field, "),
+                    Span::styled("Left", Style::default().add_modifier(Modifier::BOLD)),
+                    Span::raw(" to cancel"),
+                ],
+            }
+        };
+
+        Paragraph::new(Text::from(Spans::from(msg)))
+    }
+
+    pub fn render<B>(&self, f: &mut Frame<B>, area: Rect, editing: bool)
+    where B: Backend,
+    {
+        // Split into rows (top ==> fields, middle ==> help msg, bottom ==> input box while editing)
+        let row_layout = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints(
+                [
+                    Constraint::Min(ISSUE_CREATE_FIELDS.len() as u16 + 3),
+                    Constraint::Length(3),
+                    Constraint::Length(if editing { 3 } else { 0 }),
+                ]
+                .as_ref(),
+            )
+            .split(area);
+
+        // subtract 2 from width to account for single character table borders
+        let table_rect = Rect::new(row_layout[0].x, row_layout[0].y, row_layout[0].width.saturating_sub(2), row_layout[0].height);
+        let widths: Vec<Constraint> = widths_from_rect(&table_rect, &ISSUE_CREATE_FORM_COLUMNS);
+
+        let header_cells: Vec<Cell> = ISSUE_CREATE_FORM_COLUMNS
+            .iter()
+            .map(|h| Cell::from(h.label).style(Style::default().fg(theme().table_header)))
+            .collect();
+
+        let header = Row::new(header_cells)
+            .style(Style::default().bg(theme().table_header_bg))
+            .height(1)
+            .bottom_margin(1);
+
+        let rows: Vec<Row> = ISSUE_CREATE_FIELDS
+            .iter()
+            .map(|field| {
+                let (value, color) = self.field_value(field);
+
+                let cell_fields: Vec<String> = empty_str_to_fallback(&[field.label(), value.as_str()], &ISSUE_CREATE_FORM_COLUMNS);
+                let cell_fields: Vec<String> = if *field == IssueCreateField::Submit {
+                    vec![cell_fields[0].clone(), String::new()]
+                } else {
+                    cell_fields
+                };
+
+                let row_height = row_min_render_height(&cell_fields, &widths, &ISSUE_CREATE_FORM_COLUMNS);
+                let cell_fields = format_cell_fields(&cell_fields, &widths, &ISSUE_CREATE_FORM_COLUMNS, Some(row_height));
+
+                let value_cell = match color {
+                    Some(color) => colored_cell(cell_fields[1].clone(), &color),
+                    None => Cell::from(cell_fields[1].clone()),
+                };
+
+                Row::new(vec![Cell::from(cell_fields[0].clone()), value_cell])
+                    .height(row_height)
+            })
+            .collect();
+
+        let table = Table::new(rows)
+            .header(header)
+            .block(Block::default().borders(Borders::ALL).title(Span::styled("New Issue", Style::default().add_modifier(Modifier::BOLD))))
+            .highlight_style(Style::default().add_modifier(Modifier::REVERSED))
+            .widths(&widths);
+
+        let mut field_state = self.field_state.clone();
+        f.render_stateful_widget(table, row_layout[0], &mut field_state);
+
+        f.render_widget(
+            self.gen_help_msg(editing)
+                .block(Block::default().borders(Borders::ALL)),
+            row_layout[1]
+        );
+
+        // render input box for the selected text field
+        if editing {
+            match self.selected_field() {
+                IssueCreateField::Title => self.title_input.render(f, row_layout[2]),
+                IssueCreateField::Description => self.description_input.render(f, row_layout[2]),
+                _ => {},
+            }
+        }
+    }
+}
+
+
+impl Default for LinearIssueCreateForm {
+    fn default() -> LinearIssueCreateForm {
+        let mut field_state = TableState::default();
+        field_state.select(Some(0));
+
+        LinearIssueCreateForm {
+            field_state,
+
+            team: None,
+            title_input: UserInput::new(false),
+            description_input: UserInput::new(false),
+            state: None,
+            assignee: None,
+            priority: 0,
+            labels: Vec::new(),
+            project: None,
+            cycle: None,
+
+            validation_msg: None,
+        }
+    }
+}
@@ -58,7 +58,20 @@ use tui::{
     Frame
 };
 
+use std::sync::{
+    Arc,
+    Mutex,
+    atomic::{ AtomicBool, Ordering },
+};
+
+use crate::app::Platform;
+
+use crate::components::user_input::UserInput;
+
 use crate::util::{
+    date::format_local_datetime,
+    loader::loader_from_state,
+    GraphQLCursor,
     table::{ empty_str_to_fallback,
         format_cell_fields,
         get_row_height,
@@ -67,13 +80,132 @@ use crate::util::{
     layout::{ widths_from_rect },
 };
 
-use crate::linear::schema::{ Issue };
+use crate::linear::schema::{ Issue, Comment };
 
 use crate::constants::{ 
     table_columns::{ ISSUE_MODAL_HEADER_COLUMNS }
 };
 
-pub fn render_and_layout<B>(f: &mut Frame<B>, chunk: Rect, issue: &Issue, scroll_tick: u64 )
+// Comments thread of the expanded issue, paginated as the pane is scrolled
+#[derive(Debug)]
+pub struct IssueCommentsPane {
+    // Issue the loaded comments belong to, checked before merging a fetched page
+    pub issue_id: Arc<Mutex<Option<String>>>,
+    pub comments: Arc<Mutex<Vec<Comment>>>,
+    pub cursor: Arc<Mutex<GraphQLCursor>>,
+    pub loading: Arc<AtomicBool>,
+
+    // index of the first comment displayed
+    pub scroll_idx: usize,
+
+    pub reply_input: UserInput,
+}
+
+impl IssueCommentsPane {
+
+    // Returns true if the pane was reset for a different issue, and comments need to be loaded
+    pub fn reset_for_issue(&mut self, issue_id: &str) -> bool {
+        let mut issue_id_lock = self.issue_id.lock().unwrap();
+        if issue_id_lock.as_deref() == Some(issue_id) {
+            return false;
+        }
+
+        *issue_id_lock = Some(issue_id.to_string());
+        self.comments.lock().unwrap().clear();
+        *self.cursor.lock().unwrap() = GraphQLCursor::with_platform(Platform::Linear);
+        self.scroll_idx = 0;
+        self.reply_input.set_input(String::new());
+
+        true
+    }
+
+    pub fn clear(&mut self) {
+        *self.issue_id.lock().unwrap() = None;
+        self.comments.lock().unwrap().clear();
+        self.scroll_idx = 0;
+    }
+
+    // Returns true if scrolled onto the last loaded comment and another page is available
+    pub fn scroll_down(&mut self) -> bool {
+        let num_comments = self.comments.lock().unwrap().len();
+        if self.scroll_idx + 1 < num_comments {
+            self.scroll_idx += 1;
+        }
+
+        self.scroll_idx + 1 >= num_comments && self.cursor.lock().unwrap().has_next_page
+    }
+
+    pub fn scroll_up(&mut self) {
+        self.scroll_idx = self.scroll_idx.saturating_sub(1);
+    }
+
+    pub fn render<B>(&self, f: &mut Frame<B>, area: Rect, loader_tick: u16)
+    where B: Backend,
+    {
+        let comments_lock = self.comments.lock().unwrap();
+        let loading = self.loading.load(Ordering::Relaxed);
+
+        let title = format!("Comments ({}/{}{}) {} - PgUp/PgDn to scroll, 'e' to reply",
+            if comments_lock.is_empty() { 0 } else { self.scroll_idx + 1 },
+            comments_lock.len(),
+            if self.cursor.lock().unwrap().has_next_page { "+" } else { "" },
+            loader_from_state(loading, loader_tick)
+        );
+
+        // Comments list, reply input box
+        let pane_div = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(2), Constraint::Length(3)].as_ref())
+            .split(area);
+
+        let mut lines: Vec<Spans> = Vec::new();
+        for comment in comments_lock.iter().skip(self.scroll_idx) {
+            let author = match &comment.user {
+                Some(user) => user.display_name.clone(),
+                None => String::from("Unknown"),
+            };
+            let mut timestamp = format_local_datetime(&comment.created_at);
+            if comment.edited_at.is_some() {
+                timestamp.push_str(" (edited)");
+            }
+
+            lines.push(Spans::from(vec![
+                Span::styled(author, Style::default().add_modifier(Modifier::BOLD)),
+                Span::raw(" "),
+                Span::styled(timestamp, Style::default().fg(Color::DarkGray)),
+            ]));
+            lines.extend(comment.body.lines().map(|line| Spans::from(String::from(line))));
+            lines.push(Spans::from(""));
+        }
+        drop(comments_lock);
+
+        let comments_p = Paragraph::new(lines)
+            .block(Block::default()
+                .borders(Borders::TOP)
+                .title(Span::styled(title, Style::default().add_modifier(Modifier::BOLD))))
+            .alignment(Alignment::Left)
+            .wrap(Wrap { trim: false });
+
+        f.render_widget(comments_p, pane_div[0]);
+
+        self.reply_input.render(f, pane_div[1]);
+    }
+}
+
+impl Default for IssueCommentsPane {
+    fn default() -> IssueCommentsPane {
+        IssueCommentsPane {
+            issue_id: Arc::new(Mutex::new(None)),
+            comments: Arc::new(Mutex::new(Vec::new())),
+            cursor: Arc::new(Mutex::new(GraphQLCursor::with_platform(Platform::Linear))),
+            loading: Arc::new(AtomicBool::new(false)),
+            scroll_idx: 0,
+            reply_input: UserInput::new(false),
+        }
+    }
+}
+
+pub fn render_and_layout<B>(f: &mut Frame<B>, chunk: Rect, issue: &Issue, comments_pane: &IssueCommentsPane, scroll_tick: u64, loader_tick: u16)
 where
   B: Backend,
 {
@@ -222,10 +354,10 @@ where
         content_chunks[6]);
 
 
-    // render title & desc
+    // render title, desc & comments
     let content_div = Layout::default()
         .direction(Direction::Vertical)
-        .constraints([Constraint::Percentage(20), Constraint::Percentage(80)].as_ref())
+        .constraints([Constraint::Percentage(15), Constraint::Percentage(35), Constraint::Percentage(50)].as_ref())
         .split(content_and_categories_cols[0]);
 
     let title_p = Paragraph::new(issue.title.clone())
@@ -240,6 +372,8 @@ where
     f.render_widget(title_p, content_div[0]);
     f.render_widget(desc_p, content_div[1]);
 
+    comments_pane.render(f, content_div[2], loader_tick);
+
 
 
 
@@ -9,7 +9,7 @@ use unicode_segmentation::UnicodeSegmentation;
 
 use tui::{
     layout::{Constraint, Rect},
-    style::{Color, Modifier, Style},
+    style::{Modifier, Style},
     widgets::{Block, Borders, Cell, Row, Table, TableState},
 };
 
@@ -17,11 +17,12 @@ use crate::app::Platform;
 
 
 use crate::linear::{
-    client::{ IssueFieldObject },
-    schema::{Cycle, Project, TeamMember, State},
+    client::{ IssueFieldObject, ScaleValue },
+    schema::{Cycle, Project, TeamMember, State, Team, Label},
 };
 
 use crate::util::{
+    theme::theme,
     error_panic,
     table::{ empty_str_to_fallback, format_cell_fields,
         get_row_height, row_min_render_height, colored_cell,
@@ -37,8 +38,11 @@ use crate::constants::{
     IssueModificationOp,
     colors,
     table_columns::{
+        TableColumn,
         WORKFLOW_STATE_SELECT_COLUMNS, ASSIGNEE_SELECT_COLUMNS,
-        PROJECT_SELECT_COLUMNS, CYCLE_SELECT_COLUMNS
+        PROJECT_SELECT_COLUMNS, CYCLE_SELECT_COLUMNS,
+        TEAM_SELECT_COLUMNS, LABEL_SELECT_COLUMNS,
+        PRIORITY_SELECT_COLUMNS, ESTIMATE_SELECT_COLUMNS,
     }
 };
 
@@ -48,6 +52,13 @@ pub struct ModificationOpData {
     pub projects: Vec<Project>,
     pub users: Vec<TeamMember>,
     pub workflow_states: Vec<State>,
+    pub teams: Vec<Team>,
+    pub labels: Vec<Label>,
+    pub priorities: Vec<ScaleValue>,
+    pub estimates: Vec<ScaleValue>,
+
+    // Labels toggled on while IssueModificationOp::Labels is open (multi-select)
+    pub selected_labels: Vec<Label>,
 }
 
 pub struct LinearIssueOpInterface {
@@ -64,6 +75,44 @@ pub struct LinearIssueOpInterface {
 
 impl LinearIssueOpInterface {
 
+    // Multi-select ops toggle the selected row with 'Space', and submit all toggled rows on Confirm
+    pub fn is_multi_select(op: &IssueModificationOp) -> bool {
+        *op == IssueModificationOp::Labels
+    }
+
+    // Set the initially toggled Labels, e.g. the Labels already applied to the Issue
+    pub fn init_label_selection(&mut self, labels: Vec<Label>) {
+        self.obj_data.lock().unwrap().selected_labels = labels;
+    }
+
+    // Toggle the Label at selected_idx
+    pub fn toggle_selected_label(&mut self) {
+        if self.current_op != Some(IssueModificationOp::Labels) {
+            return;
+        }
+
+        let selected_idx = match self.selected_idx {
+            Some(idx) => idx,
+            None => return,
+        };
+
+        let mut obj_data_lock = self.obj_data.lock().unwrap();
+        let label = match obj_data_lock.labels.get(selected_idx) {
+            Some(label) => label.clone(),
+            None => return,
+        };
+
+        if let Some(idx) = obj_data_lock.selected_labels.iter().position(|e| e.id == label.id) {
+            obj_data_lock.selected_labels.remove(idx);
+        } else {
+            obj_data_lock.selected_labels.push(label);
+        }
+    }
+
+    pub fn selected_labels(&self) -> Vec<Label> {
+        self.obj_data.lock().unwrap().selected_labels.clone()
+    }
+
     pub fn table_data_from_op(&self) -> Option<Vec<IssueFieldObject>> {
         let obj_data_lock = self.obj_data.lock().unwrap();
         match self.current_op {
@@ -91,6 +140,30 @@ impl LinearIssueOpInterface {
                     .map(|cycle| { IssueFieldObject::Cycle(cycle.clone()) })
                     .collect())
             },
+            Some(IssueModificationOp::Team) => {
+                Some(obj_data_lock.teams
+                    .iter()
+                    .map(|team| { IssueFieldObject::Team(team.clone()) })
+                    .collect())
+            },
+            Some(IssueModificationOp::Labels) => {
+                Some(obj_data_lock.labels
+                    .iter()
+                    .map(|label| { IssueFieldObject::Label(label.clone()) })
+                    .collect())
+            },
+            Some(IssueModificationOp::Priority) => {
+                Some(obj_data_lock.priorities
+                    .iter()
+                    .map(|priority| { IssueFieldObject::Priority(priority.clone()) })
+                    .collect())
+            },
+            Some(IssueModificationOp::Estimate) => {
+                Some(obj_data_lock.estimates
+                    .iter()
+                    .map(|estimate| { IssueFieldObject::Estimate(estimate.clone()) })
+                    .collect())
+            },
             _ => { None }
         }
     }
@@ -113,6 +186,23 @@ impl LinearIssueOpInterface {
             Some(IssueModificationOp::Cycle) => {
                 self.selected_idx.is_some()
             },
+            Some(IssueModificationOp::Team) => {
+                self.selected_idx.is_some()
+            },
+            // Removing all Labels is a valid selection
+            Some(IssueModificationOp::Labels) => {
+                true
+            },
+            Some(IssueModificationOp::Priority) => {
+                self.selected_idx.is_some()
+            },
+            Some(IssueModificationOp::Estimate) => {
+                self.selected_idx.is_some()
+            },
+            // The Due Date Picker always has a date selected
+            Some(IssueModificationOp::DueDate) => {
+                true
+            },
             _ => {
                 false
             }
@@ -136,6 +226,22 @@ impl LinearIssueOpInterface {
             },
             Some(IssueModificationOp::Cycle) => {
                 obj_data_lock.cycles = Vec::default();
+            },
+            Some(IssueModificationOp::Team) => {
+                obj_data_lock.teams = Vec::default();
+            },
+            Some(IssueModificationOp::Labels) => {
+                obj_data_lock.labels = Vec::default();
+                obj_data_lock.selected_labels = Vec::default();
+            },
+            Some(IssueModificationOp::Priority) => {
+                obj_data_lock.priorities = Vec::default();
+            },
+            Some(IssueModificationOp::Estimate) => {
+                obj_data_lock.estimates = Vec::default();
+            },
+            Some(IssueModificationOp::DueDate) => {
+
             },
             _ => {
                 error_panic!("reset_op: invalid LinearIssueOpInterface::current_op: {:?}", self.current_op);
@@ -152,11 +258,20 @@ impl LinearIssueOpInterface {
     // render helper functions
     fn cell_fields_from_row(row: &IssueFieldObject, widths: &[Constraint]) -> Vec<String> {
 
+        let estimate_points: String = match row {
+            IssueFieldObject::Estimate(estimate) => estimate.value.to_string(),
+            _ => String::new(),
+        };
+
         let columns_from_row = |row| match row {
             IssueFieldObject::Cycle(_) => &*CYCLE_SELECT_COLUMNS,
             IssueFieldObject::Project(_) => &*PROJECT_SELECT_COLUMNS,
             IssueFieldObject::TeamMember(_) => &*ASSIGNEE_SELECT_COLUMNS,
             IssueFieldObject::State(_) => &*WORKFLOW_STATE_SELECT_COLUMNS,
+            IssueFieldObject::Team(_) => &*TEAM_SELECT_COLUMNS,
+            IssueFieldObject::Label(_) => &*LABEL_SELECT_COLUMNS,
+            IssueFieldObject::Priority(_) => &*PRIORITY_SELECT_COLUMNS,
+            IssueFieldObject::Estimate(_) => &*ESTIMATE_SELECT_COLUMNS,
         };
 
         let values = match row {
@@ -187,6 +302,30 @@ impl LinearIssueOpInterface {
                     cycle.ends_at.as_str(),
                 ]
             },
+            IssueFieldObject::Team(team) => {
+                vec![
+                    team.name.as_str(),
+                    team.key.as_str(),
+                    team.description.as_deref().unwrap_or(""),
+                ]
+            },
+            IssueFieldObject::Label(label) => {
+                vec![
+                    label.name.as_str(),
+                    label.description.as_deref().unwrap_or(""),
+                ]
+            },
+            IssueFieldObject::Priority(priority) => {
+                vec![
+                    priority.label.as_str(),
+                ]
+            },
+            IssueFieldObject::Estimate(estimate) => {
+                vec![
+                    estimate.label.as_str(),
+                    estimate_points.as_str(),
+                ]
+            },
         };
 
         let cell_fields: Vec<String> = empty_str_to_fallback(&values[..], columns_from_row(row.clone()));
@@ -205,6 +344,10 @@ impl LinearIssueOpInterface {
                 IssueModificationOp::Assignee => &ASSIGNEE_SELECT_COLUMNS,
                 IssueModificationOp::Project => &PROJECT_SELECT_COLUMNS,
                 IssueModificationOp::Cycle => &CYCLE_SELECT_COLUMNS,
+                IssueModificationOp::Team => &TEAM_SELECT_COLUMNS,
+                IssueModificationOp::Labels => &LABEL_SELECT_COLUMNS,
+                IssueModificationOp::Priority => &PRIORITY_SELECT_COLUMNS,
+                IssueModificationOp::Estimate => &ESTIMATE_SELECT_COLUMNS,
                 _ => panic!("Not ready")
             }
         )
@@ -224,6 +367,18 @@ impl LinearIssueOpInterface {
             IssueModificationOp::Cycle => {
                 "Select New Cycle".to_string()
             },
+            IssueModificationOp::Team => {
+                "Select New Team".to_string()
+            },
+            IssueModificationOp::Labels => {
+                "Select Labels ('Space' to toggle)".to_string()
+            },
+            IssueModificationOp::Priority => {
+                "Select New Priority".to_string()
+            },
+            IssueModificationOp::Estimate => {
+                "Select New Estimate".to_string()
+            },
             _ => {
                 panic!("Not ready");
             }
@@ -239,20 +394,26 @@ impl LinearIssueOpInterface {
         let bottom_margin = table_style.row_bottom_margin.unwrap_or(0);
 
         let selected_style = Style::default().add_modifier(Modifier::REVERSED);
-        let normal_style = Style::default().bg(Color::DarkGray);
-
-
-        let header_cells: Vec<Cell> = match op {
-                IssueModificationOp::WorkflowState => { &*WORKFLOW_STATE_SELECT_COLUMNS },
-                IssueModificationOp::Assignee => { &*ASSIGNEE_SELECT_COLUMNS },
-                IssueModificationOp::Project => { &*PROJECT_SELECT_COLUMNS },
-                IssueModificationOp::Cycle => { &*CYCLE_SELECT_COLUMNS },
+        let normal_style = Style::default().bg(theme().table_header_bg);
+
+
+        let header_columns: &[TableColumn] = match op {
+                IssueModificationOp::WorkflowState => { &WORKFLOW_STATE_SELECT_COLUMNS },
+                IssueModificationOp::Assignee => { &ASSIGNEE_SELECT_COLUMNS },
+                IssueModificationOp::Project => { &PROJECT_SELECT_COLUMNS },
+                IssueModificationOp::Cycle => { &CYCLE_SELECT_COLUMNS },
+                IssueModificationOp::Team => { &TEAM_SELECT_COLUMNS },
+                IssueModificationOp::Labels => { &LABEL_SELECT_COLUMNS },
+                IssueModificationOp::Priority => { &PRIORITY_SELECT_COLUMNS },
+                IssueModificationOp::Estimate => { &ESTIMATE_SELECT_COLUMNS },
                 _ => {
                     error_panic!("LinearIssueOpInterface::render - header_cells invalid IssueModificationOp: {:?}", op);
                 }
-            }
+            };
+
+        let header_cells: Vec<Cell> = header_columns
             .iter()
-            .map(|h| Cell::from(&*h.label).style(Style::default().fg(Color::LightGreen)))
+            .map(|h| Cell::from(&*h.label).style(Style::default().fg(theme().table_header)))
             .collect();
 
         let header = Row::new(header_cells)
@@ -288,6 +449,36 @@ impl LinearIssueOpInterface {
                     .map(|cycle| { IssueFieldObject::Cycle(cycle.clone()) })
                     .collect()
             },
+            IssueModificationOp::Team => {
+                table_data.teams
+                    .iter()
+                    .map(|team| { IssueFieldObject::Team(team.clone()) })
+                    .collect()
+            },
+            // Mark toggled Labels
+            IssueModificationOp::Labels => {
+                table_data.labels
+                    .iter()
+                    .map(|label| {
+                        let mut label = label.clone();
+                        let is_toggled = table_data.selected_labels.iter().any(|e| e.id == label.id);
+                        label.name = format!("{} {}", if is_toggled { "[x]" } else { "[ ]" }, label.name);
+                        IssueFieldObject::Label(label)
+                    })
+                    .collect()
+            },
+            IssueModificationOp::Priority => {
+                table_data.priorities
+                    .iter()
+                    .map(|priority| { IssueFieldObject::Priority(priority.clone()) })
+                    .collect()
+            },
+            IssueModificationOp::Estimate => {
+                table_data.estimates
+                    .iter()
+                    .map(|estimate| { IssueFieldObject::Estimate(estimate.clone()) })
+                    .collect()
+            },
             _ => {
                 panic!("unsupported op!");
             },
@@ -312,6 +503,10 @@ impl LinearIssueOpInterface {
                     IssueFieldObject::Project(project) => { cells[0] = colored_cell(name_str, &project.color); },
                     IssueFieldObject::TeamMember(_member) => {},
                     IssueFieldObject::State(state) => { cells[0] = colored_cell(name_str, &state.color); },
+                    IssueFieldObject::Team(_team) => {},
+                    IssueFieldObject::Label(label) => { cells[0] = colored_cell(name_str, &label.color); },
+                    IssueFieldObject::Priority(_priority) => {},
+                    IssueFieldObject::Estimate(_estimate) => {},
                 };
     
                 Row::new(cells)
@@ -329,7 +524,7 @@ impl LinearIssueOpInterface {
 
         let table_block = Block::default()
             .borders(Borders::ALL)
-            .border_style(Style::default().fg(if table_style.highlight_table { Color::Yellow } else { Color::White }))
+            .border_style(Style::default().fg(theme().border(table_style.highlight_table)))
             .title( gen_table_title_spans(table_style) );
 
         let t = Table::new(rows)
@@ -0,0 +1,153 @@
+use std::collections::HashMap;
+
+use std::sync::{
+    Arc,
+    Mutex,
+    atomic::{ AtomicBool, AtomicUsize, Ordering },
+};
+
+use tui::{
+    backend::Backend,
+    layout::{ Constraint, Rect },
+    widgets::{ TableState },
+    Frame,
+};
+
+use crate::app::Platform;
+
+use crate::components::{
+    user_input::UserInput,
+    dashboard_view_panel::DashboardViewPanel,
+};
+
+use crate::constants::view_panel_columns::{ PanelColumn, default_panel_columns };
+
+use crate::linear::schema::{ Issue };
+
+use crate::util::{
+    table::{ TableStyle },
+    issue_order::PanelOrder,
+    table_state,
+    GraphQLCursor,
+};
+
+// Workspace-wide issue search, results are rendered like a DashboardViewPanel
+#[derive(Debug)]
+pub struct LinearIssueSearch {
+    pub input: UserInput,
+
+    // Last submitted query
+    pub query: Arc<Mutex<String>>,
+    // Incremented for each submitted query, pages fetched for an older generation are discarded
+    pub generation: Arc<AtomicUsize>,
+
+    pub issue_table_data: Arc<Mutex<Vec<Issue>>>,
+    pub cursor: Arc<Mutex<GraphQLCursor>>,
+    pub loading: Arc<AtomicBool>,
+
+    pub table_state: TableState,
+
+    // Uses the default View Panel columns
+    pub columns: Vec<PanelColumn>,
+}
+
+impl LinearIssueSearch {
+
+    // Returns false if the query input is empty
+    pub fn submit_query(&mut self) -> bool {
+        let query = self.input.input.trim().to_string();
+        if query.is_empty() {
+            return false;
+        }
+
+        *self.query.lock().unwrap() = query;
+        self.start_generation();
+        self.issue_table_data.lock().unwrap().clear();
+        *self.cursor.lock().unwrap() = GraphQLCursor::with_platform(Platform::Linear);
+        self.table_state = TableState::default();
+
+        true
+    }
+
+    // Requests still in flight are ignored, so don't block loading the new generation
+    fn start_generation(&mut self) {
+        self.generation.fetch_add(1, Ordering::Relaxed);
+        self.loading.store(false, Ordering::Relaxed);
+    }
+
+    // Drop results & the last query, pages still loading are discarded
+    pub fn clear_results(&mut self) {
+        self.query.lock().unwrap().clear();
+        self.start_generation();
+        self.issue_table_data.lock().unwrap().clear();
+        *self.cursor.lock().unwrap() = GraphQLCursor::with_platform(Platform::Linear);
+        self.table_state = TableState::default();
+    }
+
+    pub fn selected_issue(&self) -> Option<Issue> {
+        let idx = self.table_state.selected()?;
+        self.issue_table_data.lock().unwrap().get(idx).cloned()
+    }
+
+    // Returns true if the last result is selected and another page is available
+    pub fn select_next(&mut self) -> bool {
+        let issue_table_data_lock = self.issue_table_data.lock().unwrap();
+        if issue_table_data_lock.is_empty() {
+            return false;
+        }
+
+        if table_state::is_last_element(&self.table_state, &issue_table_data_lock) {
+            return self.cursor.lock().unwrap().has_next_page;
+        }
+
+        table_state::next(&mut self.table_state, &issue_table_data_lock);
+        false
+    }
+
+    pub fn select_previous(&mut self) {
+        let issue_table_data_lock = self.issue_table_data.lock().unwrap();
+        if !issue_table_data_lock.is_empty() {
+            table_state::previous(&mut self.table_state, &issue_table_data_lock);
+        }
+    }
+
+    pub fn render<B>(&self, f: &mut Frame<B>, area: Rect, widths: &[Constraint], loader_tick: u16, due_soon_day_threshold: u32)
+    where B: Backend,
+    {
+        let query = self.query.lock().unwrap().clone();
+
+        let table_style = TableStyle {
+            title_style: Some((
+                if query.is_empty() { String::from("Search Issues") } else { format!("Search: {}", query) },
+                String::from("#FFFFFF")
+            )),
+            row_bottom_margin: Some(0),
+            view_idx: None,
+            highlight_table: true,
+            loading: self.loading.load(Ordering::Relaxed),
+            loader_state: loader_tick,
+        };
+
+        let issue_table_data_lock = self.issue_table_data.lock().unwrap();
+
+        if let Ok(table) = DashboardViewPanel::render(&issue_table_data_lock, &self.columns, widths, table_style, due_soon_day_threshold, &PanelOrder::default(), &HashMap::new()) {
+            let mut table_state = self.table_state.clone();
+            f.render_stateful_widget(table.widths(widths), area, &mut table_state);
+        }
+    }
+}
+
+impl Default for LinearIssueSearch {
+    fn default() -> LinearIssueSearch {
+        LinearIssueSearch {
+            input: UserInput::new(false),
+            query: Arc::new(Mutex::new(String::new())),
+            generation: Arc::new(AtomicUsize::new(0)),
+            issue_table_data: Arc::new(Mutex::new(Vec::new())),
+            cursor: Arc::new(Mutex::new(GraphQLCursor::with_platform(Platform::Linear))),
+            loading: Arc::new(AtomicBool::new(false)),
+            table_state: TableState::default(),
+            columns: default_panel_columns(),
+        }
+    }
+}
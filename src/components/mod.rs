@@ -1,6 +1,11 @@
 
 pub mod dashboard_view_config_display;
 pub mod dashboard_view_panel;
+pub mod dashboard_name_entry;
+pub mod panel_jump_prompt;
+pub mod panel_export_prompt;
+pub mod notification_center;
+pub mod issue_history;
 
 pub mod token_entry;
 pub mod title_entry;
@@ -11,11 +16,21 @@ pub mod command_bar;
 
 pub mod linear_custom_view_select;
 pub mod linear_issue_op_interface;
+pub mod linear_issue_create_form;
+pub mod due_date_picker;
 
 pub mod linear_issue_modal;
+pub mod linear_issue_search;
 
 
 pub enum InputComponent {
     TokenEntry,
     TitleEntry,
+    IssueCreateForm,
+    DueDatePicker,
+    CommentEntry,
+    SearchQuery,
+    DashboardName,
+    PanelJump,
+    PanelExport,
 }
\ No newline at end of file
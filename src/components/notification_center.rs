@@ -0,0 +1,194 @@
+use std::fmt::Display;
+use std::sync::{ Arc, Mutex };
+use std::time::{ Duration, Instant };
+
+use chrono::{ DateTime, Local };
+
+use tui::{
+    backend::Backend,
+    layout::{ Constraint, Direction, Layout, Rect },
+    style::{ Modifier, Style },
+    text::{ Span, Spans },
+    widgets::{ Block, Borders, Clear, List, ListItem, ListState, Paragraph, Wrap },
+    Frame,
+};
+
+use crate::constants::colors::{ RED, GREEN };
+
+use crate::util::{
+    list_state,
+    keybindings::display_key,
+};
+
+// How long a new notification is shown as a toast
+const TOAST_DURATION: Duration = Duration::from_secs(5);
+const TOAST_MAX_WIDTH: u16 = 60;
+
+// Oldest notifications are dropped past this
+const MAX_NOTIFICATIONS: usize = 200;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum NotificationLevel {
+    Info,
+    Error,
+}
+
+#[derive(Debug, Clone)]
+pub struct Notification {
+    pub level: NotificationLevel,
+    pub message: String,
+    pub time: DateTime<Local>,
+    created: Instant,
+}
+
+// Notification log shared with background tasks, clones push to the same log
+#[derive(Debug, Clone, Default)]
+pub struct Notifications {
+    entries: Arc<Mutex<Vec<Notification>>>,
+}
+
+impl Notifications {
+
+    pub fn info(&self, message: impl Into<String>) {
+        self.push(NotificationLevel::Info, message.into());
+    }
+
+    // Accepts:
+    //     context: what failed, e.g. "Loading 'My Issues'"
+    pub fn error(&self, context: &str, err: &dyn Display) {
+        error!("{}: {}", context, err);
+        self.push(NotificationLevel::Error, format!("{}: {}", context, err));
+    }
+
+    fn push(&self, level: NotificationLevel, message: String) {
+        let mut entries_lock = self.entries.lock().unwrap();
+        entries_lock.push(Notification { level, message, time: Local::now(), created: Instant::now() });
+
+        if entries_lock.len() > MAX_NOTIFICATIONS {
+            let excess = entries_lock.len() - MAX_NOTIFICATIONS;
+            entries_lock.drain(..excess);
+        }
+    }
+
+    // The newest notification, while it's recent enough to show as a toast
+    pub fn toast(&self) -> Option<Notification> {
+        self.entries.lock().unwrap()
+            .last()
+            .filter(|x| x.created.elapsed() < TOAST_DURATION)
+            .cloned()
+    }
+
+    // Newest first
+    pub fn entries(&self) -> Vec<Notification> {
+        self.entries.lock().unwrap().iter().rev().cloned().collect()
+    }
+}
+
+// Toasts for new notifications & a scrollable log of all notifications
+#[derive(Debug, Default)]
+pub struct NotificationCenter {
+    pub notifications: Notifications,
+    pub log_open: bool,
+    pub log_state: ListState,
+}
+
+impl NotificationCenter {
+
+    pub fn toggle_log(&mut self) {
+        self.log_open = !self.log_open;
+        self.log_state = ListState::default();
+        if self.log_open && !self.notifications.entries().is_empty() {
+            self.log_state.select(Some(0));
+        }
+    }
+
+    pub fn scroll_down(&mut self) {
+        let entries = self.notifications.entries();
+        if !entries.is_empty() {
+            list_state::next(&mut self.log_state, &entries);
+        }
+    }
+
+    pub fn scroll_up(&mut self) {
+        let entries = self.notifications.entries();
+        if !entries.is_empty() {
+            list_state::previous(&mut self.log_state, &entries);
+        }
+    }
+
+    fn level_style(level: NotificationLevel) -> Style {
+        match level {
+            NotificationLevel::Info => Style::default().fg(GREEN),
+            NotificationLevel::Error => Style::default().fg(RED),
+        }
+    }
+
+    // Rendered in the top right corner of 'area'
+    pub fn render_toast<B>(&self, f: &mut Frame<B>, area: Rect)
+    where B: Backend,
+    {
+        let toast = match self.notifications.toast() {
+            Some(x) => x,
+            None => return,
+        };
+
+        let width = TOAST_MAX_WIDTH.min(area.width);
+        // Borders & up to 3 lines of wrapped message
+        let height = 5.min(area.height);
+        let toast_area = Rect::new(area.x + area.width - width, area.y, width, height);
+
+        let title = match toast.level {
+            NotificationLevel::Info => String::from("Info"),
+            NotificationLevel::Error => format!("Error - '{}': Error Log", display_key('E')),
+        };
+
+        f.render_widget(Clear, toast_area);
+        f.render_widget(
+            Paragraph::new(toast.message.clone())
+                .wrap(Wrap { trim: true })
+                .block(Block::default()
+                    .borders(Borders::ALL)
+                    .border_style(NotificationCenter::level_style(toast.level))
+                    .title(Span::styled(title, NotificationCenter::level_style(toast.level).add_modifier(Modifier::BOLD)))),
+            toast_area
+        );
+    }
+
+    pub fn render_log<B>(&self, f: &mut Frame<B>, area: Rect)
+    where B: Backend,
+    {
+        let entries = self.notifications.entries();
+
+        let items: Vec<ListItem> = entries.iter()
+            .map(|x| ListItem::new(Spans::from(vec![
+                Span::styled(x.time.format("%H:%M:%S ").to_string(), Style::default().add_modifier(Modifier::DIM)),
+                Span::styled(x.message.clone(), NotificationCenter::level_style(x.level)),
+            ])))
+            .collect();
+
+        let list = List::new(items)
+            .block(Block::default().borders(Borders::ALL).title(format!("Notifications ({})", entries.len())))
+            .highlight_style(Style::default().add_modifier(Modifier::REVERSED));
+
+        // Selected notification in full, the list cuts off long messages
+        let selected_message = self.log_state.selected()
+            .and_then(|idx| entries.get(idx))
+            .map(|x| Spans::from(Span::styled(x.message.clone(), NotificationCenter::level_style(x.level))))
+            .unwrap_or_default();
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(3), Constraint::Length(5)].as_ref())
+            .split(area);
+
+        let mut log_state = self.log_state.clone();
+        f.render_widget(Clear, area);
+        f.render_stateful_widget(list, chunks[0], &mut log_state);
+        f.render_widget(
+            Paragraph::new(selected_message)
+                .wrap(Wrap { trim: true })
+                .block(Block::default().borders(Borders::ALL)),
+            chunks[1]
+        );
+    }
+}
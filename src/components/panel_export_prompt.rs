@@ -0,0 +1,164 @@
+use std::path::PathBuf;
+use std::sync::{ Arc, Mutex };
+
+use tui::{
+    backend::Backend,
+    layout::{ Constraint, Direction, Rect, Layout },
+    style::{Modifier, Style},
+    text::{Span, Spans, Text},
+    widgets::{Paragraph, Block, Borders, Wrap },
+    Frame,
+};
+
+use crate::components::user_input::UserInput;
+
+use crate::constants::colors::{ RED, GREEN };
+
+use crate::util::export::{ ExportFormat, file_stem };
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum PanelExportState {
+    Null,
+    // Number of issues fetched so far
+    Exporting(usize),
+    Exported(usize, PathBuf),
+    Failed(String),
+}
+
+// Path entry for exporting all issues of the selected View Panel, the format follows the extension
+#[derive(Debug)]
+pub struct PanelExportPrompt {
+    pub input: UserInput,
+    pub open: bool,
+    pub error: Option<String>,
+
+    // Validated path & format of the last submission
+    pub target: Option<(PathBuf, ExportFormat)>,
+    // Existing file the last submission would replace, submitting the same path again overwrites it
+    pub overwrite: Option<PathBuf>,
+
+    // Updated by the export task
+    pub state: Arc<Mutex<PanelExportState>>,
+}
+
+impl PanelExportPrompt {
+
+    // Accepts:
+    //     view_name: name of the panel's Custom View, prefills the path as "<view-name>.csv"
+    pub fn open(&mut self, view_name: &str) {
+        self.input.set_input(format!("{}.csv", file_stem(view_name)));
+        self.open = true;
+        self.error = None;
+        self.target = None;
+        self.overwrite = None;
+        *self.state.lock().unwrap() = PanelExportState::Null;
+    }
+
+    pub fn close(&mut self) {
+        self.input.set_input(String::new());
+        self.open = false;
+        self.error = None;
+        self.overwrite = None;
+    }
+
+    pub fn is_exporting(&self) -> bool {
+        matches!(*self.state.lock().unwrap(), PanelExportState::Exporting(_))
+    }
+
+    // The entered path with a leading "~/" expanded, relative paths are resolved against the working directory
+    pub fn resolved_path(&self) -> PathBuf {
+        let input = self.input.input.trim();
+
+        let path = match (input.strip_prefix("~/"), dirs::home_dir()) {
+            (Some(rest), Some(home)) => home.join(rest),
+            _ => PathBuf::from(input),
+        };
+
+        match std::env::current_dir() {
+            Ok(dir) if path.is_relative() => dir.join(path),
+            _ => path,
+        }
+    }
+
+    // Returns:
+    //     the resolved path & its format, or sets self.error if the extension isn't supported,
+    //     an existing file is only returned once submitted twice in a row
+    pub fn validated_path(&mut self) -> Option<(PathBuf, ExportFormat)> {
+        let path = self.resolved_path();
+
+        let format = match ExportFormat::from_path(&path) {
+            Some(format) if !self.input.input.trim().is_empty() => format,
+            _ => {
+                self.error = Some(String::from("Path must end in .csv, .json or .md"));
+                return None;
+            }
+        };
+
+        let confirmed = self.overwrite.take().as_ref() == Some(&path);
+        if path.exists() && !confirmed {
+            self.overwrite = Some(path);
+            return None;
+        }
+
+        Some((path, format))
+    }
+
+    pub fn render<B>(&self, f: &mut Frame<B>, area: Rect)
+    where B: Backend,
+    {
+        let row_layout = Layout::default()
+            .direction(Direction::Vertical)
+            .margin(1)
+            .constraints([Constraint::Length(4), Constraint::Length(3)].as_ref())
+            .split(area);
+
+        let msg = match (&self.error, &self.overwrite, &*self.state.lock().unwrap()) {
+            (Some(error), _, _) => Spans::from(Span::styled(error.clone(), Style::default().fg(RED))),
+            (None, Some(path), _) => Spans::from(vec![
+                Span::styled(
+                    format!("{} already exists, ", path.display()),
+                    Style::default().fg(RED)
+                ),
+                Span::raw("press "),
+                Span::styled("Enter", Style::default().add_modifier(Modifier::BOLD)),
+                Span::raw(" again to overwrite it"),
+            ]),
+            (None, None, PanelExportState::Exporting(count)) => Spans::from(format!("Fetching issues... {}", count)),
+            (None, None, PanelExportState::Exported(count, path)) => Spans::from(Span::styled(
+                format!("Exported {} issues to {}", count, path.display()),
+                Style::default().fg(GREEN)
+            )),
+            (None, None, PanelExportState::Failed(error)) => Spans::from(Span::styled(
+                format!("Export failed: {}", error),
+                Style::default().fg(RED)
+            )),
+            (None, None, PanelExportState::Null) => Spans::from(vec![
+                Span::raw("Press "),
+                Span::styled("Enter", Style::default().add_modifier(Modifier::BOLD)),
+                Span::raw(format!(" to export as .csv, .json or .md to {}", self.resolved_path().display())),
+            ]),
+        };
+
+        f.render_widget(
+            Paragraph::new(Text::from(msg))
+                .wrap(Wrap { trim: true })
+                .block(Block::default().borders(Borders::ALL).title("Export Panel")),
+            row_layout[0]
+        );
+
+        self.input.render(f, row_layout[1]);
+    }
+}
+
+impl Default for PanelExportPrompt {
+    fn default() -> PanelExportPrompt {
+        PanelExportPrompt {
+            input: UserInput::new(false),
+            open: false,
+            error: None,
+            target: None,
+            overwrite: None,
+            state: Arc::new(Mutex::new(PanelExportState::Null)),
+        }
+    }
+}
@@ -0,0 +1,86 @@
+use tui::{
+    backend::Backend,
+    layout::{ Constraint, Direction, Rect, Layout },
+    style::{Modifier, Style},
+    text::{Span, Spans, Text},
+    widgets::{Paragraph, Block, Borders },
+    Frame,
+};
+
+use crate::components::user_input::UserInput;
+
+use crate::constants::colors::RED;
+
+// Selects a View Panel by number, for panels without a digit shortcut
+#[derive(Debug)]
+pub struct PanelJumpPrompt {
+    pub input: UserInput,
+    pub open: bool,
+    pub error: Option<String>,
+}
+
+impl PanelJumpPrompt {
+
+    pub fn open(&mut self) {
+        self.input.set_input(String::new());
+        self.open = true;
+        self.error = None;
+    }
+
+    pub fn close(&mut self) {
+        self.input.set_input(String::new());
+        self.open = false;
+        self.error = None;
+    }
+
+    // Accepts:
+    //     num_panels: number of View Panels on the dashboard
+    // Returns:
+    //     the 1-based panel number, or sets self.error if it's out of range
+    pub fn validated_panel_num(&mut self, num_panels: usize) -> Option<usize> {
+        match self.input.input.trim().parse::<usize>() {
+            Ok(num) if num >= 1 && num <= num_panels => Some(num),
+            _ => {
+                self.error = Some(format!("Enter a panel number from 1 to {}", num_panels));
+                None
+            }
+        }
+    }
+
+    pub fn render<B>(&self, f: &mut Frame<B>, area: Rect)
+    where B: Backend,
+    {
+        let row_layout = Layout::default()
+            .direction(Direction::Vertical)
+            .margin(1)
+            .constraints([Constraint::Length(3), Constraint::Length(3)].as_ref())
+            .split(area);
+
+        let msg = match &self.error {
+            Some(error) => Spans::from(Span::styled(error.clone(), Style::default().fg(RED))),
+            None => Spans::from(vec![
+                Span::raw("Press "),
+                Span::styled("Enter", Style::default().add_modifier(Modifier::BOLD)),
+                Span::raw(" to select panel"),
+            ]),
+        };
+
+        f.render_widget(
+            Paragraph::new(Text::from(msg))
+                .block(Block::default().borders(Borders::ALL).title("Jump to Panel")),
+            row_layout[0]
+        );
+
+        self.input.render(f, row_layout[1]);
+    }
+}
+
+impl Default for PanelJumpPrompt {
+    fn default() -> PanelJumpPrompt {
+        PanelJumpPrompt {
+            input: UserInput::new(false),
+            open: false,
+            error: None,
+        }
+    }
+}
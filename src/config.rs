@@ -0,0 +1,849 @@
+// Versioned TOML config file (~/.config/rust-cli/config.toml), holds the workspace profiles (access token & cached viewer),
+// page sizes, tick rate, keybindings, theme, local filters and the dashboard layout.
+// Replaces the pre-TOML config.txt, viewer.txt, view_list.txt, filters.txt, panel_order.txt
+// and panel_columns.txt files, which are migrated on first load.
+
+use std::{
+    collections::{ BTreeMap, HashMap },
+    fmt,
+    fs,
+    ops::Range,
+    path::{ Path, PathBuf },
+};
+
+use anyhow::Result;
+use serde::{ Deserialize, Serialize };
+use serde_json::{ Map, Value };
+
+use crate::app::Platform;
+
+use crate::constants::{
+    LINEAR_TOKEN_LEN,
+    view_panel_columns::{ PanelColumnConfig, lookup_view_panel_column },
+};
+
+use crate::github::{ GithubProject, GithubQuery };
+
+use crate::linear::{
+    config::{ LinearConfig, CachedDataFile, MAX_PAGE_SIZE },
+    filter::LocalFilter,
+    schema::{ CustomView, Viewer },
+};
+
+use crate::util::{
+    grid_layout::GridLayout,
+    issue_order::{ GroupKey, SortKey },
+    keybindings::{ default_key, parse_key, KEYBINDING_ACTIONS },
+    theme::THEME_KEYS,
+    ui::style_color_from_hex_str,
+};
+
+// 2: [dashboard] replaced by named [[dashboards]]
+// 3: [linear] token & viewer moved into named [[profiles]]
+pub const CONFIG_VERSION: u32 = 3;
+
+// Name of the dashboard created for configs without any
+pub const DEFAULT_DASHBOARD_NAME: &str = "Default";
+
+// Name of the profile created for configs without any
+pub const DEFAULT_PROFILE_NAME: &str = "default";
+
+// Suffix appended to pre-TOML files once migrated
+const MIGRATED_FILE_SUFFIX: &str = "migrated";
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct LinearSettings {
+    pub api_endpoint: Option<String>,
+
+    pub issue_page_size: Option<u32>,
+    pub view_panel_page_size: Option<u32>,
+    pub issue_op_page_size: Option<u32>,
+    pub custom_view_page_size: Option<u32>,
+    pub team_timezone_page_size: Option<u32>,
+
+    pub due_soon_day_threshold: Option<u32>,
+}
+
+// A Linear workspace or GitHub account
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ProfileSettings {
+    pub name: String,
+    // "linear" if unset, or "github"
+    pub platform: Option<Platform>,
+    pub token: Option<String>,
+
+    // Cached viewer of the token, not meant to be edited
+    pub viewer: Option<Viewer>,
+
+    // Issue searches listed as views of a GitHub profile
+    pub queries: Vec<GithubQuery>,
+    // Projects listed as views of a GitHub profile, after its queries
+    pub projects: Vec<GithubProject>,
+}
+
+impl ProfileSettings {
+    pub fn platform(&self) -> Platform {
+        self.platform.clone().unwrap_or(Platform::Linear)
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct AppSettings {
+    pub tick_rate_ms: Option<u64>,
+    // Dashboard opened at startup, the last one switched to
+    pub dashboard: Option<String>,
+    // Profile used at startup, the last one switched to
+    pub profile: Option<String>,
+    // Address to accept Linear webhooks on, e.g. "127.0.0.1:8787", no listener if unset
+    pub webhook_listen: Option<String>,
+    // Signing secret of the webhook, unsigned payloads are accepted if unset, required unless listening on loopback
+    pub webhook_secret: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct PanelSettings {
+    pub sort: Option<SortKey>,
+    pub group: Option<GroupKey>,
+    pub columns: Vec<PanelColumnConfig>,
+    // Grid cells covered by the panel, 1 if unset
+    pub row_span: Option<u16>,
+    pub column_span: Option<u16>,
+    // Profile the view is loaded with, the active profile if unset
+    pub profile: Option<String>,
+    // Seconds between automatic refreshes, only refreshed manually if unset
+    pub refresh_interval_secs: Option<u64>,
+    // None for an empty dashboard slot
+    pub view: Option<CustomView>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct DashboardSettings {
+    pub name: String,
+    // Panels are laid out 2 per row if unset
+    pub layout: Option<GridLayout>,
+    // Columns of panels without their own 'columns'
+    pub default_columns: Vec<PanelColumnConfig>,
+    pub panels: Vec<PanelSettings>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ConfigFile {
+    pub version: u32,
+    pub linear: LinearSettings,
+    pub app: AppSettings,
+    pub profiles: Vec<ProfileSettings>,
+    // action name -> key, see KEYBINDING_ACTIONS
+    pub keybindings: BTreeMap<String, String>,
+    // theme key -> hex color, see THEME_KEYS
+    pub theme: BTreeMap<String, String>,
+    pub filters: Vec<LocalFilter>,
+    pub dashboards: Vec<DashboardSettings>,
+}
+
+impl Default for ConfigFile {
+    fn default() -> ConfigFile {
+        ConfigFile {
+            version: CONFIG_VERSION,
+            linear: LinearSettings::default(),
+            app: AppSettings::default(),
+            profiles: Vec::new(),
+            keybindings: BTreeMap::new(),
+            theme: BTreeMap::new(),
+            filters: Vec::new(),
+            dashboards: Vec::new(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConfigError {
+    // 1-based line of config.toml, if known
+    pub line: Option<usize>,
+    pub message: String,
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.line {
+            Some(line) => write!(f, "config.toml:{}: {}", line, self.message),
+            None => write!(f, "config.toml: {}", self.message),
+        }
+    }
+}
+
+// Header of a "[table]" or "[[table]]" line
+fn table_header(line: &str) -> Option<&str> {
+    let line = line.trim_start();
+    if !line.starts_with('[') {
+        return None;
+    }
+    let header = line.trim_start_matches('[');
+    header.find(']').map(|end| header[..end].trim())
+}
+
+// 0-based lines of the 'nth' "[name]" or "[[name]]" table within 'within', including its sub-tables
+// Empty if there's no such table, so errors in it have no line number
+fn find_table(text: &str, within: &Range<usize>, name: &str, nth: usize) -> Range<usize> {
+    let lines: Vec<&str> = text.lines().collect();
+    let end = within.end.min(lines.len());
+    let start = match (within.start..end).filter(|idx| table_header(lines[*idx]) == Some(name)).nth(nth) {
+        Some(idx) => idx + 1,
+        None => return 0..0,
+    };
+
+    let prefix = format!("{}.", name);
+    let end = (start..end)
+        .find(|idx| table_header(lines[*idx]).is_some_and(|header| !header.starts_with(&prefix)))
+        .unwrap_or(end);
+    start..end
+}
+
+// Lines before the first table header
+fn root_table(text: &str) -> Range<usize> {
+    0..text.lines().position(|line| table_header(line).is_some()).unwrap_or_else(|| text.lines().count())
+}
+
+// 1-based line of the first line of 'table' containing 'needle', used to locate semantic errors
+fn find_line(text: &str, table: &Range<usize>, needle: &str) -> Option<usize> {
+    text.lines().enumerate()
+        .skip(table.start)
+        .take(table.len())
+        .find(|(_, line)| line.contains(needle))
+        .map(|(idx, _)| idx + 1)
+}
+
+// Line of a "key = ..." assignment, directly in 'table' rather than one of its sub-tables
+fn find_key_line(text: &str, table: &Range<usize>, key: &str) -> Option<usize> {
+    text.lines().enumerate()
+        .skip(table.start)
+        .take(table.len())
+        .take_while(|(_, line)| table_header(line).is_none())
+        .find(|(_, line)| {
+            let line = line.trim_start();
+            line.starts_with(key) && line[key.len()..].trim_start().starts_with('=')
+        })
+        .map(|(idx, _)| idx + 1)
+}
+
+// Line of a quoted string value, either quote style
+fn find_str_line(text: &str, table: &Range<usize>, value: &str) -> Option<usize> {
+    find_line(text, table, &format!("\"{}\"", value)).or_else(|| find_line(text, table, &format!("'{}'", value)))
+}
+
+// Upgrade an older config in place, returns false if 'version' is missing or unsupported
+fn migrate_config_value(value: &mut toml::Value) -> bool {
+    let table = match value.as_table_mut() {
+        Some(table) => table,
+        None => return false,
+    };
+    let mut version = match table.get("version").and_then(|x| x.as_integer()) {
+        Some(version) if version > 0 && version <= CONFIG_VERSION as i64 => version,
+        _ => return false,
+    };
+
+    // 1 -> 2: the single [dashboard] becomes the first of [[dashboards]]
+    if version == 1 {
+        if let Some(toml::Value::Table(mut dashboard)) = table.remove("dashboard") {
+            dashboard.insert(String::from("name"), toml::Value::String(DEFAULT_DASHBOARD_NAME.to_string()));
+            table.insert(String::from("dashboards"), toml::Value::Array(vec![toml::Value::Table(dashboard)]));
+        }
+        version = 2;
+    }
+
+    // 2 -> 3: [linear] token & viewer become the default profile, which existing panels are loaded with
+    if version == 2 {
+        let mut profile = toml::value::Table::new();
+        if let Some(toml::Value::Table(linear)) = table.get_mut("linear") {
+            for key in ["token", "viewer"].iter() {
+                if let Some(value) = linear.remove(*key) {
+                    profile.insert(key.to_string(), value);
+                }
+            }
+        }
+
+        if profile.contains_key("token") {
+            if let Some(toml::Value::Array(dashboards)) = table.get_mut("dashboards") {
+                let panels = dashboards.iter_mut()
+                    .filter_map(|dashboard| dashboard.get_mut("panels").and_then(|x| x.as_array_mut()))
+                    .flat_map(|panels| panels.iter_mut());
+                for panel in panels.filter_map(|panel| panel.as_table_mut()) {
+                    if panel.contains_key("view") && !panel.contains_key("profile") {
+                        panel.insert(String::from("profile"), toml::Value::String(DEFAULT_PROFILE_NAME.to_string()));
+                    }
+                }
+            }
+        }
+
+        if !profile.is_empty() {
+            profile.insert(String::from("name"), toml::Value::String(DEFAULT_PROFILE_NAME.to_string()));
+            table.insert(String::from("profiles"), toml::Value::Array(vec![toml::Value::Table(profile)]));
+        }
+        version = 3;
+    }
+
+    table.insert(String::from("version"), toml::Value::Integer(version));
+    true
+}
+
+// TOML has no null, drop null values from JSON objects before serializing
+fn strip_json_nulls(map: &mut Map<String, Value>) {
+    let null_keys: Vec<String> = map.iter()
+        .filter(|(_, value)| value.is_null())
+        .map(|(key, _)| key.clone())
+        .collect();
+    for key in null_keys {
+        map.remove(&key);
+    }
+    for value in map.values_mut() {
+        match value {
+            Value::Object(inner) => strip_json_nulls(inner),
+            Value::Array(items) => {
+                items.retain(|item| !item.is_null());
+                for item in items.iter_mut() {
+                    if let Value::Object(inner) = item {
+                        strip_json_nulls(inner);
+                    }
+                }
+            },
+            _ => {},
+        }
+    }
+}
+
+// Whether two values are equal regardless of formatting, e.g. quote style
+fn same_value(a: &toml_edit::Value, b: &toml_edit::Value) -> bool {
+    use toml_edit::Value;
+
+    match (a, b) {
+        (Value::String(a), Value::String(b)) => a.value() == b.value(),
+        (Value::Integer(a), Value::Integer(b)) => a.value() == b.value(),
+        (Value::Float(a), Value::Float(b)) => a.value() == b.value(),
+        (Value::Boolean(a), Value::Boolean(b)) => a.value() == b.value(),
+        (Value::Datetime(a), Value::Datetime(b)) => a.value() == b.value(),
+        (Value::Array(a), Value::Array(b)) => {
+            a.len() == b.len() && a.iter().zip(b.iter()).all(|(a, b)| same_value(a, b))
+        },
+        (Value::InlineTable(a), Value::InlineTable(b)) => {
+            a.len() == b.len() && a.iter().all(|(key, a)| b.get(key).is_some_and(|b| same_value(a, b)))
+        },
+        _ => false,
+    }
+}
+
+// Make 'table' hold the same values as 'updated', keeping the comments & formatting of unchanged entries
+fn patch_table(table: &mut dyn toml_edit::TableLike, updated: &dyn toml_edit::TableLike) {
+    let removed: Vec<String> = table.iter()
+        .filter(|(key, _)| !updated.contains_key(key))
+        .map(|(key, _)| key.to_string())
+        .collect();
+    for key in removed {
+        table.remove(&key);
+    }
+
+    for (key, updated_item) in updated.iter() {
+        match table.get_mut(key) {
+            Some(item) => patch_item(item, updated_item),
+            // Defaults like 'filters = []' aren't added
+            None if is_empty_item(updated_item) => {},
+            None => { table.insert(key, updated_item.clone()); },
+        }
+    }
+}
+
+fn is_empty_item(item: &toml_edit::Item) -> bool {
+    match item {
+        toml_edit::Item::None => true,
+        toml_edit::Item::ArrayOfTables(tables) => tables.is_empty(),
+        toml_edit::Item::Value(toml_edit::Value::Array(array)) => array.is_empty(),
+        item => item.as_table_like().is_some_and(|table| table.is_empty()),
+    }
+}
+
+fn patch_item(item: &mut toml_edit::Item, updated: &toml_edit::Item) {
+    use toml_edit::Item;
+
+    match (item, updated) {
+        (Item::ArrayOfTables(tables), Item::ArrayOfTables(updated_tables)) => {
+            while tables.len() > updated_tables.len() {
+                tables.remove(tables.len() - 1);
+            }
+            for (idx, updated_table) in updated_tables.iter().enumerate() {
+                match tables.get_mut(idx) {
+                    Some(table) => patch_table(table, updated_table),
+                    None => tables.push(updated_table.clone()),
+                }
+            }
+        },
+        (Item::Value(value), Item::Value(updated_value)) => {
+            if !same_value(value, updated_value) {
+                // Keep inline tables inline & patch them, other values are replaced with their decor kept
+                match (value.as_inline_table_mut(), updated_value.as_inline_table()) {
+                    (Some(inline), Some(updated_inline)) => patch_table(inline, updated_inline),
+                    _ => {
+                        let decor = value.decor().clone();
+                        *value = updated_value.clone();
+                        *value.decor_mut() = decor;
+                    },
+                }
+            }
+        },
+        (item, updated) => match (item.as_table_like_mut(), updated.as_table_like()) {
+            // e.g. a table written inline by hand
+            (Some(table), Some(updated_table)) => patch_table(table, updated_table),
+            (_, _) => *item = updated.clone(),
+        },
+    }
+}
+
+// Write to a temporary file & rename it over 'path', so an interrupted write can't truncate 'path'
+fn write_atomic(path: &Path, contents: &str) -> Result<()> {
+    let mut tmp_path = path.to_path_buf().into_os_string();
+    tmp_path.push(".tmp");
+
+    fs::write(&tmp_path, contents)?;
+    if let Err(err) = fs::rename(&tmp_path, path) {
+        let _ = fs::remove_file(&tmp_path);
+        return Err(err.into());
+    }
+    Ok(())
+}
+
+// Rename each migrated pre-TOML file, so it isn't migrated again
+fn mark_migrated(paths: Vec<PathBuf>) {
+    for path in paths {
+        let mut migrated_path = path.clone().into_os_string();
+        migrated_path.push(format!(".{}", MIGRATED_FILE_SUFFIX));
+        if let Err(err) = fs::rename(&path, &migrated_path) {
+            error!("mark_migrated() - failed to rename {:?}: {:?}", path, err);
+        } else {
+            info!("mark_migrated() - migrated {:?}", path);
+        }
+    }
+}
+
+pub fn config_path() -> PathBuf {
+    LinearConfig::get_or_build_paths(CachedDataFile::ConfigFile)
+}
+
+impl ConfigFile {
+
+    // Accepts:
+    //     text: contents of config.toml
+    // Returns:
+    //     all syntax and validation errors, with line numbers where possible
+    pub fn parse(text: &str) -> Result<ConfigFile, Vec<ConfigError>> {
+        ConfigFile::parse_versioned(text).map(|(config, _)| config)
+    }
+
+    // Also returns whether the config was upgraded from an older version
+    fn parse_versioned(text: &str) -> Result<(ConfigFile, bool), Vec<ConfigError>> {
+        let syntax_error = |err: toml::de::Error| {
+            vec![ConfigError {
+                line: err.line_col().map(|(line, _)| line + 1),
+                message: err.to_string(),
+            }]
+        };
+
+        let mut value: toml::Value = toml::from_str(text).map_err(syntax_error)?;
+        let is_current = value.get("version").and_then(|x| x.as_integer()) == Some(CONFIG_VERSION as i64);
+
+        // Deserialize from text where possible, so errors keep their line numbers
+        let migrated = !is_current && migrate_config_value(&mut value);
+        let config: ConfigFile = if migrated {
+            value.try_into().map_err(syntax_error)?
+        } else {
+            toml::from_str(text).map_err(syntax_error)?
+        };
+
+        let errors = config.validate(text);
+        if errors.is_empty() { Ok((config, migrated)) } else { Err(errors) }
+    }
+
+    fn validate(&self, text: &str) -> Vec<ConfigError> {
+        let mut errors = Vec::new();
+        let mut error = |line: Option<usize>, message: String| errors.push(ConfigError { line, message });
+
+        // Errors are located within the table they belong to, keys like 'name' repeat across tables
+        let whole = 0..text.lines().count();
+        let table = |name: &str| find_table(text, &whole, name, 0);
+
+        if self.version == 0 || self.version > CONFIG_VERSION {
+            error(find_key_line(text, &root_table(text), "version"), format!("unsupported config version {}, expected {}", self.version, CONFIG_VERSION));
+        }
+
+        // [linear]
+        let linear_table = table("linear");
+        let page_sizes = [
+            ("issue_page_size", self.linear.issue_page_size),
+            ("view_panel_page_size", self.linear.view_panel_page_size),
+            ("issue_op_page_size", self.linear.issue_op_page_size),
+            ("custom_view_page_size", self.linear.custom_view_page_size),
+            ("team_timezone_page_size", self.linear.team_timezone_page_size),
+        ];
+        for (key, page_size) in page_sizes.iter() {
+            if let Some(page_size) = page_size {
+                if *page_size == 0 || *page_size > MAX_PAGE_SIZE {
+                    error(find_key_line(text, &linear_table, key), format!("{} must be between 1 and {}", key, MAX_PAGE_SIZE));
+                }
+            }
+        }
+
+        // [app]
+        let app_table = table("app");
+        if self.app.tick_rate_ms == Some(0) {
+            error(find_key_line(text, &app_table, "tick_rate_ms"), String::from("tick_rate_ms must be greater than 0"));
+        }
+        if let Some(addr) = &self.app.webhook_listen {
+            match addr.parse::<std::net::SocketAddr>() {
+                Err(_) => {
+                    error(find_key_line(text, &app_table, "webhook_listen"), format!("invalid webhook_listen address {:?}, expected e.g. \"127.0.0.1:8787\"", addr));
+                },
+                // Unsigned payloads are only accepted from the local machine
+                Ok(addr) if !addr.ip().is_loopback() && self.app.webhook_secret.is_none() => {
+                    error(find_key_line(text, &app_table, "webhook_listen"), format!("webhook_listen address {} isn't loopback, so needs a webhook_secret", addr));
+                },
+                Ok(_) => {},
+            }
+        }
+
+        // [[profiles]]
+        for (idx, profile) in self.profiles.iter().enumerate() {
+            let profile_table = find_table(text, &whole, "profiles", idx);
+            if profile.name.trim().is_empty() {
+                error(None, format!("profile {} has no name", idx + 1));
+            } else if self.profiles[..idx].iter().any(|x| x.name == profile.name) {
+                error(find_str_line(text, &profile_table, &profile.name), format!("duplicate profile name '{}'", profile.name));
+            }
+
+            match profile.platform() {
+                Platform::Linear => {
+                    if let Some(token) = &profile.token {
+                        if unicode_width::UnicodeWidthStr::width(token.as_str()) as u16 != LINEAR_TOKEN_LEN {
+                            error(find_str_line(text, &profile_table, token).or_else(|| find_key_line(text, &profile_table, "token")),
+                                format!("token of profile '{}' must be {} characters", profile.name, LINEAR_TOKEN_LEN));
+                        }
+                    }
+                    if !profile.queries.is_empty() {
+                        error(find_key_line(text, &profile_table, "queries").or_else(|| find_line(text, &profile_table, "[[profiles.queries]]")),
+                            format!("profile '{}' has queries, which are only supported by GitHub profiles", profile.name));
+                    }
+                    if !profile.projects.is_empty() {
+                        error(find_key_line(text, &profile_table, "projects").or_else(|| find_line(text, &profile_table, "[[profiles.projects]]")),
+                            format!("profile '{}' has projects, which are only supported by GitHub profiles", profile.name));
+                    }
+                },
+                Platform::Github => {
+                    if profile.token.is_none() {
+                        error(find_str_line(text, &profile_table, &profile.name), format!("GitHub profile '{}' needs a token", profile.name));
+                    }
+                    for query in profile.queries.iter().filter(|query| query.query.trim().is_empty()) {
+                        error(find_str_line(text, &profile_table, &query.name), format!("query '{}' of profile '{}' is empty", query.name, profile.name));
+                    }
+                    for project in profile.projects.iter().filter(|project| project.owner.trim().is_empty()) {
+                        error(find_str_line(text, &profile_table, &project.name), format!("project '{}' of profile '{}' has no owner", project.name, profile.name));
+                    }
+                },
+                Platform::Na => {
+                    error(find_key_line(text, &profile_table, "platform"), format!("invalid platform of profile '{}', expected \"linear\" or \"github\"", profile.name));
+                },
+            }
+        }
+        if let Some(name) = &self.app.profile {
+            if !self.profiles.is_empty() && self.profile(name).is_none() {
+                error(find_key_line(text, &app_table, "profile"), format!("unknown profile '{}'", name));
+            }
+        }
+
+        // [keybindings]
+        let keybindings_table = table("keybindings");
+        let mut bound_keys: HashMap<char, &str> = HashMap::new();
+        for (action, default) in KEYBINDING_ACTIONS.iter() {
+            let key = match self.keybindings.get(*action) {
+                Some(value) => match parse_key(value) {
+                    Some(key) => key,
+                    None => {
+                        error(find_key_line(text, &keybindings_table, action), format!("invalid key {:?} for '{}', expected a single non-digit character or \"space\"", value, action));
+                        continue;
+                    }
+                },
+                None => *default,
+            };
+            if let Some(other) = bound_keys.insert(key, action) {
+                error(find_key_line(text, &keybindings_table, action).or_else(|| find_key_line(text, &keybindings_table, other)),
+                    format!("key {:?} is bound to both '{}' and '{}'", key, other, action));
+            }
+        }
+        for action in self.keybindings.keys() {
+            if default_key(action).is_none() {
+                error(find_key_line(text, &keybindings_table, action), format!("unknown keybinding action '{}'", action));
+            }
+        }
+
+        // [theme]
+        let theme_table = table("theme");
+        for (key, value) in self.theme.iter() {
+            if !THEME_KEYS.contains(&key.as_str()) {
+                error(find_key_line(text, &theme_table, key), format!("unknown theme key '{}'", key));
+            } else if style_color_from_hex_str(value).is_none() {
+                error(find_key_line(text, &theme_table, key), format!("invalid color {:?} for '{}', expected a hex color", value, key));
+            }
+        }
+
+        // [[filters]]
+        for (idx, local_filter) in self.filters.iter().enumerate() {
+            if let Err(err) = local_filter.to_custom_view() {
+                error(find_line(text, &find_table(text, &whole, "filters", idx), &local_filter.expression), format!("invalid filter '{}': {}", local_filter.name, err));
+            }
+        }
+
+        // [[dashboards]]
+        for (idx, dashboard) in self.dashboards.iter().enumerate() {
+            let dashboard_table = find_table(text, &whole, "dashboards", idx);
+            if dashboard.name.trim().is_empty() {
+                error(None, format!("dashboard {} has no name", idx + 1));
+            } else if self.dashboards[..idx].iter().any(|x| x.name == dashboard.name) {
+                error(find_str_line(text, &dashboard_table, &dashboard.name), format!("duplicate dashboard name '{}'", dashboard.name));
+            }
+
+            if let Some(layout) = &dashboard.layout {
+                if layout.rows.contains(&0) || layout.columns.is_empty() || layout.columns.contains(&0) {
+                    let layout_table = find_table(text, &dashboard_table, "dashboards.layout", 0);
+                    error(find_key_line(text, &layout_table, "columns").or_else(|| find_key_line(text, &layout_table, "rows")),
+                        format!("layout of dashboard '{}' needs at least one column, and row & column sizes greater than 0", dashboard.name));
+                }
+            }
+            for (panel_idx, panel) in dashboard.panels.iter().enumerate() {
+                let panel_table = find_table(text, &dashboard_table, "dashboards.panels", panel_idx);
+                if panel.row_span == Some(0) || panel.column_span == Some(0) {
+                    error(find_key_line(text, &panel_table, "row_span").or_else(|| find_key_line(text, &panel_table, "column_span")),
+                        format!("panel spans of dashboard '{}' must be greater than 0", dashboard.name));
+                }
+                if panel.refresh_interval_secs == Some(0) {
+                    error(find_key_line(text, &panel_table, "refresh_interval_secs"),
+                        format!("panel refresh intervals of dashboard '{}' must be greater than 0", dashboard.name));
+                }
+                if let Some(profile) = &panel.profile {
+                    if self.profile(profile).is_none() {
+                        error(find_str_line(text, &panel_table, profile), format!("unknown profile '{}' in dashboard '{}'", profile, dashboard.name));
+                    }
+                }
+            }
+
+            let column_configs = dashboard.default_columns.iter()
+                .chain(dashboard.panels.iter().flat_map(|panel| panel.columns.iter()));
+            for column_config in column_configs {
+                if lookup_view_panel_column(&column_config.id).is_none() {
+                    error(find_str_line(text, &dashboard_table, &column_config.id), format!("unknown column id '{}'", column_config.id));
+                }
+            }
+        }
+        if let Some(name) = &self.app.dashboard {
+            if !self.dashboards.is_empty() && self.dashboard(name).is_none() {
+                error(find_key_line(text, &app_table, "dashboard"), format!("unknown dashboard '{}'", name));
+            }
+        }
+
+        errors
+    }
+
+    // Load config.toml, migrating the pre-TOML files if it doesn't exist yet
+    pub fn load() -> Result<ConfigFile, Vec<ConfigError>> {
+        let path = config_path();
+
+        if !path.exists() {
+            let (config, legacy_paths) = ConfigFile::migrate_legacy_files();
+            // Legacy files are kept until their contents are safely in config.toml
+            match config.save() {
+                Ok(()) => mark_migrated(legacy_paths),
+                Err(err) => error!("ConfigFile::load() - failed to write {:?}: {:?}", path, err),
+            }
+            return Ok(config);
+        }
+
+        let text = fs::read_to_string(&path).map_err(|err| {
+            vec![ConfigError { line: None, message: format!("failed to read {:?}: {}", path, err) }]
+        })?;
+
+        let (config, migrated) = ConfigFile::parse_versioned(&text)?;
+
+        // Write back configs upgraded from an older version
+        if migrated {
+            info!("ConfigFile::load() - upgrading {:?} to version {}", path, CONFIG_VERSION);
+            if let Err(err) = config.save() {
+                error!("ConfigFile::load() - failed to write {:?}: {:?}", path, err);
+            }
+        }
+
+        Ok(config)
+    }
+
+    pub fn dashboard(&self, name: &str) -> Option<&DashboardSettings> {
+        self.dashboards.iter().find(|x| x.name == name)
+    }
+
+    // Creates the dashboard if it doesn't exist
+    pub fn dashboard_mut(&mut self, name: &str) -> &mut DashboardSettings {
+        let idx = match self.dashboards.iter().position(|x| x.name == name) {
+            Some(idx) => idx,
+            None => {
+                self.dashboards.push(DashboardSettings { name: name.to_string(), ..DashboardSettings::default() });
+                self.dashboards.len() - 1
+            }
+        };
+        &mut self.dashboards[idx]
+    }
+
+    pub fn dashboard_names(&self) -> Vec<String> {
+        self.dashboards.iter().map(|x| x.name.clone()).collect()
+    }
+
+    pub fn profile(&self, name: &str) -> Option<&ProfileSettings> {
+        self.profiles.iter().find(|x| x.name == name)
+    }
+
+    // Creates the profile if it doesn't exist
+    pub fn profile_mut(&mut self, name: &str) -> &mut ProfileSettings {
+        let idx = match self.profiles.iter().position(|x| x.name == name) {
+            Some(idx) => idx,
+            None => {
+                self.profiles.push(ProfileSettings { name: name.to_string(), ..ProfileSettings::default() });
+                self.profiles.len() - 1
+            }
+        };
+        &mut self.profiles[idx]
+    }
+
+    pub fn profile_names(&self) -> Vec<String> {
+        self.profiles.iter().map(|x| x.name.clone()).collect()
+    }
+
+    pub fn save(&self) -> Result<()> {
+        let path = config_path();
+        let existing = fs::read_to_string(&path).ok();
+        write_atomic(&path, &self.to_toml(existing.as_deref())?)?;
+        Ok(())
+    }
+
+    // Accepts:
+    //     existing: current contents of config.toml, whose comments & formatting are kept
+    // Returns:
+    //     config.toml contents, only values which changed are rewritten
+    pub fn to_toml(&self, existing: Option<&str>) -> Result<String> {
+        let mut config = self.clone();
+        let panels = config.dashboards.iter_mut().flat_map(|dashboard| dashboard.panels.iter_mut());
+        for view in panels.filter_map(|panel| panel.view.as_mut()) {
+            strip_json_nulls(&mut view.filter_data);
+            strip_json_nulls(&mut view.filters);
+        }
+
+        // Serialize through toml::Value, so plain values are emitted before tables
+        let serialized = toml::to_string_pretty(&toml::Value::try_from(&config)?)?;
+
+        // An unparseable config.toml is replaced
+        match existing.and_then(|text| text.parse::<toml_edit::DocumentMut>().ok()) {
+            Some(mut document) => {
+                let updated: toml_edit::DocumentMut = serialized.parse()?;
+                patch_table(document.as_table_mut(), updated.as_table());
+                Ok(document.to_string())
+            },
+            None => Ok(serialized),
+        }
+    }
+
+    // Load, modify and save config.toml, an invalid config.toml is left untouched
+    pub fn update<F: FnOnce(&mut ConfigFile)>(f: F) {
+        match ConfigFile::load() {
+            Ok(mut config) => {
+                f(&mut config);
+                if let Err(err) = config.save() {
+                    error!("ConfigFile::update() - save failed: {:?}", err);
+                }
+            },
+            Err(errors) => {
+                error!("ConfigFile::update() - not saving, config.toml is invalid: {:?}", errors);
+            },
+        }
+    }
+
+    // Build a config from the pre-TOML files
+    // Returns:
+    //     (config, paths of the files it was built from)
+    fn migrate_legacy_files() -> (ConfigFile, Vec<PathBuf>) {
+        let mut config = ConfigFile::default();
+
+        let read_legacy = |data_file: CachedDataFile| -> Option<(PathBuf, String)> {
+            let path = LinearConfig::get_or_build_paths(data_file);
+            fs::read_to_string(&path).ok().map(|data| (path, data))
+        };
+        let mut migrated: Vec<PathBuf> = Vec::new();
+
+        if let Some((path, token)) = read_legacy(CachedDataFile::AccessToken) {
+            config.profile_mut(DEFAULT_PROFILE_NAME).token = Some(token.trim().to_string());
+            migrated.push(path);
+        }
+
+        if let Some((path, data)) = read_legacy(CachedDataFile::ViewerObject) {
+            match serde_json::from_str::<Viewer>(&data) {
+                Ok(viewer) => config.profile_mut(DEFAULT_PROFILE_NAME).viewer = Some(viewer),
+                Err(err) => error!("migrate_legacy_files() - invalid {:?}: {:?}", path, err),
+            }
+            migrated.push(path);
+        }
+
+        let panel_orders: HashMap<String, (Option<SortKey>, Option<GroupKey>)> = read_legacy(CachedDataFile::PanelOrder)
+            .map(|(path, data)| {
+                migrated.push(path);
+                serde_json::from_str::<HashMap<String, Value>>(&data).unwrap_or_default()
+                    .into_iter()
+                    .map(|(id, order)| (id, (
+                        serde_json::from_value(order["sort"].clone()).ok().flatten(),
+                        serde_json::from_value(order["group"].clone()).ok().flatten(),
+                    )))
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        let mut panel_columns: HashMap<String, Vec<PanelColumnConfig>> = read_legacy(CachedDataFile::PanelColumns)
+            .map(|(path, data)| {
+                migrated.push(path);
+                serde_json::from_str(&data).unwrap_or_default()
+            })
+            .unwrap_or_default();
+        let dashboard = config.dashboard_mut(DEFAULT_DASHBOARD_NAME);
+        dashboard.default_columns = panel_columns.remove("default").unwrap_or_default();
+
+        // Legacy panels belong to the migrated token
+        let profile: Option<String> = config.profile(DEFAULT_PROFILE_NAME).map(|x| x.name.clone());
+
+        if let Some((path, data)) = read_legacy(CachedDataFile::ViewList) {
+            let view_list: Vec<Option<CustomView>> = serde_json::from_str(&data).unwrap_or_default();
+            config.dashboard_mut(DEFAULT_DASHBOARD_NAME).panels = view_list.into_iter()
+                .map(|view| match view {
+                    Some(view) => {
+                        let (sort, group) = panel_orders.get(&view.id).copied().unwrap_or((None, None));
+                        let columns = panel_columns.get(&view.id)
+                            .or_else(|| panel_columns.get(&view.name))
+                            .cloned()
+                            .unwrap_or_default();
+                        PanelSettings { sort, group, columns, profile: profile.clone(), view: Some(view), ..PanelSettings::default() }
+                    },
+                    None => PanelSettings::default(),
+                })
+                .collect();
+            migrated.push(path);
+        }
+
+        if let Some((path, data)) = read_legacy(CachedDataFile::LocalFilters) {
+            config.filters = data.lines().filter_map(LocalFilter::parse_line).collect();
+            migrated.push(path);
+        }
+
+        (config, migrated)
+    }
+}
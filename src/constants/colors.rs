@@ -14,6 +14,20 @@ pub const DASHBOARD_VIEW_LIST_TABLE_TITLE: Color = Color::Rgb(255u8, 255u8, 255u
 // View Panel Colors
 pub const SELECTED_COMPONENT_BORDER: Color = Color::Yellow;
 
+// Due Date Colors
+pub const DUE_DATE_OVERDUE: Color = Color::Rgb( 235u8, 87u8, 87u8 );
+pub const DUE_DATE_SOON: Color = Color::Rgb( 242u8, 153u8, 74u8 );
+
+// Due Date Picker Colors
+pub const DUE_DATE_PICKER_WEEKDAY: Color = Color::LightGreen;
+pub const DUE_DATE_PICKER_CURRENT: Color = Color::Rgb( 242u8, 201u8, 76u8 );
+
+// View Panel group header rows
+pub const PANEL_GROUP_HEADER: Color = Color::Rgb( 76u8, 164u8, 242u8 );
+
+// View Panel rows added or changed by an automatic refresh
+pub const PANEL_REFRESHED_ROW: Color = Color::Rgb( 38u8, 77u8, 60u8 );
+
 
 // Command Bar Colors
 
@@ -29,6 +43,44 @@ pub const REPLACE_VIEW_CMD_INACTIVE: Color = Color::Rgb( 40u8, 7u8, 242u8 );
 pub const DELETE_VIEW_CMD_ACTIVE: Color = Color::Rgb( 184u8, 4u8, 4u8 );
 pub const DELETE_VIEW_CMD_INACTIVE: Color = Color::Rgb( 138u8, 3u8, 3u8 );
 
+// Dashboard management (25% darker for inactive) -- https://pinetools.com/darken-color
+// Green
+pub const NEW_DASHBOARD_CMD_ACTIVE: Color = Color::Rgb( 111u8, 207u8, 151u8 );
+pub const NEW_DASHBOARD_CMD_INACTIVE: Color = Color::Rgb( 58u8, 171u8, 105u8 );
+// Lavender
+pub const RENAME_DASHBOARD_CMD_ACTIVE: Color = Color::Rgb( 187u8, 135u8, 252u8 );
+pub const RENAME_DASHBOARD_CMD_INACTIVE: Color = Color::Rgb( 134u8, 44u8, 249u8 );
+// Sky
+pub const DUPLICATE_DASHBOARD_CMD_ACTIVE: Color = Color::Rgb( 86u8, 204u8, 242u8 );
+pub const DUPLICATE_DASHBOARD_CMD_INACTIVE: Color = Color::Rgb( 15u8, 167u8, 216u8 );
+// Amber
+pub const SWITCH_DASHBOARD_CMD_ACTIVE: Color = Color::Rgb( 242u8, 201u8, 76u8 );
+pub const SWITCH_DASHBOARD_CMD_INACTIVE: Color = Color::Rgb( 219u8, 168u8, 15u8 );
+
+// Pink (25% darker for inactive)
+pub const SWITCH_PROFILE_CMD_ACTIVE: Color = Color::Rgb( 235u8, 87u8, 153u8 );
+pub const SWITCH_PROFILE_CMD_INACTIVE: Color = Color::Rgb( 216u8, 26u8, 110u8 );
+
+// Panel navigation (25% darker for inactive)
+// Peach
+pub const ZOOM_PANEL_CMD_ACTIVE: Color = Color::Rgb( 247u8, 172u8, 135u8 );
+pub const ZOOM_PANEL_CMD_INACTIVE: Color = Color::Rgb( 241u8, 110u8, 44u8 );
+// Mint
+pub const JUMP_TO_PANEL_CMD_ACTIVE: Color = Color::Rgb( 136u8, 232u8, 196u8 );
+pub const JUMP_TO_PANEL_CMD_INACTIVE: Color = Color::Rgb( 45u8, 212u8, 150u8 );
+
+// Sky blue (25% darker for inactive) -- https://pinetools.com/darken-color
+pub const EXPORT_PANEL_CMD_ACTIVE: Color = Color::Rgb( 125u8, 211u8, 252u8 );
+pub const EXPORT_PANEL_CMD_INACTIVE: Color = Color::Rgb( 44u8, 184u8, 250u8 );
+
+// Issue history (25% darker for inactive)
+// Lavender
+pub const UNDO_MODIFICATION_CMD_ACTIVE: Color = Color::Rgb( 196u8, 181u8, 253u8 );
+pub const UNDO_MODIFICATION_CMD_INACTIVE: Color = Color::Rgb( 128u8, 97u8, 250u8 );
+// Lilac
+pub const REDO_MODIFICATION_CMD_ACTIVE: Color = Color::Rgb( 240u8, 171u8, 252u8 );
+pub const REDO_MODIFICATION_CMD_INACTIVE: Color = Color::Rgb( 229u8, 99u8, 250u8 );
+
 // Teal (25% darker for inactive) -- https://pinetools.com/darken-color
 pub const REFRESH_PANEL_CMD_ACTIVE: Color = Color::Rgb( 81u8, 193u8, 177u8 );
 pub const REFRESH_PANEL_CMD_INACTIVE: Color = Color::Rgb( 53u8, 151u8, 137u8 );
@@ -51,4 +103,40 @@ pub const MODIFY_PROJECT_CMD_INACTIVE: Color = Color::Rgb( 61u8, 18u8, 173u8 );
 
 // Green (25% darker for inactive) -- https://pinetools.com/darken-color
 pub const MODIFY_CYCLE_CMD_ACTIVE: Color = Color::Rgb( 14u8, 138u8, 22u8 );
-pub const MODIFY_CYCLE_CMD_INACTIVE: Color = Color::Rgb( 10u8, 103u8, 16u8 );
\ No newline at end of file
+pub const MODIFY_CYCLE_CMD_INACTIVE: Color = Color::Rgb( 10u8, 103u8, 16u8 );
+
+// Yellow (25% darker for inactive) -- https://pinetools.com/darken-color
+pub const MODIFY_LABELS_CMD_ACTIVE: Color = Color::Rgb( 242u8, 201u8, 76u8 );
+pub const MODIFY_LABELS_CMD_INACTIVE: Color = Color::Rgb( 212u8, 164u8, 16u8 );
+
+// Pink (25% darker for inactive) -- https://pinetools.com/darken-color
+pub const MODIFY_TEAM_CMD_ACTIVE: Color = Color::Rgb( 227u8, 28u8, 151u8 );
+pub const MODIFY_TEAM_CMD_INACTIVE: Color = Color::Rgb( 170u8, 21u8, 113u8 );
+
+// Light Red (25% darker for inactive) -- https://pinetools.com/darken-color
+pub const MODIFY_PRIORITY_CMD_ACTIVE: Color = Color::Rgb( 242u8, 100u8, 76u8 );
+pub const MODIFY_PRIORITY_CMD_INACTIVE: Color = Color::Rgb( 212u8, 48u8, 16u8 );
+
+// Light Blue (25% darker for inactive) -- https://pinetools.com/darken-color
+pub const MODIFY_ESTIMATE_CMD_ACTIVE: Color = Color::Rgb( 76u8, 164u8, 242u8 );
+pub const MODIFY_ESTIMATE_CMD_INACTIVE: Color = Color::Rgb( 16u8, 122u8, 212u8 );
+
+// Gold (25% darker for inactive) -- https://pinetools.com/darken-color
+pub const MODIFY_DUE_DATE_CMD_ACTIVE: Color = Color::Rgb( 242u8, 182u8, 76u8 );
+pub const MODIFY_DUE_DATE_CMD_INACTIVE: Color = Color::Rgb( 212u8, 141u8, 16u8 );
+
+// Brown (25% darker for inactive) -- https://pinetools.com/darken-color
+pub const MODIFY_DESCRIPTION_CMD_ACTIVE: Color = Color::Rgb( 196u8, 138u8, 84u8 );
+pub const MODIFY_DESCRIPTION_CMD_INACTIVE: Color = Color::Rgb( 153u8, 101u8, 52u8 );
+
+// Lavender (25% darker for inactive) -- https://pinetools.com/darken-color
+pub const SORT_PANEL_CMD_ACTIVE: Color = Color::Rgb( 167u8, 139u8, 250u8 );
+pub const SORT_PANEL_CMD_INACTIVE: Color = Color::Rgb( 103u8, 60u8, 246u8 );
+
+// Teal (25% darker for inactive) -- https://pinetools.com/darken-color
+pub const GROUP_PANEL_CMD_ACTIVE: Color = Color::Rgb( 45u8, 212u8, 191u8 );
+pub const GROUP_PANEL_CMD_INACTIVE: Color = Color::Rgb( 31u8, 161u8, 145u8 );
+
+// Light Green (25% darker for inactive) -- https://pinetools.com/darken-color
+pub const CREATE_ISSUE_CMD_ACTIVE: Color = Color::Rgb( 52u8, 227u8, 28u8 );
+pub const CREATE_ISSUE_CMD_INACTIVE: Color = Color::Rgb( 39u8, 170u8, 21u8 );
@@ -4,6 +4,8 @@ use tui::{
 
 use crate::constants::colors;
 
+use crate::util::keybindings::display_key;
+
 #[derive(Debug, Clone)]
 pub enum DashboardCommand {
     RefreshPanel,
@@ -14,11 +16,38 @@ pub enum DashboardCommand {
     ModifyAssignee,
     ModifyProject,
     ModifyCycle,
+    ModifyLabels,
+    ModifyTeam,
+    ModifyPriority,
+    ModifyEstimate,
+    ModifyDueDate,
+    ModifyDescription,
+
+    CreateIssue,
+
+    SortPanel,
+    GroupPanel,
+
+    ZoomPanel,
+    JumpToPanel,
+
+    ExportPanel,
+
+    UndoModification,
+    RedoModification,
 }
 
 #[derive(Debug, Clone)]
 pub enum ViewListCommand {
     RemoveView,
+
+    NewDashboard,
+    RenameDashboard,
+    DuplicateDashboard,
+    PreviousDashboard,
+    NextDashboard,
+
+    SwitchProfile,
 }
 
 #[derive(Debug, Clone)]
@@ -37,7 +66,7 @@ pub struct CommandValue<'a> {
 
 impl<'a> CommandValue<'a> {
     pub fn gen_label(&self) -> String {
-        format!("'{}': {}", self.key_char, self.label)
+        format!("'{}': {}", display_key(self.key_char), self.label)
     }
 }
 
@@ -94,6 +123,90 @@ impl Default for CommandList<'_> {
                     active_color: colors::MODIFY_CYCLE_CMD_ACTIVE,
                     inactive_color: colors::MODIFY_CYCLE_CMD_INACTIVE,
                 },
+                CommandValue { key_char: 'l',
+                    cmd_type: Command::Dashboard(DashboardCommand::ModifyLabels),
+                    label: "Modify Labels",
+                    active_color: colors::MODIFY_LABELS_CMD_ACTIVE,
+                    inactive_color: colors::MODIFY_LABELS_CMD_INACTIVE,
+                },
+                CommandValue { key_char: 'm',
+                    cmd_type: Command::Dashboard(DashboardCommand::ModifyTeam),
+                    label: "Move to Team",
+                    active_color: colors::MODIFY_TEAM_CMD_ACTIVE,
+                    inactive_color: colors::MODIFY_TEAM_CMD_INACTIVE,
+                },
+                CommandValue { key_char: 'i',
+                    cmd_type: Command::Dashboard(DashboardCommand::ModifyPriority),
+                    label: "Modify Priority",
+                    active_color: colors::MODIFY_PRIORITY_CMD_ACTIVE,
+                    inactive_color: colors::MODIFY_PRIORITY_CMD_INACTIVE,
+                },
+                CommandValue { key_char: 's',
+                    cmd_type: Command::Dashboard(DashboardCommand::ModifyEstimate),
+                    label: "Modify Estimate",
+                    active_color: colors::MODIFY_ESTIMATE_CMD_ACTIVE,
+                    inactive_color: colors::MODIFY_ESTIMATE_CMD_INACTIVE,
+                },
+                CommandValue { key_char: 'u',
+                    cmd_type: Command::Dashboard(DashboardCommand::ModifyDueDate),
+                    label: "Modify Due Date",
+                    active_color: colors::MODIFY_DUE_DATE_CMD_ACTIVE,
+                    inactive_color: colors::MODIFY_DUE_DATE_CMD_INACTIVE,
+                },
+                CommandValue { key_char: 'o',
+                    cmd_type: Command::Dashboard(DashboardCommand::ModifyDescription),
+                    label: "Edit Description",
+                    active_color: colors::MODIFY_DESCRIPTION_CMD_ACTIVE,
+                    inactive_color: colors::MODIFY_DESCRIPTION_CMD_INACTIVE,
+                },
+                CommandValue { key_char: 'n',
+                    cmd_type: Command::Dashboard(DashboardCommand::CreateIssue),
+                    label: "Create Issue",
+                    active_color: colors::CREATE_ISSUE_CMD_ACTIVE,
+                    inactive_color: colors::CREATE_ISSUE_CMD_INACTIVE,
+                },
+                CommandValue { key_char: 'S',
+                    cmd_type: Command::Dashboard(DashboardCommand::SortPanel),
+                    label: "Sort Panel",
+                    active_color: colors::SORT_PANEL_CMD_ACTIVE,
+                    inactive_color: colors::SORT_PANEL_CMD_INACTIVE,
+                },
+                CommandValue { key_char: 'G',
+                    cmd_type: Command::Dashboard(DashboardCommand::GroupPanel),
+                    label: "Group Panel",
+                    active_color: colors::GROUP_PANEL_CMD_ACTIVE,
+                    inactive_color: colors::GROUP_PANEL_CMD_INACTIVE,
+                },
+                CommandValue { key_char: 'z',
+                    cmd_type: Command::Dashboard(DashboardCommand::ZoomPanel),
+                    label: "Zoom Panel",
+                    active_color: colors::ZOOM_PANEL_CMD_ACTIVE,
+                    inactive_color: colors::ZOOM_PANEL_CMD_INACTIVE,
+                },
+                CommandValue { key_char: 'J',
+                    cmd_type: Command::Dashboard(DashboardCommand::JumpToPanel),
+                    label: "Jump to Panel",
+                    active_color: colors::JUMP_TO_PANEL_CMD_ACTIVE,
+                    inactive_color: colors::JUMP_TO_PANEL_CMD_INACTIVE,
+                },
+                CommandValue { key_char: 'x',
+                    cmd_type: Command::Dashboard(DashboardCommand::ExportPanel),
+                    label: "Export Panel",
+                    active_color: colors::EXPORT_PANEL_CMD_ACTIVE,
+                    inactive_color: colors::EXPORT_PANEL_CMD_INACTIVE,
+                },
+                CommandValue { key_char: 'U',
+                    cmd_type: Command::Dashboard(DashboardCommand::UndoModification),
+                    label: "Undo",
+                    active_color: colors::UNDO_MODIFICATION_CMD_ACTIVE,
+                    inactive_color: colors::UNDO_MODIFICATION_CMD_INACTIVE,
+                },
+                CommandValue { key_char: 'Y',
+                    cmd_type: Command::Dashboard(DashboardCommand::RedoModification),
+                    label: "Redo",
+                    active_color: colors::REDO_MODIFICATION_CMD_ACTIVE,
+                    inactive_color: colors::REDO_MODIFICATION_CMD_INACTIVE,
+                },
 
 
             ],
@@ -104,6 +217,42 @@ impl Default for CommandList<'_> {
                     active_color: colors::DELETE_VIEW_CMD_ACTIVE,
                     inactive_color: colors::DELETE_VIEW_CMD_INACTIVE,
                 },
+                CommandValue { key_char: 'N',
+                    cmd_type: Command::ViewList(ViewListCommand::NewDashboard),
+                    label: "New Dashboard",
+                    active_color: colors::NEW_DASHBOARD_CMD_ACTIVE,
+                    inactive_color: colors::NEW_DASHBOARD_CMD_INACTIVE,
+                },
+                CommandValue { key_char: 'R',
+                    cmd_type: Command::ViewList(ViewListCommand::RenameDashboard),
+                    label: "Rename Dashboard",
+                    active_color: colors::RENAME_DASHBOARD_CMD_ACTIVE,
+                    inactive_color: colors::RENAME_DASHBOARD_CMD_INACTIVE,
+                },
+                CommandValue { key_char: 'D',
+                    cmd_type: Command::ViewList(ViewListCommand::DuplicateDashboard),
+                    label: "Duplicate Dashboard",
+                    active_color: colors::DUPLICATE_DASHBOARD_CMD_ACTIVE,
+                    inactive_color: colors::DUPLICATE_DASHBOARD_CMD_INACTIVE,
+                },
+                CommandValue { key_char: '[',
+                    cmd_type: Command::ViewList(ViewListCommand::PreviousDashboard),
+                    label: "Previous Dashboard",
+                    active_color: colors::SWITCH_DASHBOARD_CMD_ACTIVE,
+                    inactive_color: colors::SWITCH_DASHBOARD_CMD_INACTIVE,
+                },
+                CommandValue { key_char: ']',
+                    cmd_type: Command::ViewList(ViewListCommand::NextDashboard),
+                    label: "Next Dashboard",
+                    active_color: colors::SWITCH_DASHBOARD_CMD_ACTIVE,
+                    inactive_color: colors::SWITCH_DASHBOARD_CMD_INACTIVE,
+                },
+                CommandValue { key_char: 'W',
+                    cmd_type: Command::ViewList(ViewListCommand::SwitchProfile),
+                    label: "Switch Profile",
+                    active_color: colors::SWITCH_PROFILE_CMD_ACTIVE,
+                    inactive_color: colors::SWITCH_PROFILE_CMD_INACTIVE,
+                },
             ],
         }
     }
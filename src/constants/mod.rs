@@ -1,10 +1,14 @@
 pub mod table_columns;
+pub mod view_panel_columns;
 pub mod command_list;
 pub mod colors;
 
 pub const LINEAR_TOKEN_LEN: u16=48;
 pub const SCROLL_TICK_MAX: u64 = u64::MAX;
 
+// Indexed by Linear's issue priority values
+pub const ISSUE_PRIORITY_LABELS: [&str; 5] = ["No priority", "Urgent", "High", "Medium", "Low"];
+
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum IssueModificationOp {
     // implemented
@@ -14,7 +18,8 @@ pub enum IssueModificationOp {
     Project,
     Cycle,
     Team,
-
-    // unimplemented
     Labels,
+    Priority,
+    Estimate,
+    DueDate,
 }
\ No newline at end of file
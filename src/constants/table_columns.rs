@@ -16,16 +16,6 @@ pub struct TableColumn<'a> {
 
 lazy_static! {
 
-    pub static ref VIEW_PANEL_COLUMNS: Vec<TableColumn<'static>> = {
-        vec![
-            TableColumn { label: "#", null_fallback: "", min_width: 4, max_height: 1, priority: 1 },
-            TableColumn { label: "Title", null_fallback: "", min_width: 5, max_height: 2, priority: 3 },
-            TableColumn { label: "State", null_fallback: "", min_width: 5, max_height: 1, priority: 2 },
-            TableColumn { label: "Desc", null_fallback: "", min_width: 4, max_height: 3, priority: 3 },
-            TableColumn { label: "createdAt", null_fallback: "", min_width: 9, max_height: 1, priority: 2 },
-        ]
-    };
-
     pub static ref ISSUE_MODAL_HEADER_COLUMNS: Vec<TableColumn<'static>> = {
         vec![
             TableColumn { label: "Team", null_fallback: "", min_width: 8, max_height: 1, priority: 2 },
@@ -65,8 +55,43 @@ lazy_static! {
         ]
     };
 
+    pub static ref TEAM_SELECT_COLUMNS: Vec<TableColumn<'static>> = {
+        vec![
+            TableColumn { label: "Name", null_fallback: "", min_width: 4, max_height: 2, priority: 2 },
+            TableColumn { label: "Key", null_fallback: "", min_width: 3, max_height: 1, priority: 1 },
+            TableColumn { label: "Desc", null_fallback: "", min_width: 4, max_height: 3, priority: 3 },
+        ]
+    };
+
+    pub static ref LABEL_SELECT_COLUMNS: Vec<TableColumn<'static>> = {
+        vec![
+            TableColumn { label: "Name", null_fallback: "", min_width: 4, max_height: 2, priority: 2 },
+            TableColumn { label: "Desc", null_fallback: "", min_width: 4, max_height: 3, priority: 3 },
+        ]
+    };
+
+    pub static ref PRIORITY_SELECT_COLUMNS: Vec<TableColumn<'static>> = {
+        vec![
+            TableColumn { label: "Priority", null_fallback: "", min_width: 8, max_height: 1, priority: 1 },
+        ]
+    };
+
+    pub static ref ESTIMATE_SELECT_COLUMNS: Vec<TableColumn<'static>> = {
+        vec![
+            TableColumn { label: "Estimate", null_fallback: "", min_width: 8, max_height: 1, priority: 2 },
+            TableColumn { label: "Points", null_fallback: "", min_width: 6, max_height: 1, priority: 1 },
+        ]
+    };
+
     // Issue Modification Columns End
 
+    pub static ref ISSUE_CREATE_FORM_COLUMNS: Vec<TableColumn<'static>> = {
+        vec![
+            TableColumn { label: "Field", null_fallback: "", min_width: 11, max_height: 1, priority: 1 },
+            TableColumn { label: "Value", null_fallback: "None", min_width: 5, max_height: 2, priority: 4 },
+        ]
+    };
+
     pub static ref DASHBOARD_VIEW_CONFIG_COLUMNS: Vec<TableColumn<'static>> = {
         vec![
             TableColumn { label: "Name", null_fallback: "", min_width: 4, max_height: 2, priority: 2 },
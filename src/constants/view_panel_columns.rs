@@ -0,0 +1,210 @@
+use chrono::NaiveDate;
+
+use serde::{ Deserialize, Serialize };
+
+use tui::style::Color;
+
+use crate::constants::{
+    ISSUE_PRIORITY_LABELS,
+    table_columns::TableColumn,
+};
+
+use crate::linear::schema::Issue;
+
+use crate::util::{
+    date::{ due_status, DueStatus },
+    ui::style_color_from_hex_str,
+    theme::theme,
+};
+
+// Columns shown when a panel has no column config
+pub const DEFAULT_VIEW_PANEL_COLUMN_IDS: [&str; 6] = ["number", "title", "state", "due", "description", "created"];
+
+// Values shared by all cells of a render
+pub struct CellContext {
+    pub today: NaiveDate,
+    pub due_soon_day_threshold: u32,
+}
+
+// A column available to DashboardViewPanels, 'column' holds the default sizing
+#[derive(Debug)]
+pub struct ViewPanelColumn {
+    pub id: &'static str,
+    pub column: TableColumn<'static>,
+    pub cell_value: fn(&Issue) -> String,
+    pub cell_color: fn(&Issue, &CellContext) -> Option<Color>,
+}
+
+fn no_color(_issue: &Issue, _ctx: &CellContext) -> Option<Color> {
+    None
+}
+
+fn state_color(issue: &Issue, _ctx: &CellContext) -> Option<Color> {
+    style_color_from_hex_str(&issue.state.color)
+}
+
+// Highlight overdue and due soon issues
+fn due_color(issue: &Issue, ctx: &CellContext) -> Option<Color> {
+    match issue.due_date.as_deref().and_then(|due| due_status(due, ctx.today, ctx.due_soon_day_threshold)) {
+        Some(DueStatus::Overdue) => Some(theme().due_overdue),
+        Some(DueStatus::DueSoon) => Some(theme().due_soon),
+        _ => None,
+    }
+}
+
+// Color of the first label
+fn labels_color(issue: &Issue, _ctx: &CellContext) -> Option<Color> {
+    issue.labels.nodes.first().and_then(|label| style_color_from_hex_str(&label.color))
+}
+
+fn project_color(issue: &Issue, _ctx: &CellContext) -> Option<Color> {
+    issue.project.as_ref().and_then(|project| style_color_from_hex_str(&project.color))
+}
+
+fn priority_label(issue: &Issue) -> String {
+    ISSUE_PRIORITY_LABELS.get(issue.priority as usize).copied().unwrap_or("").to_string()
+}
+
+fn labels_value(issue: &Issue) -> String {
+    issue.labels.nodes.iter()
+        .map(|label| label.name.as_str())
+        .collect::<Vec<&str>>()
+        .join(", ")
+}
+
+lazy_static! {
+
+    pub static ref VIEW_PANEL_COLUMN_REGISTRY: Vec<ViewPanelColumn> = {
+        vec![
+            ViewPanelColumn { id: "number",
+                column: TableColumn { label: "#", null_fallback: "", min_width: 4, max_height: 1, priority: 1 },
+                cell_value: |issue| issue.number.to_string(),
+                cell_color: no_color,
+            },
+            ViewPanelColumn { id: "title",
+                column: TableColumn { label: "Title", null_fallback: "", min_width: 5, max_height: 2, priority: 3 },
+                cell_value: |issue| issue.title.clone(),
+                cell_color: no_color,
+            },
+            ViewPanelColumn { id: "state",
+                column: TableColumn { label: "State", null_fallback: "", min_width: 5, max_height: 1, priority: 2 },
+                cell_value: |issue| issue.state.name.clone(),
+                cell_color: state_color,
+            },
+            ViewPanelColumn { id: "due",
+                column: TableColumn { label: "Due", null_fallback: "", min_width: 10, max_height: 1, priority: 1 },
+                cell_value: |issue| issue.due_date.clone().unwrap_or_default(),
+                cell_color: due_color,
+            },
+            ViewPanelColumn { id: "description",
+                column: TableColumn { label: "Desc", null_fallback: "", min_width: 4, max_height: 3, priority: 3 },
+                cell_value: |issue| issue.description.clone().unwrap_or_default(),
+                cell_color: no_color,
+            },
+            ViewPanelColumn { id: "created",
+                column: TableColumn { label: "createdAt", null_fallback: "", min_width: 9, max_height: 1, priority: 2 },
+                cell_value: |issue| issue.created_at.clone(),
+                cell_color: no_color,
+            },
+            ViewPanelColumn { id: "assignee",
+                column: TableColumn { label: "Assignee", null_fallback: "", min_width: 8, max_height: 1, priority: 2 },
+                cell_value: |issue| issue.assignee.as_ref().map(|x| x.display_name.clone()).unwrap_or_default(),
+                cell_color: no_color,
+            },
+            ViewPanelColumn { id: "creator",
+                column: TableColumn { label: "Creator", null_fallback: "", min_width: 7, max_height: 1, priority: 1 },
+                cell_value: |issue| issue.creator.as_ref().map(|x| x.display_name.clone()).unwrap_or_default(),
+                cell_color: no_color,
+            },
+            ViewPanelColumn { id: "priority",
+                column: TableColumn { label: "Priority", null_fallback: "", min_width: 8, max_height: 1, priority: 1 },
+                cell_value: priority_label,
+                cell_color: no_color,
+            },
+            ViewPanelColumn { id: "estimate",
+                column: TableColumn { label: "Est", null_fallback: "", min_width: 3, max_height: 1, priority: 1 },
+                cell_value: |issue| issue.estimate.map(|x| x.to_string()).unwrap_or_default(),
+                cell_color: no_color,
+            },
+            ViewPanelColumn { id: "labels",
+                column: TableColumn { label: "Labels", null_fallback: "", min_width: 6, max_height: 2, priority: 2 },
+                cell_value: labels_value,
+                cell_color: labels_color,
+            },
+            ViewPanelColumn { id: "project",
+                column: TableColumn { label: "Project", null_fallback: "", min_width: 7, max_height: 1, priority: 2 },
+                cell_value: |issue| issue.project.as_ref().map(|x| x.name.clone()).unwrap_or_default(),
+                cell_color: project_color,
+            },
+            ViewPanelColumn { id: "cycle",
+                column: TableColumn { label: "Cycle", null_fallback: "", min_width: 5, max_height: 1, priority: 1 },
+                cell_value: |issue| issue.cycle.as_ref().and_then(|x| x.name.clone()).unwrap_or_default(),
+                cell_color: no_color,
+            },
+            ViewPanelColumn { id: "team",
+                column: TableColumn { label: "Team", null_fallback: "", min_width: 4, max_height: 1, priority: 1 },
+                cell_value: |issue| issue.team.name.clone(),
+                cell_color: no_color,
+            },
+        ]
+    };
+}
+
+pub fn lookup_view_panel_column(id: &str) -> Option<&'static ViewPanelColumn> {
+    VIEW_PANEL_COLUMN_REGISTRY.iter().find(|x| x.id == id)
+}
+
+// A panel's column as stored in config, unset sizing fields use the registry defaults
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PanelColumnConfig {
+    pub id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_width: Option<u16>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_height: Option<u16>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub priority: Option<u16>,
+}
+
+// Registry column with the sizing a panel renders it with
+#[derive(Debug, Clone, Copy)]
+pub struct PanelColumn {
+    pub def: &'static ViewPanelColumn,
+    pub column: TableColumn<'static>,
+}
+
+pub fn default_panel_columns() -> Vec<PanelColumn> {
+    DEFAULT_VIEW_PANEL_COLUMN_IDS.iter()
+        .filter_map(|id| lookup_view_panel_column(id))
+        .map(|def| PanelColumn { def, column: def.column })
+        .collect()
+}
+
+// Unknown column ids are logged and skipped, falls back to the default columns if none remain
+pub fn resolve_panel_columns(configs: &[PanelColumnConfig]) -> Vec<PanelColumn> {
+    let columns: Vec<PanelColumn> = configs.iter()
+        .filter_map(|config| {
+            match lookup_view_panel_column(&config.id) {
+                Some(def) => Some(PanelColumn {
+                    def,
+                    column: TableColumn {
+                        min_width: config.min_width.unwrap_or(def.column.min_width),
+                        max_height: config.max_height.unwrap_or(def.column.max_height).max(1),
+                        priority: config.priority.unwrap_or(def.column.priority),
+                        ..def.column
+                    },
+                }),
+                None => {
+                    error!("resolve_panel_columns - unknown column id: {:?}", config.id);
+                    None
+                }
+            }
+        })
+        .collect();
+
+    if columns.is_empty() { default_panel_columns() } else { columns }
+}
+
+pub fn panel_table_columns(columns: &[PanelColumn]) -> Vec<TableColumn<'static>> {
+    columns.iter().map(|x| x.column).collect()
+}
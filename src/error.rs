@@ -0,0 +1,88 @@
+// Errors of backend requests, reported to the notification center by background tasks
+
+use reqwest::StatusCode;
+use thiserror::Error;
+
+pub type ClientResult<T> = std::result::Result<T, ClientError>;
+
+#[derive(Debug, Clone, Error)]
+pub enum ClientError {
+    #[error("network error: {0}")]
+    Network(String),
+    #[error("GraphQL error: {0}")]
+    GraphQL(String),
+    #[error("authentication failed: {0}")]
+    Auth(String),
+    #[error("rate limited: {0}")]
+    RateLimit(String),
+    #[error("failed to decode response: {0}")]
+    Decode(String),
+    // Unsuccessful response that won't succeed on retry, e.g. HTTP 400 without a GraphQL error body
+    #[error("unexpected response: {0}")]
+    Response(String),
+    // Client construction, e.g. a missing or malformed token
+    #[error("invalid client config: {0}")]
+    Config(String),
+    #[error("{0}")]
+    Unsupported(String),
+}
+
+impl ClientError {
+
+    // Error of an unsuccessful response without a GraphQL error body
+    pub fn from_status(status: StatusCode, body: &str) -> ClientError {
+        let message = format!("HTTP {} {}", status.as_u16(), body.trim()).trim().to_string();
+        match status {
+            StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN => ClientError::Auth(message),
+            StatusCode::TOO_MANY_REQUESTS => ClientError::RateLimit(message),
+            _ if status.is_server_error() || status == StatusCode::REQUEST_TIMEOUT => ClientError::Network(message),
+            _ => ClientError::Response(message),
+        }
+    }
+
+    // Errors a retry of the same request may not run into
+    pub fn is_transient(&self) -> bool {
+        matches!(self, ClientError::Network(_) | ClientError::RateLimit(_))
+    }
+
+    // Accepts:
+    //     errors: message & error code of each GraphQL error,
+    //         codes are Linear's 'extensions.code' or GitHub's 'type'
+    pub fn from_graphql<'a>(errors: impl Iterator<Item = (&'a str, Option<&'a str>)>) -> ClientError {
+        let mut messages: Vec<&str> = Vec::new();
+        let mut codes: Vec<String> = Vec::new();
+
+        for (message, code) in errors {
+            messages.push(message);
+            if let Some(code) = code {
+                codes.push(code.to_uppercase());
+            }
+        }
+
+        let message = messages.join("; ");
+
+        if codes.iter().any(|code| code == "RATELIMITED" || code == "RATE_LIMITED") {
+            ClientError::RateLimit(message)
+        } else if codes.iter().any(|code| code == "AUTHENTICATION_ERROR" || code == "FORBIDDEN") {
+            ClientError::Auth(message)
+        } else {
+            ClientError::GraphQL(message)
+        }
+    }
+}
+
+impl From<reqwest::Error> for ClientError {
+    fn from(err: reqwest::Error) -> ClientError {
+        match err.status() {
+            _ if err.is_decode() => ClientError::Decode(err.to_string()),
+            Some(status) => ClientError::from_status(status, ""),
+            None => ClientError::Network(err.to_string()),
+        }
+    }
+}
+
+impl From<serde_json::Error> for ClientError {
+    fn from(err: serde_json::Error) -> ClientError {
+        ClientError::Decode(err.to_string())
+    }
+}
@@ -0,0 +1,749 @@
+// GitHub Issues & Projects backend, over the GitHub GraphQL API.
+// There's no GitHub schema under gql/, so requests are plain JSON and
+// responses are converted into the Linear schema types the app renders.
+// Views are issue searches or projects (ProjectV2), a project's single select field (e.g. "Status") is shown as the workflow state of its items.
+
+use std::env;
+
+use futures::future::BoxFuture;
+use reqwest::header;
+use serde::{ Deserialize, Serialize, de::DeserializeOwned };
+use serde_json::{ json, Value };
+
+use crate::app::Platform;
+use crate::backend::IssueBackend;
+use crate::config::ProfileSettings;
+use crate::constants::IssueModificationOp;
+use crate::error::{ ClientError, ClientResult };
+
+use crate::linear::schema::{
+    CustomView,
+    CustomViewResponseData,
+    IssuesResponseData,
+    TeamMembersResponseData,
+    StatesResponseData,
+    TeamLabelsResponseData,
+    IssueDescriptionResponseData,
+    IssueCommentsResponseData,
+    CommentCreateResponseData,
+    IssueUpdateInput,
+    IssueUpdateResponseData,
+    JSONObject,
+};
+
+use crate::util::GraphQLCursor;
+
+const DEFAULT_GITHUB_API_ENDPOINT: &str = "https://api.github.com/graphql";
+
+// GitHub allows up to 100
+const GITHUB_PAGE_SIZE: u32 = 50;
+
+pub const GITHUB_VIEW_ID_PREFIX: &str = "github:";
+pub const GITHUB_PROJECT_VIEW_ID_PREFIX: &str = "github-project:";
+const GITHUB_VIEW_COLOR: &str = "#6E5494";
+
+// Single select field of a project shown as the workflow state of its items, if not configured
+const DEFAULT_STATUS_FIELD: &str = "Status";
+
+// Project items' team & state ids are scoped to the project's status field, with parts separated by this
+const SCOPED_ID_SEPARATOR: char = '|';
+
+// Issues have two states, shown as Linear workflow states
+const OPEN_STATE: (&str, &str, &str, &str) = ("OPEN", "Open", "unstarted", "#3FB950");
+const CLOSED_STATE: (&str, &str, &str, &str) = ("CLOSED", "Closed", "completed", "#A371F7");
+
+// Project items without a value for the status field
+const NO_STATUS_NAME: &str = "No Status";
+const NO_STATUS_COLOR: &str = "#8B949E";
+
+const ISSUE_FIELDS: &str = "
+    id
+    number
+    title
+    body
+    createdAt
+    state
+    repository { id nameWithOwner }
+    author { login }
+    assignees(first: 1) { nodes { id login } }
+    labels(first: 20) { nodes { id name color description } }
+    milestone { dueOn }
+";
+
+const COMMENT_FIELDS: &str = "
+    id
+    body
+    createdAt
+    lastEditedAt
+    author { login }
+";
+
+// An issue search, listed as a view of a GitHub profile, e.g.
+//     name = "My issues", query = "repo:owner/name is:issue is:open assignee:@me"
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct GithubQuery {
+    pub name: String,
+    pub query: String,
+}
+
+impl GithubQuery {
+    pub fn to_custom_view(&self) -> CustomView {
+        let mut filter_data = JSONObject::new();
+        filter_data.insert(String::from("query"), Value::String(self.query.clone()));
+
+        let mut view = CustomView {
+            id: format!("{}{}", GITHUB_VIEW_ID_PREFIX, self.query),
+            name: self.name.clone(),
+            description: Some(self.query.clone()),
+            color: Some(GITHUB_VIEW_COLOR.to_string()),
+            filter_data,
+            ..CustomView::default()
+        };
+        view.organization.name = String::from("GitHub");
+
+        view
+    }
+}
+
+// A project (ProjectV2) of an organization or user, listed as a view of a GitHub profile, e.g.
+//     name = "Roadmap", owner = "octo-org", number = 5, status_field = "Status"
+// Issues of the project are shown, draft issues & pull requests are skipped
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct GithubProject {
+    pub name: String,
+    // Login of the organization or user
+    pub owner: String,
+    pub number: u32,
+    // Single select field shown & modified as the workflow state, "Status" if unset
+    pub status_field: Option<String>,
+}
+
+impl GithubProject {
+    pub fn status_field(&self) -> &str {
+        self.status_field.as_deref().unwrap_or(DEFAULT_STATUS_FIELD)
+    }
+
+    pub fn to_custom_view(&self) -> CustomView {
+        let mut filter_data = JSONObject::new();
+        filter_data.insert(String::from("owner"), Value::String(self.owner.clone()));
+        filter_data.insert(String::from("number"), Value::from(self.number));
+        filter_data.insert(String::from("statusField"), Value::String(self.status_field().to_string()));
+
+        let mut view = CustomView {
+            id: format!("{}{}/{}", GITHUB_PROJECT_VIEW_ID_PREFIX, self.owner, self.number),
+            name: self.name.clone(),
+            description: Some(format!("{}/projects/{}", self.owner, self.number)),
+            color: Some(GITHUB_VIEW_COLOR.to_string()),
+            filter_data,
+            ..CustomView::default()
+        };
+        view.organization.name = String::from("GitHub");
+
+        view
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct GithubConfig {
+    pub profile: String,
+    pub token: Option<String>,
+    pub queries: Vec<GithubQuery>,
+    pub projects: Vec<GithubProject>,
+
+    // Override to target a mock server or GitHub Enterprise
+    pub api_endpoint: String,
+    pub page_size: u32,
+}
+
+impl GithubConfig {
+    pub fn with_profile(profile: &ProfileSettings) -> GithubConfig {
+        GithubConfig {
+            profile: profile.name.clone(),
+            token: profile.token.clone(),
+            queries: profile.queries.clone(),
+            projects: profile.projects.clone(),
+            api_endpoint: match env::var("GITHUB_API_ENDPOINT") {
+                Ok(x) if !x.trim().is_empty() => x.trim().to_string(),
+                _ => DEFAULT_GITHUB_API_ENDPOINT.to_string(),
+            },
+            page_size: GITHUB_PAGE_SIZE,
+        }
+    }
+}
+
+// The single select field of a project, shown as the workflow state of its items
+#[derive(Debug, Clone, PartialEq)]
+struct StatusField {
+    project_id: String,
+    field_id: String,
+}
+
+// Teams of project items are "<repository id>|<project id>|<field id>", so the field's options are their workflow states
+fn scoped_team_id(repository_id: &str, status_field: &StatusField) -> String {
+    [repository_id, &status_field.project_id, &status_field.field_id].join(&SCOPED_ID_SEPARATOR.to_string())
+}
+
+// Returns:
+//     the repository id, & the status field if the team is of a project item
+fn split_team_id(team_id: &str) -> (&str, Option<StatusField>) {
+    let mut parts = team_id.split(SCOPED_ID_SEPARATOR);
+    let repository_id = parts.next().unwrap_or_default();
+    match (parts.next(), parts.next()) {
+        (Some(project_id), Some(field_id)) => (repository_id, Some(StatusField { project_id: project_id.to_string(), field_id: field_id.to_string() })),
+        _ => (repository_id, None),
+    }
+}
+
+// States of project items are "<project id>|<field id>|<option id>", the option id is empty for "No Status"
+fn scoped_state_id(status_field: &StatusField, option_id: &str) -> String {
+    [&status_field.project_id, &status_field.field_id, option_id].join(&SCOPED_ID_SEPARATOR.to_string())
+}
+
+// Returns:
+//     the status field & option id, None for the Open & Closed states of issues
+fn split_state_id(state_id: &str) -> Option<(StatusField, String)> {
+    let mut parts = state_id.split(SCOPED_ID_SEPARATOR);
+    match (parts.next(), parts.next(), parts.next()) {
+        (Some(project_id), Some(field_id), Some(option_id)) => Some((
+            StatusField { project_id: project_id.to_string(), field_id: field_id.to_string() },
+            option_id.to_string(),
+        )),
+        _ => None,
+    }
+}
+
+// Projects have no state types, these are guessed from the option names, e.g. GitHub's default "Todo", "In Progress" & "Done"
+fn status_type(name: &str) -> &'static str {
+    let name = name.to_lowercase();
+    if name.contains("done") || name.contains("complete") || name.contains("closed") {
+        "completed"
+    } else if name.contains("cancel") {
+        "canceled"
+    } else if name.contains("backlog") {
+        "backlog"
+    } else if name.contains("progress") || name.contains("review") {
+        "started"
+    } else {
+        "unstarted"
+    }
+}
+
+fn option_color(color: &Value) -> &'static str {
+    match color.as_str() {
+        Some("BLUE") => "#2F81F7",
+        Some("GREEN") => "#3FB950",
+        Some("ORANGE") => "#DB6D28",
+        Some("PINK") => "#DB61A2",
+        Some("PURPLE") => "#A371F7",
+        Some("RED") => "#F85149",
+        Some("YELLOW") => "#D29922",
+        _ => NO_STATUS_COLOR,
+    }
+}
+
+// An option of a project's status field, as a workflow state
+fn option_state_value(status_field: &StatusField, option: &Value) -> Value {
+    json!({
+        "id": scoped_state_id(status_field, option["id"].as_str().unwrap_or_default()),
+        "name": option["name"],
+        "type": status_type(option["name"].as_str().unwrap_or_default()),
+        "color": option_color(&option["color"]),
+        "description": option["description"].as_str().filter(|x| !x.is_empty()),
+    })
+}
+
+fn no_status_value(status_field: &StatusField) -> Value {
+    json!({ "id": scoped_state_id(status_field, ""), "name": NO_STATUS_NAME, "type": "backlog", "color": NO_STATUS_COLOR, "description": null })
+}
+
+fn state_value(state: (&str, &str, &str, &str)) -> Value {
+    let (id, name, type_, color) = state;
+    json!({ "id": id, "name": name, "type": type_, "color": color, "description": null })
+}
+
+fn user_value(author: &Value) -> Value {
+    match author["login"].as_str() {
+        Some(login) => json!({ "id": author["id"].as_str().unwrap_or(login), "name": login, "displayName": login }),
+        None => Value::Null,
+    }
+}
+
+fn label_value(label: &Value) -> Value {
+    json!({
+        "id": label["id"],
+        "name": label["name"],
+        "color": format!("#{}", label["color"].as_str().unwrap_or("AAAAAA")),
+        "description": label["description"],
+    })
+}
+
+fn page_info_value(page_info: &Value) -> Value {
+    json!({
+        "hasPreviousPage": false,
+        "hasNextPage": page_info["hasNextPage"].as_bool().unwrap_or(false),
+        "startCursor": null,
+        "endCursor": page_info["endCursor"],
+    })
+}
+
+// Convert a GitHub Issue node into the fields of IssuesQuery, the repository takes the place of the team
+fn issue_value(node: &Value) -> Value {
+    let state = if node["state"].as_str() == Some(CLOSED_STATE.0) { CLOSED_STATE } else { OPEN_STATE };
+
+    json!({
+        "id": node["id"],
+        "createdAt": node["createdAt"],
+        "number": node["number"],
+        "dueDate": node["milestone"]["dueOn"].as_str().map(|due_on| &due_on[..10.min(due_on.len())]),
+        "title": node["title"],
+        "description": node["body"],
+        "priority": 0.0,
+        "estimate": null,
+        "team": { "id": node["repository"]["id"], "name": node["repository"]["nameWithOwner"] },
+        "cycle": null,
+        "state": state_value(state),
+        "creator": user_value(&node["author"]),
+        "assignee": user_value(&node["assignees"]["nodes"][0]),
+        "labels": { "nodes": node["labels"]["nodes"].as_array().map(|labels| labels.iter().map(label_value).collect::<Vec<Value>>()).unwrap_or_default() },
+        "project": null,
+    })
+}
+
+fn comment_value(node: &Value) -> Value {
+    json!({
+        "id": node["id"],
+        "body": node["body"],
+        "createdAt": node["createdAt"],
+        "editedAt": node["lastEditedAt"],
+        "user": user_value(&node["author"]),
+    })
+}
+
+fn convert<T: DeserializeOwned>(value: Value) -> ClientResult<Option<T>> {
+    Ok(Some(serde_json::from_value(value)?))
+}
+
+pub struct GithubClient {
+    pub client: reqwest::Client,
+    pub config: GithubConfig,
+}
+
+impl GithubClient {
+
+    pub fn with_config(config: GithubConfig) -> ClientResult<GithubClient> {
+        let token = match &config.token {
+            Some(x) => x.to_string(),
+            None => return Err(ClientError::Config(String::from("GithubConfig missing token"))),
+        };
+
+        let mut headers = header::HeaderMap::new();
+
+        let mut auth_value = header::HeaderValue::from_str(&format!("bearer {}", token))
+            .map_err(|err| ClientError::Config(err.to_string()))?;
+        auth_value.set_sensitive(true);
+
+        headers.insert(header::AUTHORIZATION, auth_value);
+        headers.insert(header::CONTENT_TYPE, header::HeaderValue::from_static("application/json"));
+
+        Ok(GithubClient {
+            client: reqwest::Client::builder()
+                .user_agent("tissues")
+                .default_headers(headers)
+                .build()?,
+            config,
+        })
+    }
+
+    // Returns the "data" of a response, unsuccessful responses & GraphQL errors are returned as the matching ClientError
+    async fn graphql(&self, query: &str, variables: Value) -> ClientResult<Value> {
+        let resp = self.client
+            .post(&self.config.api_endpoint)
+            .json(&json!({ "query": query, "variables": variables }))
+            .send()
+            .await?;
+
+        let status = resp.status();
+        let text = resp.text().await?;
+        if !status.is_success() {
+            return Err(ClientError::from_status(status, &text));
+        }
+
+        let resp: Value = serde_json::from_str(&text)?;
+
+        if let Some(errors) = resp["errors"].as_array() {
+            return Err(ClientError::from_graphql(errors.iter().map(|error| (
+                error["message"].as_str().unwrap_or("unknown error"),
+                error["type"].as_str(),
+            ))));
+        }
+        Ok(resp["data"].clone())
+    }
+
+    fn after_cursor(cursor_opt: Option<GraphQLCursor>) -> Option<String> {
+        cursor_opt.and_then(|cursor| cursor.end_cursor)
+    }
+
+    // Queries, then projects of the profile, not paginated
+    pub async fn custom_views(&self, _cursor_opt: Option<GraphQLCursor>) -> ClientResult<Option<CustomViewResponseData>> {
+        let nodes: Vec<CustomView> = self.config.queries.iter().map(|query| query.to_custom_view())
+            .chain(self.config.projects.iter().map(|project| project.to_custom_view()))
+            .collect();
+
+        let mut views = CustomViewResponseData::default();
+        views.custom_views.nodes = nodes;
+        Ok(Some(views))
+    }
+
+    // Fetch a page of a query's issues or a project's items, pull requests are skipped
+    pub async fn view_issues(&self, view: &CustomView, cursor_opt: Option<GraphQLCursor>) -> ClientResult<Option<IssuesResponseData>> {
+        if view.filter_data.contains_key("owner") {
+            return self.project_items(view, cursor_opt).await;
+        }
+
+        let search = match view.filter_data.get("query").and_then(|x| x.as_str()) {
+            Some(search) => search,
+            None => return Err(ClientError::Unsupported(format!("view '{}' has no GitHub query", view.name))),
+        };
+
+        let query = format!("
+            query SearchIssues($query: String!, $firstNum: Int!, $afterCursor: String) {{
+                search(type: ISSUE, query: $query, first: $firstNum, after: $afterCursor) {{
+                    nodes {{ ... on Issue {{ {} }} }}
+                    pageInfo {{ hasNextPage endCursor }}
+                }}
+            }}", ISSUE_FIELDS);
+        let variables = json!({
+            "query": search,
+            "firstNum": self.config.page_size,
+            "afterCursor": GithubClient::after_cursor(cursor_opt),
+        });
+
+        debug!("GithubClient::view_issues() - Variables: {:?}", variables);
+
+        let data = self.graphql(&query, variables).await?;
+        let nodes: Vec<Value> = data["search"]["nodes"].as_array().cloned().unwrap_or_default()
+            .iter()
+            .filter(|node| node.get("id").is_some())
+            .map(issue_value)
+            .collect();
+
+        convert(json!({ "issues": { "nodes": nodes, "pageInfo": page_info_value(&data["search"]["pageInfo"]) } }))
+    }
+
+    // Fetch a page of a project's issues, with the value of its status field as their workflow state
+    async fn project_items(&self, view: &CustomView, cursor_opt: Option<GraphQLCursor>) -> ClientResult<Option<IssuesResponseData>> {
+        let owner = view.filter_data.get("owner").and_then(|x| x.as_str()).unwrap_or_default();
+        let number = match view.filter_data.get("number").and_then(|x| x.as_u64()) {
+            Some(number) => number,
+            None => return Err(ClientError::Unsupported(format!("view '{}' has no GitHub project number", view.name))),
+        };
+        let field_name = view.filter_data.get("statusField").and_then(|x| x.as_str()).unwrap_or(DEFAULT_STATUS_FIELD);
+
+        let query = format!("
+            query ProjectItems($owner: String!, $number: Int!, $field: String!, $firstNum: Int!, $afterCursor: String) {{
+                repositoryOwner(login: $owner) {{ ... on ProjectV2Owner {{
+                    projectV2(number: $number) {{
+                        id
+                        field(name: $field) {{ ... on ProjectV2SingleSelectField {{ id options {{ id name color description }} }} }}
+                        items(first: $firstNum, after: $afterCursor) {{
+                            nodes {{
+                                fieldValueByName(name: $field) {{ ... on ProjectV2ItemFieldSingleSelectValue {{ optionId }} }}
+                                content {{ ... on Issue {{ {} }} }}
+                            }}
+                            pageInfo {{ hasNextPage endCursor }}
+                        }}
+                    }}
+                }} }}
+            }}", ISSUE_FIELDS);
+        let variables = json!({
+            "owner": owner,
+            "number": number,
+            "field": field_name,
+            "firstNum": self.config.page_size,
+            "afterCursor": GithubClient::after_cursor(cursor_opt),
+        });
+
+        debug!("GithubClient::project_items() - Variables: {:?}", variables);
+
+        let data = self.graphql(&query, variables).await?;
+        let project = &data["repositoryOwner"]["projectV2"];
+        let project_id = match project["id"].as_str() {
+            Some(x) => x,
+            None => return Err(ClientError::Response(format!("GitHub project {}/{} not found", owner, number))),
+        };
+        let status_field = match project["field"]["id"].as_str() {
+            Some(field_id) => StatusField { project_id: project_id.to_string(), field_id: field_id.to_string() },
+            None => return Err(ClientError::Unsupported(format!("GitHub project {}/{} has no single select field '{}'", owner, number, field_name))),
+        };
+        let options: Vec<Value> = project["field"]["options"].as_array().cloned().unwrap_or_default();
+
+        let nodes: Vec<Value> = project["items"]["nodes"].as_array().cloned().unwrap_or_default()
+            .iter()
+            .filter(|item| item["content"].get("id").is_some())
+            .map(|item| {
+                let mut issue = issue_value(&item["content"]);
+                issue["team"]["id"] = json!(scoped_team_id(issue["team"]["id"].as_str().unwrap_or_default(), &status_field));
+                issue["state"] = match options.iter().find(|option| option["id"] == item["fieldValueByName"]["optionId"]) {
+                    Some(option) => option_state_value(&status_field, option),
+                    None => no_status_value(&status_field),
+                };
+                issue
+            })
+            .collect();
+
+        convert(json!({ "issues": { "nodes": nodes, "pageInfo": page_info_value(&project["items"]["pageInfo"]) } }))
+    }
+
+    // Users assignable in the repository
+    pub async fn team_members(&self, team_id: &str, cursor_opt: Option<GraphQLCursor>) -> ClientResult<Option<TeamMembersResponseData>> {
+        let query = "
+            query RepositoryAssignees($ref: ID!, $firstNum: Int!, $afterCursor: String) {
+                node(id: $ref) { ... on Repository {
+                    assignableUsers(first: $firstNum, after: $afterCursor) {
+                        nodes { id login name }
+                        pageInfo { hasNextPage endCursor }
+                    }
+                } }
+            }";
+        let variables = json!({ "ref": split_team_id(team_id).0, "firstNum": self.config.page_size, "afterCursor": GithubClient::after_cursor(cursor_opt) });
+
+        let data = self.graphql(query, variables).await?;
+        let users = &data["node"]["assignableUsers"];
+        let nodes: Vec<Value> = users["nodes"].as_array().cloned().unwrap_or_default()
+            .iter()
+            .map(|user| json!({ "id": user["id"], "name": user["name"].as_str().or_else(|| user["login"].as_str()), "displayName": user["login"] }))
+            .collect();
+
+        convert(json!({ "team": { "members": { "nodes": nodes, "pageInfo": page_info_value(&users["pageInfo"]) } } }))
+    }
+
+    // Open & Closed, or "No Status" & the options of the status field for project items
+    pub async fn team_states(&self, team_id: &str, _cursor_opt: Option<GraphQLCursor>) -> ClientResult<Option<StatesResponseData>> {
+        let status_field = match split_team_id(team_id).1 {
+            Some(x) => x,
+            None => return convert(json!({
+                "workflowStates": {
+                    "nodes": [state_value(OPEN_STATE), state_value(CLOSED_STATE)],
+                    "pageInfo": { "hasNextPage": false, "endCursor": null },
+                }
+            })),
+        };
+
+        let query = "
+            query StatusFieldOptions($ref: ID!) {
+                node(id: $ref) { ... on ProjectV2SingleSelectField { options { id name color description } } }
+            }";
+
+        let data = self.graphql(query, json!({ "ref": status_field.field_id })).await?;
+        let nodes: Vec<Value> = std::iter::once(no_status_value(&status_field))
+            .chain(data["node"]["options"].as_array().cloned().unwrap_or_default().iter().map(|option| option_state_value(&status_field, option)))
+            .collect();
+
+        convert(json!({ "workflowStates": { "nodes": nodes, "pageInfo": { "hasNextPage": false, "endCursor": null } } }))
+    }
+
+    // Labels of the repository
+    pub async fn team_labels(&self, team_id: &str, cursor_opt: Option<GraphQLCursor>) -> ClientResult<Option<TeamLabelsResponseData>> {
+        let query = "
+            query RepositoryLabels($ref: ID!, $firstNum: Int!, $afterCursor: String) {
+                node(id: $ref) { ... on Repository {
+                    labels(first: $firstNum, after: $afterCursor) {
+                        nodes { id name color description }
+                        pageInfo { hasNextPage endCursor }
+                    }
+                } }
+            }";
+        let variables = json!({ "ref": split_team_id(team_id).0, "firstNum": self.config.page_size, "afterCursor": GithubClient::after_cursor(cursor_opt) });
+
+        let data = self.graphql(query, variables).await?;
+        let labels = &data["node"]["labels"];
+        let nodes: Vec<Value> = labels["nodes"].as_array().cloned().unwrap_or_default().iter().map(label_value).collect();
+
+        convert(json!({ "issueLabels": { "nodes": nodes, "pageInfo": page_info_value(&labels["pageInfo"]) } }))
+    }
+
+    pub async fn issue_description(&self, issue_id: &str) -> ClientResult<Option<IssueDescriptionResponseData>> {
+        let query = "
+            query IssueBody($ref: ID!) {
+                node(id: $ref) { ... on Issue { id body updatedAt } }
+            }";
+
+        let data = self.graphql(query, json!({ "ref": issue_id })).await?;
+        let issue = &data["node"];
+
+        convert(json!({ "issue": { "id": issue["id"], "description": issue["body"], "updatedAt": issue["updatedAt"] } }))
+    }
+
+    pub async fn issue_comments(&self, issue_id: &str, cursor_opt: Option<GraphQLCursor>) -> ClientResult<Option<IssueCommentsResponseData>> {
+        let query = format!("
+            query IssueComments($ref: ID!, $firstNum: Int!, $afterCursor: String) {{
+                node(id: $ref) {{ ... on Issue {{
+                    id
+                    comments(first: $firstNum, after: $afterCursor) {{
+                        nodes {{ {} }}
+                        pageInfo {{ hasNextPage endCursor }}
+                    }}
+                }} }}
+            }}", COMMENT_FIELDS);
+        let variables = json!({ "ref": issue_id, "firstNum": self.config.page_size, "afterCursor": GithubClient::after_cursor(cursor_opt) });
+
+        let data = self.graphql(&query, variables).await?;
+        let comments = &data["node"]["comments"];
+        let nodes: Vec<Value> = comments["nodes"].as_array().cloned().unwrap_or_default().iter().map(comment_value).collect();
+
+        convert(json!({
+            "issue": {
+                "id": data["node"]["id"],
+                "comments": { "nodes": nodes, "pageInfo": { "hasNextPage": comments["pageInfo"]["hasNextPage"].as_bool().unwrap_or(false), "endCursor": comments["pageInfo"]["endCursor"] } },
+            }
+        }))
+    }
+
+    pub async fn create_comment(&self, issue_id: &str, body: &str) -> ClientResult<Option<CommentCreateResponseData>> {
+        let query = format!("
+            mutation AddComment($subjectId: ID!, $body: String!) {{
+                addComment(input: {{ subjectId: $subjectId, body: $body }}) {{
+                    commentEdge {{ node {{ {} }} }}
+                }}
+            }}", COMMENT_FIELDS);
+
+        let data = self.graphql(&query, json!({ "subjectId": issue_id, "body": body })).await?;
+
+        convert(json!({ "commentCreate": { "success": true, "comment": comment_value(&data["addComment"]["commentEdge"]["node"]) } }))
+    }
+
+    // Supports title, description, state, assignee & labels, the state of project items is their status field
+    pub async fn update_issue(&self, issue_id: &str, update: IssueUpdateInput) -> ClientResult<Option<IssueUpdateResponseData>> {
+        self.update_issue_clearing(issue_id, update, &[]).await
+    }
+
+    // Only the assignee can be cleared
+    pub async fn update_issue_clearing(&self, issue_id: &str, update: IssueUpdateInput, cleared: &[IssueModificationOp]) -> ClientResult<Option<IssueUpdateResponseData>> {
+        if update.priority.is_some() || update.estimate.is_some() || update.team_id.is_some() ||
+            update.cycle_id.is_some() || update.project_id.is_some() || update.due_date.is_some() {
+            return Err(ClientError::Unsupported(String::from("GitHub issues only support title, description, state, assignee & label updates")));
+        }
+
+        let item_status = update.state_id.as_deref().and_then(split_state_id);
+
+        let mut input = json!({ "id": issue_id });
+        if let Some(title) = update.title {
+            input["title"] = json!(title);
+        }
+        if let Some(description) = update.description {
+            input["body"] = json!(description);
+        }
+        if let Some(state_id) = update.state_id.filter(|_| item_status.is_none()) {
+            input["state"] = json!(state_id);
+        }
+        if let Some(assignee_id) = update.assignee_id {
+            input["assigneeIds"] = json!([assignee_id]);
+        }
+        if let Some(label_ids) = update.label_ids {
+            input["labelIds"] = json!(label_ids);
+        }
+        for op in cleared.iter() {
+            match op {
+                IssueModificationOp::Assignee => input["assigneeIds"] = json!([]),
+                _ => return Err(ClientError::Unsupported(format!("{:?} can't be cleared on GitHub issues", op))),
+            }
+        }
+
+        // A project item's status is a field of the item, not the issue
+        if input.as_object().map_or(0, |fields| fields.len()) > 1 {
+            let query = "
+                mutation UpdateIssue($input: UpdateIssueInput!) {
+                    updateIssue(input: $input) { issue { id } }
+                }";
+
+            debug!("GithubClient::update_issue() - input: {:?}", input);
+
+            self.graphql(query, json!({ "input": input })).await?;
+        }
+        if let Some((status_field, option_id)) = item_status {
+            self.update_item_status(issue_id, &status_field, &option_id).await?;
+        }
+
+        // Only team changes read the updated issue
+        convert(json!({ "issueUpdate": { "success": true, "issue": null } }))
+    }
+}
+
+impl GithubClient {
+
+    // Set the status field of the issue's item in the project, an empty 'option_id' clears it
+    async fn update_item_status(&self, issue_id: &str, status_field: &StatusField, option_id: &str) -> ClientResult<()> {
+        let query = "
+            query IssueProjectItems($ref: ID!) {
+                node(id: $ref) { ... on Issue { projectItems(first: 100) { nodes { id project { id } } } } }
+            }";
+
+        let data = self.graphql(query, json!({ "ref": issue_id })).await?;
+        let item_id = data["node"]["projectItems"]["nodes"].as_array().cloned().unwrap_or_default()
+            .iter()
+            .find(|item| item["project"]["id"].as_str() == Some(status_field.project_id.as_str()))
+            .and_then(|item| item["id"].as_str().map(|x| x.to_string()))
+            .ok_or_else(|| ClientError::Response(format!("issue {} is not an item of the project", issue_id)))?;
+
+        let mut input = json!({ "projectId": status_field.project_id, "itemId": item_id, "fieldId": status_field.field_id });
+        let query = if option_id.is_empty() {
+            "
+            mutation ClearItemStatus($input: ClearProjectV2ItemFieldValueInput!) {
+                clearProjectV2ItemFieldValue(input: $input) { projectV2Item { id } }
+            }"
+        } else {
+            input["value"] = json!({ "singleSelectOptionId": option_id });
+            "
+            mutation UpdateItemStatus($input: UpdateProjectV2ItemFieldValueInput!) {
+                updateProjectV2ItemFieldValue(input: $input) { projectV2Item { id } }
+            }"
+        };
+
+        debug!("GithubClient::update_item_status() - input: {:?}", input);
+
+        self.graphql(query, json!({ "input": input })).await?;
+        Ok(())
+    }
+}
+
+impl IssueBackend for GithubClient {
+    fn platform(&self) -> Platform {
+        Platform::Github
+    }
+
+    fn custom_views(&self, cursor_opt: Option<GraphQLCursor>) -> BoxFuture<'_, ClientResult<Option<CustomViewResponseData>>> {
+        Box::pin(GithubClient::custom_views(self, cursor_opt))
+    }
+    fn view_issues<'a>(&'a self, view: &'a CustomView, cursor_opt: Option<GraphQLCursor>) -> BoxFuture<'a, ClientResult<Option<IssuesResponseData>>> {
+        Box::pin(GithubClient::view_issues(self, view, cursor_opt))
+    }
+
+    fn team_members<'a>(&'a self, team_id: &'a str, cursor: Option<GraphQLCursor>) -> BoxFuture<'a, ClientResult<Option<TeamMembersResponseData>>> {
+        Box::pin(GithubClient::team_members(self, team_id, cursor))
+    }
+    fn team_states<'a>(&'a self, team_id: &'a str, cursor: Option<GraphQLCursor>) -> BoxFuture<'a, ClientResult<Option<StatesResponseData>>> {
+        Box::pin(GithubClient::team_states(self, team_id, cursor))
+    }
+    fn team_labels<'a>(&'a self, team_id: &'a str, cursor: Option<GraphQLCursor>) -> BoxFuture<'a, ClientResult<Option<TeamLabelsResponseData>>> {
+        Box::pin(GithubClient::team_labels(self, team_id, cursor))
+    }
+
+    fn issue_description<'a>(&'a self, issue_id: &'a str) -> BoxFuture<'a, ClientResult<Option<IssueDescriptionResponseData>>> {
+        Box::pin(GithubClient::issue_description(self, issue_id))
+    }
+    fn issue_comments<'a>(&'a self, issue_id: &'a str, cursor: Option<GraphQLCursor>) -> BoxFuture<'a, ClientResult<Option<IssueCommentsResponseData>>> {
+        Box::pin(GithubClient::issue_comments(self, issue_id, cursor))
+    }
+    fn create_comment<'a>(&'a self, issue_id: &'a str, body: &'a str) -> BoxFuture<'a, ClientResult<Option<CommentCreateResponseData>>> {
+        Box::pin(GithubClient::create_comment(self, issue_id, body))
+    }
+
+    fn update_issue<'a>(&'a self, issue_id: &'a str, update: IssueUpdateInput) -> BoxFuture<'a, ClientResult<Option<IssueUpdateResponseData>>> {
+        Box::pin(GithubClient::update_issue(self, issue_id, update))
+    }
+    fn update_issue_clearing<'a>(&'a self, issue_id: &'a str, update: IssueUpdateInput, cleared: &'a [IssueModificationOp]) -> BoxFuture<'a, ClientResult<Option<IssueUpdateResponseData>>> {
+        Box::pin(GithubClient::update_issue_clearing(self, issue_id, update, cleared))
+    }
+}
@@ -0,0 +1,2 @@
+pub mod client;
+pub use client::{ GithubClient, GithubConfig, GithubProject, GithubQuery };
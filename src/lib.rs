@@ -8,9 +8,16 @@ extern crate simplelog;
 
 pub mod app;
 pub mod linear;
+pub mod github;
+pub mod backend;
 pub mod ui;
 pub mod constants;
 pub mod util;
 pub mod command;
 
-pub mod components;
\ No newline at end of file
+pub mod components;
+pub mod config;
+pub mod error;
+pub mod cli;
+pub mod subcommand;
+pub mod webhook;
\ No newline at end of file
@@ -0,0 +1,127 @@
+// Local cache of Linear query results, allows the dashboard to render instantly on startup
+// and remain browsable when requests to the Linear API fail
+
+use std::sync::{
+    Arc,
+    atomic::{ AtomicBool, Ordering },
+};
+
+use anyhow::Result;
+use serde::{ Serialize, de::DeserializeOwned };
+
+use super::config::{ LinearConfig, CachedDataFile };
+
+use crate::util::GraphQLCursor;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CacheTree {
+    Issues,
+    CustomViews,
+    WorkflowStates,
+    TeamMembers,
+    Projects,
+    Cycles,
+    Teams,
+    Labels,
+    TeamEstimation,
+    Comments,
+}
+
+impl CacheTree {
+    fn name(&self) -> &'static str {
+        match self {
+            CacheTree::Issues => "issues",
+            CacheTree::CustomViews => "custom_views",
+            CacheTree::WorkflowStates => "workflow_states",
+            CacheTree::TeamMembers => "team_members",
+            CacheTree::Projects => "projects",
+            CacheTree::Cycles => "cycles",
+            CacheTree::Teams => "teams",
+            CacheTree::Labels => "labels",
+            CacheTree::TeamEstimation => "team_estimation",
+            CacheTree::Comments => "comments",
+        }
+    }
+}
+
+// sled::Db is a handle to a shared instance, cloning is cheap
+#[derive(Clone)]
+pub struct LinearCache {
+    db: sled::Db,
+
+    // true if the most recent request was served from the cache due to a failed request
+    pub offline: Arc<AtomicBool>,
+}
+
+impl LinearCache {
+
+    pub fn open() -> Result<LinearCache> {
+        let cache_path = LinearConfig::get_or_build_paths(CachedDataFile::LocalCache);
+        let db = sled::open(&cache_path)?;
+
+        Ok(LinearCache {
+            db,
+            offline: Arc::new(AtomicBool::new(false)),
+        })
+    }
+
+    // Accepts:
+    //     scope: id of the object the page belongs to (e.g. view id, team id), empty if not applicable
+    //     cursor_opt: cursor used to request the page
+    // Returns:
+    //     key of the form "{scope}/{end_cursor}", where the first page of a scope has an empty end_cursor
+    pub fn page_key(scope: &str, cursor_opt: &Option<GraphQLCursor>) -> String {
+        let after_cursor: &str = match cursor_opt {
+            Some(cursor) => cursor.end_cursor.as_deref().unwrap_or(""),
+            None => "",
+        };
+        format!("{}/{}", scope, after_cursor)
+    }
+
+    pub fn get<T: DeserializeOwned>(&self, tree: CacheTree, key: &str) -> Option<T> {
+        let fetched = self.db.open_tree(tree.name())
+            .and_then(|tree_handle| tree_handle.get(key.as_bytes()));
+
+        match fetched {
+            Ok(Some(bytes)) => {
+                match serde_json::from_slice(&bytes) {
+                    Ok(value) => Some(value),
+                    Err(err) => {
+                        warn!("LinearCache::get - failed to deserialize {:?} '{}': {:?}", tree, key, err);
+                        None
+                    }
+                }
+            },
+            Ok(None) => None,
+            Err(err) => {
+                error!("LinearCache::get - {:?} '{}': {:?}", tree, key, err);
+                None
+            }
+        }
+    }
+
+    pub fn insert<T: Serialize>(&self, tree: CacheTree, key: &str, value: &T) {
+        let serialized = match serde_json::to_vec(value) {
+            Ok(x) => x,
+            Err(err) => {
+                error!("LinearCache::insert - failed to serialize {:?} '{}': {:?}", tree, key, err);
+                return;
+            }
+        };
+
+        let res = self.db.open_tree(tree.name())
+            .and_then(|tree_handle| tree_handle.insert(key.as_bytes(), serialized));
+
+        if let Err(err) = res {
+            error!("LinearCache::insert - {:?} '{}': {:?}", tree, key, err);
+        }
+    }
+
+    pub fn is_offline(&self) -> bool {
+        self.offline.load(Ordering::Relaxed)
+    }
+
+    pub fn set_offline(&self, offline: bool) {
+        self.offline.store(offline, Ordering::Relaxed);
+    }
+}
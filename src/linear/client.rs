@@ -1,16 +1,20 @@
-// TODO: LinearClient needs better error propagation on requests rather than burying them
-
 use super::config::{ LinearConfig };
+use super::cache::{ LinearCache, CacheTree };
+use super::scheduler::RequestScheduler;
+
+use std::{boxed::Box, future::Future};
 
-use anyhow::{anyhow,Result};
-use std::{boxed::Box};
+use serde::{ Serialize, de::DeserializeOwned };
 
 use crate::{
+    app::Platform,
+    error::{ ClientError, ClientResult },
     util::{ GraphQLCursor },
+    constants::{ ISSUE_PRIORITY_LABELS, IssueModificationOp },
 };
 
 use reqwest::header;
-use graphql_client::reqwest::post_graphql;
+use graphql_client::GraphQLQuery;
 
 use super::schema::{
 
@@ -33,18 +37,62 @@ use super::schema::{
     states_query,
     StatesQuery, StatesVariables, StatesResponseData, State,
 
+    // Teams
+    TeamsQuery, TeamsVariables, TeamsResponseData, Team,
+
+    // Team Estimate Scale
+    TeamEstimationQuery, TeamEstimationVariables, TeamEstimationResponseData,
+
+    // Labels By Team
+    TeamLabelsQuery, TeamLabelsVariables, TeamLabelsResponseData, LabelFilter, Label,
+
+    // Issue Search
+    IssueSearchQuery, IssueSearchVariables, IssueSearchResponseData,
+
+    // Issue Description
+    IssueDescriptionQuery, IssueDescriptionVariables, IssueDescriptionResponseData,
+
+    // Issue Comments
+    IssueCommentsQuery, IssueCommentsVariables, IssueCommentsResponseData,
+    CommentCreateMut, CommentCreateVariables, CommentCreateResponseData, CommentCreateInput,
+
+    // Create Issue
+    IssueCreateMut, IssueCreateInput, IssueCreateVariables, IssueCreateResponseData,
+
     // Update Issue
     IssueUpdateMut, IssueUpdateInput, IssueUpdateVariables, IssueUpdateResponseData,
 
     // Issues
     IssuesQuery, IssuesVariables, IssueFilter, IssuesResponseData,
+
+    CustomView,
+    Issue,
 };
 
 pub enum IssueFieldResponse {
-    Cycles(Result<Option<CyclesResponseData>>),
-    Projects(Result<Option<ProjectsResponseData>>),
-    TeamMembers(Result<Option<TeamMembersResponseData>>),
-    States(Result<Option<StatesResponseData>>),
+    Cycles(ClientResult<Option<CyclesResponseData>>),
+    Projects(ClientResult<Option<ProjectsResponseData>>),
+    TeamMembers(ClientResult<Option<TeamMembersResponseData>>),
+    States(ClientResult<Option<StatesResponseData>>),
+    Teams(ClientResult<Option<TeamsResponseData>>),
+    Labels(ClientResult<Option<TeamLabelsResponseData>>),
+    Estimation(ClientResult<Option<TeamEstimationResponseData>>),
+}
+
+impl IssueFieldResponse {
+    // The request's error, if it failed
+    pub fn error(&self) -> Option<&ClientError> {
+        match self {
+            IssueFieldResponse::Cycles(Err(err))
+            | IssueFieldResponse::Projects(Err(err))
+            | IssueFieldResponse::TeamMembers(Err(err))
+            | IssueFieldResponse::States(Err(err))
+            | IssueFieldResponse::Teams(Err(err))
+            | IssueFieldResponse::Labels(Err(err))
+            | IssueFieldResponse::Estimation(Err(err)) => Some(err),
+            _ => None,
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -53,26 +101,90 @@ pub enum IssueFieldObject {
     Project(Project),
     TeamMember(TeamMember),
     State(State),
+    Team(Team),
+    Label(Label),
+    Priority(ScaleValue),
+    Estimate(ScaleValue),
+}
+
+// A value of a fixed-scale Issue field (priority, estimate) & its display label
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScaleValue {
+    pub value: i64,
+    pub label: String,
+}
+
+impl ScaleValue {
+
+    // Linear's priorities, in order of ISSUE_PRIORITY_LABELS
+    pub fn priorities() -> Vec<ScaleValue> {
+        ISSUE_PRIORITY_LABELS.iter()
+            .enumerate()
+            .map(|(value, label)| ScaleValue { value: value as i64, label: label.to_string() })
+            .collect()
+    }
+
+    // Accepts:
+    //     estimation_type: Team.issueEstimationType ("notUsed", "exponential", "fibonacci", "linear", "tShirt")
+    //     allow_zero: Team.issueEstimationAllowZero
+    //     extended: Team.issueEstimationExtended
+    // Returns:
+    //     the estimates selectable for the team's issues, empty if estimates are not used
+    pub fn estimates(estimation_type: &str, allow_zero: bool, extended: bool) -> Vec<ScaleValue> {
+        let values: &[i64] = match estimation_type {
+            "exponential" => &[1, 2, 4, 8, 16, 32, 64],
+            "fibonacci" | "tShirt" => &[1, 2, 3, 5, 8, 13, 21],
+            "linear" => &[1, 2, 3, 4, 5, 6, 7],
+            _ => return Vec::new(),
+        };
+
+        let t_shirt_label = |value: i64| match value {
+            1 => "XS",
+            2 => "S",
+            3 => "M",
+            5 => "L",
+            8 => "XL",
+            13 => "XXL",
+            21 => "XXXL",
+            _ => "",
+        };
+
+        let mut estimates: Vec<ScaleValue> = if allow_zero { vec![ScaleValue { value: 0, label: "0".to_string() }] } else { Vec::new() };
+
+        estimates.extend(values.iter()
+            // the last two values are only available on extended scales
+            .take(if extended { values.len() } else { values.len() - 2 })
+            .map(|value| ScaleValue {
+                value: *value,
+                label: if estimation_type == "tShirt" { t_shirt_label(*value).to_string() } else { value.to_string() },
+            })
+        );
+
+        estimates
+    }
 }
 
 
 pub struct LinearClient {
     pub client: reqwest::Client,
     pub config: LinearConfig,
+    pub cache: Option<LinearCache>,
+    pub scheduler: RequestScheduler,
 }
 
 impl LinearClient {
 
-    pub fn with_config(config: LinearConfig) -> Result<LinearClient> {
+    pub fn with_config(config: LinearConfig) -> ClientResult<LinearClient> {
 
         let linear_api_key = match &config.api_key {
             Some(x) => x.to_string(),
-            None => return Err(anyhow!("LinearConfig missing API key")),
+            None => return Err(ClientError::Config(String::from("LinearConfig missing API key"))),
         };
 
         let mut headers = header::HeaderMap::new();
 
-        let mut auth_value = header::HeaderValue::from_str(&format!("{}", linear_api_key))?;
+        let mut auth_value = header::HeaderValue::from_str(&format!("{}", linear_api_key))
+            .map_err(|err| ClientError::Config(err.to_string()))?;
         auth_value.set_sensitive(true);
     
         headers.insert(header::AUTHORIZATION, auth_value);
@@ -85,11 +197,97 @@ impl LinearClient {
                 .default_headers(headers.clone())
                 .build()
                 .unwrap(),
-            config: config
+            config: config,
+            cache: None,
+            scheduler: RequestScheduler::new(),
         })
     }
 
-    pub async fn custom_views(&self, cursor_opt: Option<GraphQLCursor>) -> Result<Option<CustomViewResponseData>> {
+    // Send a query through the scheduler, unsuccessful responses & GraphQL errors are returned as the matching ClientError
+    async fn post<Q: GraphQLQuery>(&self, variables: Q::Variables) -> ClientResult<Option<Q::ResponseData>> {
+        let query = Q::build_query(variables);
+        let body = serde_json::to_value(&query)?;
+
+        self.post_body::<Q>(body).await
+    }
+
+    // Accepts:
+    //     body: a built query, e.g. with variables edited after serializing
+    async fn post_body<Q: GraphQLQuery>(&self, body: serde_json::Value) -> ClientResult<Option<Q::ResponseData>> {
+        let idempotent = !body["query"].as_str().unwrap_or_default().trim_start().starts_with("mutation");
+
+        self.scheduler.run(idempotent, || self.post_once::<Q>(&body)).await
+    }
+
+    async fn post_once<Q: GraphQLQuery>(&self, body: &serde_json::Value) -> ClientResult<Option<Q::ResponseData>> {
+        let resp = self.client
+            .post(&self.config.api_endpoint)
+            .json(body)
+            .send()
+            .await?;
+
+        self.scheduler.record(resp.headers());
+
+        let status = resp.status();
+        let text = resp.text().await?;
+
+        let response: graphql_client::Response<Q::ResponseData> = match serde_json::from_str(&text) {
+            Ok(x) => x,
+            Err(err) if status.is_success() => return Err(err.into()),
+            Err(_) => return Err(ClientError::from_status(status, &text)),
+        };
+
+        if let Some(errors) = response.errors.filter(|errors| !errors.is_empty()) {
+            let err = ClientError::from_graphql(errors.iter().map(|error| (
+                error.message.as_str(),
+                error.extensions.as_ref().and_then(|x| x.get("code")).and_then(|x| x.as_str()),
+            )));
+
+            // Keep partial data, if any was returned
+            if response.data.is_none() {
+                return Err(err);
+            }
+            warn!("LinearClient::post() - partial response: {}", err);
+        }
+
+        Ok(response.data)
+    }
+
+    // Write successful responses to the cache, and fall back to the cached response if the request fails
+    async fn with_cache<T, F>(&self, tree: CacheTree, key: &str, request: F) -> ClientResult<Option<T>>
+    where
+        T: Serialize + DeserializeOwned,
+        F: Future<Output = ClientResult<Option<T>>>,
+    {
+        let cache = match &self.cache {
+            Some(cache) => cache,
+            None => return request.await,
+        };
+
+        match request.await {
+            Ok(Some(data)) => {
+                cache.insert(tree, key, &data);
+                cache.set_offline(false);
+                Ok(Some(data))
+            },
+            Ok(None) => Ok(None),
+            Err(err) => {
+                match cache.get::<T>(tree, key) {
+                    Some(cached) => {
+                        warn!("LinearClient - request failed, using cached {:?} '{}': {:?}", tree, key, err);
+                        cache.set_offline(true);
+                        Ok(Some(cached))
+                    },
+                    None => Err(err),
+                }
+            }
+        }
+    }
+
+    // Workspace-wide, cached per profile
+    pub async fn custom_views(&self, cursor_opt: Option<GraphQLCursor>) -> ClientResult<Option<CustomViewResponseData>> {
+
+        let cache_key = LinearCache::page_key(&self.config.profile, &cursor_opt);
 
         let variables = CustomViewVariables {
             first_num: Some(self.config.custom_view_page_size as i64),
@@ -98,20 +296,18 @@ impl LinearClient {
         
         debug!("custom_views() - Variables: {:?}", variables);
 
-        Ok(
-            post_graphql::<ViewQuery, _>(&self.client, "https://api.linear.app/graphql", variables).await?.data
-        )
+        self.with_cache(CacheTree::CustomViews, &cache_key, async {
+            self.post::<ViewQuery>(variables).await
+        }).await
     }
 
-    pub async fn viewer(&self) -> Result<Option<ViewerResponseData>> {
+    pub async fn viewer(&self) -> ClientResult<Option<ViewerResponseData>> {
 
         let variables = ViewerVariables{};
-        Ok(
-            post_graphql::<ViewerQuery, _>(&self.client, "https://api.linear.app/graphql", variables).await?.data
-        )
+        self.post::<ViewerQuery>(variables).await
     }
 
-    pub async fn issues(&self, filter: IssueFilter, cursor_opt: Option<GraphQLCursor>) -> Result<Option<IssuesResponseData>> {
+    pub async fn issues(&self, filter: IssueFilter, cursor_opt: Option<GraphQLCursor>) -> ClientResult<Option<IssuesResponseData>> {
         let variables = IssuesVariables {
             first_num: Some(self.config.view_panel_page_size as i64),
             after_cursor: if let Some(cursor) = cursor_opt { cursor.end_cursor } else { None },
@@ -120,14 +316,57 @@ impl LinearClient {
 
         // debug!("issues() - Variables: {:?}", variables);
 
-        let x = post_graphql::<IssuesQuery, _>(&self.client, "https://api.linear.app/graphql", variables).await?;
-        // debug!("issues() - Response<ResponseData>: {:?}", x);
-        Ok(
-            x.data
-        )
+        self.post::<IssuesQuery>(variables).await
+    }
+
+    // Fetch a page of a Custom View's issues, cached by view id & cursor
+    pub async fn view_issues(&self, view: &CustomView, cursor_opt: Option<GraphQLCursor>) -> ClientResult<Option<IssuesResponseData>> {
+        let cache_key = LinearCache::page_key(&view.id, &cursor_opt);
+        let filter: IssueFilter = serde_json::from_value(serde_json::to_value(&view.filter_data)?)?;
+
+        self.with_cache(CacheTree::Issues, &cache_key, self.issues(filter, cursor_opt)).await
+    }
+
+    // Check if an issue is included in a Custom View, by querying the view's filter restricted to the issue's id
+    pub async fn view_contains_issue(&self, view: &CustomView, issue_id: &str) -> ClientResult<bool> {
+        Ok(!self.view_issues_by_id(view, &[issue_id.to_string()]).await?.is_empty())
+    }
+
+    // Accepts:
+    //     issue_ids: looked up with a single 'id in [...]' filter, paginated if more than a page
+    // Returns:
+    //     the Issues among 'issue_ids' which match the Custom View's filters
+    pub async fn view_issues_by_id(&self, view: &CustomView, issue_ids: &[String]) -> ClientResult<Vec<Issue>> {
+        let filter = serde_json::json!({
+            "and": [
+                serde_json::to_value(&view.filter_data)?,
+                { "id": { "in": issue_ids } },
+            ]
+        });
+
+        let mut issues: Vec<Issue> = Vec::new();
+        let mut cursor: Option<GraphQLCursor> = None;
+        loop {
+            let data = match self.issues(serde_json::from_value(filter.clone())?, cursor).await? {
+                Some(data) => data,
+                None => break,
+            };
+            issues.extend(data.issues.nodes.into_iter().filter(|issue| issue_ids.contains(&issue.id)));
+
+            if !data.issues.page_info.has_next_page {
+                break;
+            }
+            cursor = Some(GraphQLCursor {
+                platform: Platform::Linear,
+                has_next_page: true,
+                end_cursor: data.issues.page_info.end_cursor,
+            });
+        }
+
+        Ok(issues)
     }
 
-    pub async fn team_cycles(&self, team_id: &str, cursor: Option<GraphQLCursor>) -> Result<Option<CyclesResponseData>> {
+    pub async fn team_cycles(&self, team_id: &str, cursor: Option<GraphQLCursor>) -> ClientResult<Option<CyclesResponseData>> {
 
         type CycleFilter = cycles_query::CycleFilter;
         type TeamFilter = cycles_query::TeamFilter;
@@ -168,44 +407,45 @@ impl LinearClient {
             or: None,
         };
     
-        self.cycles(Some(filter), cursor).await
+        let cache_key = LinearCache::page_key(team_id, &cursor);
+        self.with_cache(CacheTree::Cycles, &cache_key, self.cycles(Some(filter), cursor)).await
     }
-    pub async fn cycles(&self, cycle_filter: Option<cycles_query::CycleFilter>, cursor_opt: Option<GraphQLCursor>) -> Result<Option<CyclesResponseData>> {
+    pub async fn cycles(&self, cycle_filter: Option<cycles_query::CycleFilter>, cursor_opt: Option<GraphQLCursor>) -> ClientResult<Option<CyclesResponseData>> {
 
         let variables = CyclesVariables {
             first_num: Some(self.config.issue_op_page_size as i64),
             after_cursor: if let Some(cursor) = cursor_opt { cursor.end_cursor } else { None },
-            cycle_filter: cycle_filter,
+            cycle_filter,
         };
-        Ok(
-            post_graphql::<CyclesQuery, _>(&self.client, "https://api.linear.app/graphql", variables).await?.data
-        )
+        self.post::<CyclesQuery>(variables).await
     }
 
-    pub async fn team_projects(&self, team_id: &str, cursor_opt: Option<GraphQLCursor>) -> Result<Option<ProjectsResponseData>> {
+    pub async fn team_projects(&self, team_id: &str, cursor_opt: Option<GraphQLCursor>) -> ClientResult<Option<ProjectsResponseData>> {
+        let cache_key = LinearCache::page_key(team_id, &cursor_opt);
         let variables = ProjectsVariables {
             first_num: Some(self.config.issue_op_page_size as i64),
             after_cursor: if let Some(cursor) = cursor_opt { cursor.end_cursor } else { None },
             ref_: team_id.to_string(),
         };
         
-        Ok(
-            post_graphql::<TeamProjectsQuery, _>(&self.client, "https://api.linear.app/graphql", variables).await?.data
-        )
+        self.with_cache(CacheTree::Projects, &cache_key, async {
+            self.post::<TeamProjectsQuery>(variables).await
+        }).await
     }
 
-    pub async fn team_members(&self, team_id: &str, cursor_opt: Option<GraphQLCursor>) -> Result<Option<TeamMembersResponseData>> {
+    pub async fn team_members(&self, team_id: &str, cursor_opt: Option<GraphQLCursor>) -> ClientResult<Option<TeamMembersResponseData>> {
+        let cache_key = LinearCache::page_key(team_id, &cursor_opt);
         let variables = TeamMembersVariables {
             first_num: Some(self.config.issue_op_page_size as i64),
             after_cursor: if let Some(cursor) = cursor_opt { cursor.end_cursor } else { None },
             ref_: team_id.to_string(),
         };
-        Ok(
-            post_graphql::<TeamMembersQuery, _>(&self.client, "https://api.linear.app/graphql", variables).await?.data
-        )
+        self.with_cache(CacheTree::TeamMembers, &cache_key, async {
+            self.post::<TeamMembersQuery>(variables).await
+        }).await
     }
 
-    pub async fn team_states(&self, team_id: &str, cursor: Option<GraphQLCursor>) -> Result<Option<StatesResponseData>> {
+    pub async fn team_states(&self, team_id: &str, cursor: Option<GraphQLCursor>) -> ClientResult<Option<StatesResponseData>> {
 
         type StateFilter = states_query::WorkflowStateFilter;
         type TeamFilter = states_query::TeamFilter;
@@ -240,31 +480,168 @@ impl LinearClient {
             or: Box::new(None),
         };
     
-        self.states(filter, cursor).await
+        let cache_key = LinearCache::page_key(team_id, &cursor);
+        self.with_cache(CacheTree::WorkflowStates, &cache_key, self.states(filter, cursor)).await
     
     }
-    pub async fn states(&self, state_filter: states_query::WorkflowStateFilter, cursor_opt: Option<GraphQLCursor>) -> Result<Option<StatesResponseData>> {
+    // Select the workflow state an issue should have after moving to the team 'team_id':
+    //     the current state if it belongs to the team, otherwise the team's first state of the same type
+    // Returns None if no match is found (or the request fails), leaving the selection to Linear
+    pub async fn destination_team_state(&self, team_id: &str, state_id: &str, state_type: &str) -> Option<String> {
+        let team_states: Vec<State> = match self.team_states(team_id, None).await {
+            Ok(Some(resp)) => resp.workflow_states.nodes,
+            x => {
+                warn!("destination_team_state - failed to fetch team '{}' states: {:?}", team_id, x);
+                return None;
+            }
+        };
+
+        team_states.iter()
+            .find(|state| state.id == state_id)
+            .or_else(|| team_states.iter().find(|state| state.type_ == state_type))
+            .map(|state| state.id.clone())
+    }
+
+    pub async fn states(&self, state_filter: states_query::WorkflowStateFilter, cursor_opt: Option<GraphQLCursor>) -> ClientResult<Option<StatesResponseData>> {
     
         let variables = StatesVariables {
             first_num: Some(self.config.issue_op_page_size as i64),
             after_cursor: if let Some(cursor) = cursor_opt { cursor.end_cursor } else { None },
             state_filter: Some(state_filter)
         };
-        Ok(
-            post_graphql::<StatesQuery, _>(&self.client, "https://api.linear.app/graphql", variables).await?.data
-        )
+        self.post::<StatesQuery>(variables).await
+    }
+
+    // Workspace-wide, cached per profile
+    pub async fn teams(&self, cursor_opt: Option<GraphQLCursor>) -> ClientResult<Option<TeamsResponseData>> {
+        let cache_key = LinearCache::page_key(&self.config.profile, &cursor_opt);
+        let variables = TeamsVariables {
+            first_num: Some(self.config.issue_op_page_size as i64),
+            after_cursor: if let Some(cursor) = cursor_opt { cursor.end_cursor } else { None },
+        };
+        self.with_cache(CacheTree::Teams, &cache_key, async {
+            self.post::<TeamsQuery>(variables).await
+        }).await
+    }
+
+    // Labels which can be applied to the team's issues: the team's own labels & workspace labels
+    pub async fn team_labels(&self, team_id: &str, cursor_opt: Option<GraphQLCursor>) -> ClientResult<Option<TeamLabelsResponseData>> {
+        let cache_key = LinearCache::page_key(team_id, &cursor_opt);
+
+        let label_filter: LabelFilter = serde_json::from_value(serde_json::json!({
+            "or": [
+                { "team": { "id": { "eq": team_id } } },
+                { "team": { "null": true } },
+            ]
+        }))?;
+
+        let variables = TeamLabelsVariables {
+            first_num: Some(self.config.issue_op_page_size as i64),
+            after_cursor: if let Some(cursor) = cursor_opt { cursor.end_cursor } else { None },
+            label_filter: Some(label_filter),
+        };
+        self.with_cache(CacheTree::Labels, &cache_key, async {
+            self.post::<TeamLabelsQuery>(variables).await
+        }).await
+    }
+
+    pub async fn team_estimation(&self, team_id: &str) -> ClientResult<Option<TeamEstimationResponseData>> {
+        let variables = TeamEstimationVariables {
+            ref_: team_id.to_string(),
+        };
+        self.with_cache(CacheTree::TeamEstimation, team_id, async {
+            self.post::<TeamEstimationQuery>(variables).await
+        }).await
+    }
+
+    // Not cached, search results are only shown while searching
+    pub async fn issue_search(&self, query: &str, cursor_opt: Option<GraphQLCursor>) -> ClientResult<Option<IssueSearchResponseData>> {
+        let variables = IssueSearchVariables {
+            query: query.to_string(),
+            first_num: Some(self.config.view_panel_page_size as i64),
+            after_cursor: if let Some(cursor) = cursor_opt { cursor.end_cursor } else { None },
+        };
+        self.post::<IssueSearchQuery>(variables).await
+    }
+
+    // Not cached, always reflects the remote description
+    pub async fn issue_description(&self, issue_id: &str) -> ClientResult<Option<IssueDescriptionResponseData>> {
+        let variables = IssueDescriptionVariables {
+            ref_: issue_id.to_string(),
+        };
+        self.post::<IssueDescriptionQuery>(variables).await
+    }
+
+    // Fetch a page of an Issue's comments, cached by issue id & cursor
+    pub async fn issue_comments(&self, issue_id: &str, cursor_opt: Option<GraphQLCursor>) -> ClientResult<Option<IssueCommentsResponseData>> {
+        let cache_key = LinearCache::page_key(issue_id, &cursor_opt);
+        let variables = IssueCommentsVariables {
+            ref_: issue_id.to_string(),
+            first_num: Some(self.config.issue_op_page_size as i64),
+            after_cursor: if let Some(cursor) = cursor_opt { cursor.end_cursor } else { None },
+        };
+        self.with_cache(CacheTree::Comments, &cache_key, async {
+            self.post::<IssueCommentsQuery>(variables).await
+        }).await
+    }
+
+    pub async fn create_comment(&self, issue_id: &str, body: &str) -> ClientResult<Option<CommentCreateResponseData>> {
+        let variables = CommentCreateVariables {
+            input: CommentCreateInput {
+                id: None,
+                body: Some(body.to_string()),
+                body_data: None,
+                issue_id: issue_id.to_string(),
+                parent_id: None,
+                create_as_user: None,
+                display_icon_url: None,
+                created_at: None,
+            },
+        };
+        self.post::<CommentCreateMut>(variables).await
+    }
+
+    pub async fn create_issue(&self, input: IssueCreateInput) -> ClientResult<Option<IssueCreateResponseData>> {
+
+        let variables = IssueCreateVariables {
+            input,
+        };
+        self.post::<IssueCreateMut>(variables).await
     }
 
     // Note: Idempotent
-    pub async fn update_issue(&self, issue_id: &str, update: IssueUpdateInput) -> Result<Option<IssueUpdateResponseData>> {
+    pub async fn update_issue(&self, issue_id: &str, update: IssueUpdateInput) -> ClientResult<Option<IssueUpdateResponseData>> {
+
+        let variables = IssueUpdateVariables {
+            issue_id: issue_id.to_string(),
+            update,
+        };
+        self.post::<IssueUpdateMut>(variables).await
+    }
+
+    // Unset fields of 'update' are left out of the request, 'cleared' fields are sent as null instead
+    // e.g. to unassign an Issue
+    pub async fn update_issue_clearing(&self, issue_id: &str, update: IssueUpdateInput, cleared: &[IssueModificationOp]) -> ClientResult<Option<IssueUpdateResponseData>> {
 
         let variables = IssueUpdateVariables {
             issue_id: issue_id.to_string(),
             update,
         };
-        Ok(
-            post_graphql::<IssueUpdateMut, _>(&self.client, "https://api.linear.app/graphql", variables).await?.data
-        )
+        let mut body = serde_json::to_value(IssueUpdateMut::build_query(variables))?;
+
+        for op in cleared.iter() {
+            let field = match op {
+                IssueModificationOp::Assignee => "assigneeId",
+                IssueModificationOp::Project => "projectId",
+                IssueModificationOp::Cycle => "cycleId",
+                IssueModificationOp::Estimate => "estimate",
+                IssueModificationOp::DueDate => "dueDate",
+                _ => return Err(ClientError::Unsupported(format!("{:?} can't be cleared", op))),
+            };
+            body["variables"]["update"][field] = serde_json::Value::Null;
+        }
+
+        self.post_body::<IssueUpdateMut>(body).await
     }
 
 }
\ No newline at end of file
@@ -1,26 +1,50 @@
 
 use std::{
+    collections::HashMap,
     fs,
     env,
     path::{Path, PathBuf},
+    str::FromStr,
+    time::Duration,
 };
 
+use crate::config::{ ConfigFile, DashboardSettings, LinearSettings, PanelSettings, DEFAULT_DASHBOARD_NAME, DEFAULT_PROFILE_NAME };
+
+use crate::app::Platform;
+
 use crate::linear::schema::{CustomView, Viewer};
 
-use crate::constants::LINEAR_TOKEN_LEN;
+use crate::constants::view_panel_columns::PanelColumnConfig;
 
-use crate::util::error_panic;
+use crate::util::{
+    error_panic,
+    dashboard::compact_view_list,
+    grid_layout::{ GridLayout, PanelSpan },
+    issue_order::PanelOrder,
+};
 
 const CONFIG_DIR: &str = ".config";
 const APP_CONFIG_DIR: &str = "rust-cli";
 
+const APP_CONFIG_TOML_FILE_NAME: &str = "config.toml";
+const APP_LOCAL_CACHE_DIR: &str = "cache";
+
+// Pre-TOML files, only read to migrate them into config.toml
 const APP_CONFIG_FILE_NAME: &str = "config.txt";
 const APP_VIEWER_OBJECT_FILE_NAME: &str = "viewer.txt";
 const APP_DASHBOARD_VIEW_LIST: &str = "view_list.txt";
+const APP_LOCAL_FILTERS_FILE_NAME: &str = "filters.txt";
+const APP_PANEL_ORDER_FILE_NAME: &str = "panel_order.txt";
+const APP_PANEL_COLUMNS_FILE_NAME: &str = "panel_columns.txt";
+
+// Key of read_panel_columns() applied to panels without their own columns
+pub const DEFAULT_PANEL_COLUMNS_KEY: &str = "default";
 
 
 pub const MAX_PAGE_SIZE: u32 = 50;
 
+const DEFAULT_LINEAR_API_ENDPOINT: &str = "https://api.linear.app/graphql";
+
 const DEFAULT_LINEAR_ISSUE_PAGE_SIZE: u32 = 50;
 const DEFAULT_LINEAR_VIEW_PANEL_PAGE_SIZE: u32 = 50;
 const DEFAULT_LINEAR_ISSUE_OP_PAGE_SIZE: u32 = 50;
@@ -30,10 +54,15 @@ const DEFAULT_LINEAR_DUE_SOON_DAY_THRESHOLD: u32 = 5;
 
 #[derive(Debug, Clone)]
 pub struct LinearConfig {
+    // Name of the profile the token & viewer belong to
+    pub profile: String,
     pub is_valid_token: bool,
     pub api_key: Option<String>,
     pub viewer_object: Option<Viewer>,
 
+    // GraphQL endpoint all requests are sent to, override to target a mock server or proxy
+    pub api_endpoint: String,
+
     pub issue_page_size: u32,
     pub view_panel_page_size: u32,
     pub issue_op_page_size: u32,
@@ -43,47 +72,53 @@ pub struct LinearConfig {
     pub due_soon_day_threshold: u32,
 }
 
-impl Default for LinearConfig {
-    fn default() -> LinearConfig {
-
-        info!("{:?}", env::var("LINEAR_PERSONAL_API_KEY"));
+// Environment variable, then config.toml value, then default
+fn setting<T: FromStr + Copy>(var: &str, file_value: Option<T>, default: T) -> T {
+    match env::var(var).ok().and_then(|x| x.parse::<T>().ok()) {
+        Some(x) => x,
+        None => file_value.unwrap_or(default),
+    }
+}
 
+impl LinearConfig {
+    fn with_settings(settings: &LinearSettings) -> LinearConfig {
         LinearConfig {
+            profile: DEFAULT_PROFILE_NAME.to_string(),
             is_valid_token: false,
             api_key: None,
             viewer_object: None,
-            issue_page_size: match env::var("LINEAR_ISSUE_PAGE_SIZE").ok() {
-                Some(x) => *x.parse::<u32>().ok().get_or_insert(DEFAULT_LINEAR_ISSUE_PAGE_SIZE),
-                None => DEFAULT_LINEAR_ISSUE_PAGE_SIZE,
-            },
-            view_panel_page_size: match env::var("LINEAR_VIEW_PANEL_PAGE_SIZE").ok() {
-                Some(x) => *x.parse::<u32>().ok().get_or_insert(DEFAULT_LINEAR_VIEW_PANEL_PAGE_SIZE),
-                None => DEFAULT_LINEAR_VIEW_PANEL_PAGE_SIZE,
-            },
-            issue_op_page_size: match env::var("LINEAR_ISSUE_OP_PAGE_SIZE").ok() {
-                Some(x) => *x.parse::<u32>().ok().get_or_insert(DEFAULT_LINEAR_ISSUE_OP_PAGE_SIZE),
-                None => DEFAULT_LINEAR_ISSUE_OP_PAGE_SIZE,
+            api_endpoint: match env::var("LINEAR_API_ENDPOINT").ok().or_else(|| settings.api_endpoint.clone()) {
+                Some(x) if !x.trim().is_empty() => x.trim().to_string(),
+                _ => DEFAULT_LINEAR_API_ENDPOINT.to_string(),
             },
-            custom_view_page_size: match env::var("LINEAR_CUSTOM_VIEW_PAGE_SIZE").ok() {
-                Some(x) => *x.parse::<u32>().ok().get_or_insert(DEFAULT_LINEAR_CUSTOM_VIEW_PAGE_SIZE),
-                None => DEFAULT_LINEAR_CUSTOM_VIEW_PAGE_SIZE,
-            },
-            team_timezone_page_size: match env::var("LINEAR_TEAM_TIMEZONE_PAGE_SIZE").ok() {
-                Some(x) => *x.parse::<u32>().ok().get_or_insert(DEFAULT_LINEAR_TEAM_TIMEZONE_PAGE_SIZE),
-                None => DEFAULT_LINEAR_TEAM_TIMEZONE_PAGE_SIZE,
-            },
-            due_soon_day_threshold: match env::var("LINEAR_DUE_SOON_DAY_THRESHOLD").ok() {
-                Some(x) => *x.parse::<u32>().ok().get_or_insert(DEFAULT_LINEAR_DUE_SOON_DAY_THRESHOLD),
-                None => DEFAULT_LINEAR_DUE_SOON_DAY_THRESHOLD,
-            }
+            issue_page_size: setting("LINEAR_ISSUE_PAGE_SIZE", settings.issue_page_size, DEFAULT_LINEAR_ISSUE_PAGE_SIZE),
+            view_panel_page_size: setting("LINEAR_VIEW_PANEL_PAGE_SIZE", settings.view_panel_page_size, DEFAULT_LINEAR_VIEW_PANEL_PAGE_SIZE),
+            issue_op_page_size: setting("LINEAR_ISSUE_OP_PAGE_SIZE", settings.issue_op_page_size, DEFAULT_LINEAR_ISSUE_OP_PAGE_SIZE),
+            custom_view_page_size: setting("LINEAR_CUSTOM_VIEW_PAGE_SIZE", settings.custom_view_page_size, DEFAULT_LINEAR_CUSTOM_VIEW_PAGE_SIZE),
+            team_timezone_page_size: setting("LINEAR_TEAM_TIMEZONE_PAGE_SIZE", settings.team_timezone_page_size, DEFAULT_LINEAR_TEAM_TIMEZONE_PAGE_SIZE),
+            due_soon_day_threshold: setting("LINEAR_DUE_SOON_DAY_THRESHOLD", settings.due_soon_day_threshold, DEFAULT_LINEAR_DUE_SOON_DAY_THRESHOLD),
         }
     }
 }
 
+impl Default for LinearConfig {
+    fn default() -> LinearConfig {
+        let settings = ConfigFile::load().map(|config| config.linear).unwrap_or_default();
+        LinearConfig::with_settings(&settings)
+    }
+}
+
 pub enum CachedDataFile {
+    ConfigFile,
+    LocalCache,
+
+    // Pre-TOML files
     AccessToken,
     ViewerObject,
     ViewList,
+    LocalFilters,
+    PanelOrder,
+    PanelColumns,
 }
 
 impl LinearConfig {
@@ -108,9 +143,14 @@ impl LinearConfig {
                     }
                 }
                 let file_path = match data_file {
+                    CachedDataFile::ConfigFile => app_config_dir.join(APP_CONFIG_TOML_FILE_NAME),
                     CachedDataFile::AccessToken => app_config_dir.join(APP_CONFIG_FILE_NAME),
                     CachedDataFile::ViewerObject => app_config_dir.join(APP_VIEWER_OBJECT_FILE_NAME),
-                    CachedDataFile::ViewList => app_config_dir.join(APP_DASHBOARD_VIEW_LIST)
+                    CachedDataFile::ViewList => app_config_dir.join(APP_DASHBOARD_VIEW_LIST),
+                    CachedDataFile::LocalCache => app_config_dir.join(APP_LOCAL_CACHE_DIR),
+                    CachedDataFile::LocalFilters => app_config_dir.join(APP_LOCAL_FILTERS_FILE_NAME),
+                    CachedDataFile::PanelOrder => app_config_dir.join(APP_PANEL_ORDER_FILE_NAME),
+                    CachedDataFile::PanelColumns => app_config_dir.join(APP_PANEL_COLUMNS_FILE_NAME),
                 };
                 file_path.to_path_buf()
             }
@@ -120,16 +160,17 @@ impl LinearConfig {
         }
     }
 
-    pub fn new(token: &str, viewer_opt: Option<Viewer>, cache: bool) -> LinearConfig {
+    pub fn new(profile: &str, token: &str, viewer_opt: Option<Viewer>, cache: bool) -> LinearConfig {
 
         if cache {
-            LinearConfig::save_access_token(token);
+            LinearConfig::save_access_token(profile, token);
             if let Some(ref viewer_obj) = viewer_opt {
-                LinearConfig::save_viewer_object(viewer_obj.clone());
+                LinearConfig::save_viewer_object(profile, viewer_obj.clone());
             }
         }
 
         let mut config = LinearConfig::default();
+        config.profile = profile.to_string();
         config.api_key = Some(String::from(token));
         config.is_valid_token = true;
         config.viewer_object = viewer_opt;
@@ -137,105 +178,252 @@ impl LinearConfig {
         config
     }
 
-    fn save_access_token(token: &str) {
-        let config_file_path = LinearConfig::get_or_build_paths(CachedDataFile::AccessToken);
-        fs::write(&config_file_path, token.to_string()).expect("Unable to write file");
+    fn save_access_token(profile: &str, token: &str) {
+        ConfigFile::update(|config| config.profile_mut(profile).token = Some(token.to_string()));
     }
 
-    fn save_viewer_object(viewer_object: Viewer) {
-        let viewer_object_file_path = LinearConfig::get_or_build_paths(CachedDataFile::ViewerObject);
-        let serialized = serde_json::to_string(&viewer_object).unwrap();
-        fs::write(&viewer_object_file_path, serialized).unwrap();
-    }    
+    pub fn save_viewer_object(profile: &str, viewer_object: Viewer) {
+        ConfigFile::update(|config| config.profile_mut(profile).viewer = Some(viewer_object));
+    }
 
+    // Config of the active profile
     pub fn load_config() -> Option<LinearConfig> {
+        LinearConfig::load_profile(&LinearConfig::read_active_profile())
+    }
 
-        let mut config = LinearConfig::default();
+    // None if config.toml is invalid or the profile has no token
+    pub fn load_profile(profile: &str) -> Option<LinearConfig> {
+        let config_file = match ConfigFile::load() {
+            Ok(config_file) => config_file,
+            Err(errors) => {
+                error!("load_profile() - invalid config.toml: {:?}", errors);
+                return None;
+            }
+        };
+        let profile_settings = config_file.profile(profile)?.clone();
 
-        // Load token 
-        let config_file_path = LinearConfig::get_or_build_paths(CachedDataFile::AccessToken);
-        if config_file_path.exists() {
-            match fs::read_to_string(&config_file_path) {
-                Ok(token_val) => {
-                    // verify token is correct len
-                    let token_len: u16 = unicode_width::UnicodeWidthStr::width(token_val.as_str()) as u16;
-                    if token_len != LINEAR_TOKEN_LEN {
-                        return None;
-                    }
+        let mut config = LinearConfig::with_settings(&config_file.linear);
+        config.profile = profile_settings.name;
 
-                    config.is_valid_token = true;
-                    config.api_key = Some(token_val);
-                },
-                Err(_) => {
-                    error_panic!("load_config - fs::read_to_string() failed: {:?}", config_file_path);
-                },
+        // Token length is checked by ConfigFile::parse()
+        config.api_key = Some(profile_settings.token?);
+        config.is_valid_token = true;
+        config.viewer_object = profile_settings.viewer;
+
+        Some(config)
+    }
+
+    fn read_config_file() -> ConfigFile {
+        ConfigFile::load().unwrap_or_else(|errors| {
+            error!("read_config_file() - invalid config.toml: {:?}", errors);
+            ConfigFile::default()
+        })
+    }
+
+    // Profiles
+
+    // Names of all profiles, in config order, empty until a token is saved
+    pub fn read_profile_names() -> Vec<String> {
+        LinearConfig::read_config_file().profile_names()
+    }
+
+    // Profile last switched to, falls back to the first profile
+    pub fn read_active_profile() -> String {
+        let config = LinearConfig::read_config_file();
+        config.app.profile.clone()
+            .filter(|name| config.profile(name).is_some())
+            .or_else(|| config.profiles.first().map(|x| x.name.clone()))
+            .unwrap_or_else(|| DEFAULT_PROFILE_NAME.to_string())
+    }
+
+    pub fn save_active_profile(name: &str) {
+        ConfigFile::update(|config| config.app.profile = Some(name.to_string()));
+    }
+
+    // Cached viewers of all profiles, keyed by profile name
+    pub fn read_profile_viewers() -> HashMap<String, Viewer> {
+        LinearConfig::read_config_file().profiles.into_iter()
+            .filter_map(|profile| Some((profile.name, profile.viewer?)))
+            .collect()
+    }
+
+    // Platform of each profile, keyed by profile name
+    pub fn read_profile_platforms() -> HashMap<String, Platform> {
+        LinearConfig::read_config_file().profiles.into_iter()
+            .map(|profile| (profile.name.clone(), profile.platform()))
+            .collect()
+    }
+
+    // Dashboards
+
+    // Names of all dashboards, in config order
+    pub fn read_dashboard_names() -> Vec<String> {
+        let names = LinearConfig::read_config_file().dashboard_names();
+        if names.is_empty() { vec![DEFAULT_DASHBOARD_NAME.to_string()] } else { names }
+    }
+
+    // Dashboard last switched to, falls back to the first dashboard
+    pub fn read_active_dashboard() -> String {
+        let config = LinearConfig::read_config_file();
+        config.app.dashboard.clone()
+            .filter(|name| config.dashboard(name).is_some())
+            .or_else(|| config.dashboards.first().map(|x| x.name.clone()))
+            .unwrap_or_else(|| DEFAULT_DASHBOARD_NAME.to_string())
+    }
+
+    pub fn save_active_dashboard(name: &str) {
+        ConfigFile::update(|config| {
+            config.dashboard_mut(name);
+            config.app.dashboard = Some(name.to_string());
+        });
+    }
+
+    pub fn create_dashboard(name: &str) {
+        ConfigFile::update(|config| { config.dashboard_mut(name); });
+    }
+
+    pub fn rename_dashboard(name: &str, new_name: &str) {
+        ConfigFile::update(|config| {
+            config.dashboard_mut(name).name = new_name.to_string();
+            if config.app.dashboard.as_deref() == Some(name) {
+                config.app.dashboard = Some(new_name.to_string());
             }
-        } else {
+        });
+    }
+
+    // Copies panels, orders and columns
+    pub fn duplicate_dashboard(name: &str, new_name: &str) {
+        ConfigFile::update(|config| {
+            let dashboard = DashboardSettings { name: new_name.to_string(), ..config.dashboard_mut(name).clone() };
+            config.dashboards.push(dashboard);
+        });
+    }
+
+    fn read_dashboard(name: &str) -> DashboardSettings {
+        LinearConfig::read_config_file().dashboard(name).cloned().unwrap_or_default()
+    }
+
+    // Panel settings are kept for views still on the dashboard
+    pub fn save_view_list(dashboard: &str, view_list: Vec<Option<CustomView>>) {
+        ConfigFile::update(|config| {
+            let dashboard = config.dashboard_mut(dashboard);
+            let mut prev_panels = std::mem::take(&mut dashboard.panels);
+
+            // Empty slots aren't saved
+            dashboard.panels = view_list.into_iter()
+                .filter(|view| view.is_some())
+                .map(|view| {
+                    let prev_idx = view.as_ref().and_then(|view| {
+                        prev_panels.iter().position(|panel| panel.view.as_ref().map(|x| &x.id) == Some(&view.id))
+                    });
+                    let prev = prev_idx.map(|idx| prev_panels.remove(idx)).unwrap_or_default();
+                    PanelSettings { view, ..prev }
+                })
+                .collect();
+        });
+    }
+
+    // Attempt to read dashboard view list from config.toml
+    pub fn read_view_list(dashboard: &str) -> Option<Vec<Option<CustomView>>> {
+        let dashboard = LinearConfig::read_dashboard(dashboard);
+        if dashboard.panels.is_empty() {
             return None;
         }
 
-        debug!("load_config() - Preparing to load viewer object");
-
-        // load & set viewer_object
-        let viewer_obj_file_path = LinearConfig::get_or_build_paths(CachedDataFile::ViewerObject);
-        if viewer_obj_file_path.exists() {
-            debug!("load_config() - viewer object file found");
-            match fs::read_to_string(&viewer_obj_file_path) {
-                Ok(viewer_obj_val) => {
-
-                    debug!("load_config() - viewer object parsed");
-                    let viewer_obj: Viewer = serde_json::from_str(&viewer_obj_val).unwrap();
-                    config.viewer_object = Some(viewer_obj);
-                },
-                Err(_) => {
-                    error_panic!("load_config - fs::read_to_string() failed: {:?}", viewer_obj_file_path);
-                },
+        let mut view_list: Vec<Option<CustomView>> = dashboard.panels.into_iter()
+            .map(|panel| panel.view)
+            .collect();
+        compact_view_list(&mut view_list);
+        Some(view_list)
+    }
+
+    // View Panel sort & grouping, keyed by CustomView id
+    pub fn save_panel_orders(dashboard: &str, panel_orders: &HashMap<String, PanelOrder>) {
+        ConfigFile::update(|config| {
+            for panel in config.dashboard_mut(dashboard).panels.iter_mut() {
+                let order = panel.view.as_ref()
+                    .and_then(|view| panel_orders.get(&view.id))
+                    .copied()
+                    .unwrap_or_default();
+                panel.sort = order.sort;
+                panel.group = order.group;
             }
-        }
+        });
+    }
 
-        Some(config)
+    pub fn read_panel_orders(dashboard: &str) -> HashMap<String, PanelOrder> {
+        LinearConfig::read_dashboard(dashboard).panels.into_iter()
+            .filter_map(|panel| {
+                let order = PanelOrder { sort: panel.sort, group: panel.group };
+                panel.view.map(|view| (view.id, order))
+            })
+            .filter(|(_, order)| !order.is_default())
+            .collect()
     }
 
+    // View Panel profiles, keyed by CustomView id
+    pub fn save_panel_profiles(dashboard: &str, panel_profiles: &HashMap<String, String>) {
+        ConfigFile::update(|config| {
+            for panel in config.dashboard_mut(dashboard).panels.iter_mut() {
+                panel.profile = panel.view.as_ref().and_then(|view| panel_profiles.get(&view.id)).cloned();
+            }
+        });
+    }
 
-    pub fn save_view_list(view_list: Vec<Option<CustomView>>) {
-        let view_list_file_path = LinearConfig::get_or_build_paths(CachedDataFile::ViewList);
-        let serialized = serde_json::to_string(&view_list).unwrap();
-        fs::write(&view_list_file_path, serialized).unwrap();
+    pub fn read_panel_profiles(dashboard: &str) -> HashMap<String, String> {
+        LinearConfig::read_dashboard(dashboard).panels.into_iter()
+            .filter_map(|panel| Some((panel.view?.id, panel.profile?)))
+            .collect()
     }
 
-    // Attempt to read cached view list from file
-    pub fn read_view_list() -> Option<Vec<Option<CustomView>>> {
-        match dirs::home_dir() {
-            Some(home) => {
-                let path = Path::new(&home);
-                let home_config_dir = path.join(CONFIG_DIR);
-                let app_config_dir = home_config_dir.join(APP_CONFIG_DIR);
-                let view_list_file_path = app_config_dir.join(APP_DASHBOARD_VIEW_LIST);
-
-                let data = fs::read_to_string(view_list_file_path);
-
-                match data {
-                    Ok(data_str) => {
-                        let deserialized: Vec<Option<CustomView>> = serde_json::from_str(&data_str).unwrap();
-                        return Some(deserialized);
-                    },
-                    // Return None if file is not found, otherwise panic
-                    Err(io_err) => {
-                        // error_panic!("read_view_list() error - {:?}", io_err);
-                        match io_err.kind() {
-                            std::io::ErrorKind::NotFound => {
-                                return None;
-                            },
-                            _ => {
-                                error_panic!("read_view_list() error - {:?}", io_err);
-                            }
-                        }
-                    }
-                }
-            },
-            None => { None }
-        }
+    // None if the dashboard has no grid layout
+    pub fn read_dashboard_layout(dashboard: &str) -> Option<GridLayout> {
+        LinearConfig::read_dashboard(dashboard).layout
     }
 
+    // View Panel grid spans, keyed by CustomView id
+    pub fn read_panel_spans(dashboard: &str) -> HashMap<String, PanelSpan> {
+        LinearConfig::read_dashboard(dashboard).panels.into_iter()
+            .filter_map(|panel| {
+                let span = PanelSpan {
+                    rows: panel.row_span.unwrap_or(1),
+                    columns: panel.column_span.unwrap_or(1),
+                };
+                panel.view.map(|view| (view.id, span))
+            })
+            .collect()
+    }
+
+    // View Panel auto-refresh intervals, keyed by CustomView id, panels without one aren't included
+    pub fn read_panel_refresh_intervals(dashboard: &str) -> HashMap<String, Duration> {
+        LinearConfig::read_dashboard(dashboard).panels.into_iter()
+            .filter_map(|panel| Some((panel.view?.id, Duration::from_secs(panel.refresh_interval_secs?))))
+            .collect()
+    }
+
+    // View Panel columns, keyed by CustomView id, or DEFAULT_PANEL_COLUMNS_KEY for the dashboard's default_columns
+    pub fn read_panel_columns(dashboard: &str) -> HashMap<String, Vec<PanelColumnConfig>> {
+        let dashboard = LinearConfig::read_dashboard(dashboard);
+
+        let mut panel_columns: HashMap<String, Vec<PanelColumnConfig>> = dashboard.panels.into_iter()
+            .filter(|panel| !panel.columns.is_empty())
+            .filter_map(|panel| Some((panel.view?.id, panel.columns)))
+            .collect();
 
-}
\ No newline at end of file
+        if !dashboard.default_columns.is_empty() {
+            panel_columns.insert(DEFAULT_PANEL_COLUMNS_KEY.to_string(), dashboard.default_columns);
+        }
+        panel_columns
+    }
+
+    // Local filter panels from config.toml [[filters]], invalid filters are rejected by ConfigFile::parse()
+    pub fn read_local_filters() -> Vec<CustomView> {
+        LinearConfig::read_config_file().filters.iter()
+            .filter_map(|local_filter| {
+                local_filter.to_custom_view()
+                    .map_err(|err| error!("read_local_filters() invalid filter '{}' - {}", local_filter.expression, err))
+                    .ok()
+            })
+            .collect()
+    }
+}
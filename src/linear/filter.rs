@@ -0,0 +1,208 @@
+// Local filter expressions, e.g. "assignee:me state:started label:bug",
+// compiled into an IssueFilter and displayed as dashboard panels without a Linear CustomView
+
+use anyhow::{ anyhow, Result };
+use serde::{ Deserialize, Serialize };
+use serde_json::{ json, Value };
+
+use super::schema::{ CustomView, IssueFilter, JSONObject };
+
+// Id prefix of CustomViews built from a local filter, never collides with Linear ids
+pub const LOCAL_VIEW_ID_PREFIX: &str = "local:";
+
+const LOCAL_VIEW_COLOR: &str = "#AAAAAA";
+
+const STATE_TYPES: [&str; 6] = ["triage", "backlog", "unstarted", "started", "completed", "canceled"];
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LocalFilter {
+    pub name: String,
+    pub expression: String,
+}
+
+impl LocalFilter {
+
+    // Accepts a line of the pre-TOML filters.txt: "name = expression", or just "expression"
+    pub fn parse_line(line: &str) -> Option<LocalFilter> {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            return None;
+        }
+
+        let (name, expression) = match line.split_once('=') {
+            Some((name, expression)) if !name.contains(':') => (name.trim(), expression.trim()),
+            _ => (line, line),
+        };
+
+        Some(LocalFilter {
+            name: if name.is_empty() { expression.to_string() } else { name.to_string() },
+            expression: expression.to_string(),
+        })
+    }
+
+    // Compile into a CustomView, so the filter can be placed in linear_dashboard_view_list
+    // and fetched through LinearClient::view_issues() like any other view
+    pub fn to_custom_view(&self) -> Result<CustomView> {
+        let filter_data: JSONObject = match compile_filter(&self.expression)? {
+            Value::Object(map) => map,
+            _ => unreachable!(),
+        };
+
+        let mut view = CustomView {
+            id: format!("{}{}", LOCAL_VIEW_ID_PREFIX, self.expression),
+            name: self.name.clone(),
+            description: if self.name != self.expression { Some(self.expression.clone()) } else { None },
+            color: Some(LOCAL_VIEW_COLOR.to_string()),
+            filter_data,
+            ..CustomView::default()
+        };
+        view.organization.name = String::from("Local");
+
+        Ok(view)
+    }
+}
+
+// Split on whitespace, double quotes group a value containing spaces: state:"In Progress"
+fn tokenize(expression: &str) -> Result<Vec<String>> {
+    let mut tokens = Vec::new();
+    let mut token = String::new();
+    let mut in_quotes = false;
+
+    for c in expression.chars() {
+        match c {
+            '"' => in_quotes = !in_quotes,
+            c if c.is_whitespace() && !in_quotes => {
+                if !token.is_empty() {
+                    tokens.push(std::mem::take(&mut token));
+                }
+            },
+            c => token.push(c),
+        }
+    }
+
+    if in_quotes {
+        return Err(anyhow!("unterminated quote"));
+    }
+    if !token.is_empty() {
+        tokens.push(token);
+    }
+
+    Ok(tokens)
+}
+
+fn priority_value(value: &str) -> Result<Value> {
+    let priority = match value {
+        "none" | "0" => 0,
+        "urgent" | "1" => 1,
+        "high" | "2" => 2,
+        "medium" | "3" => 3,
+        "low" | "4" => 4,
+        _ => return Err(anyhow!("unknown priority '{}'", value)),
+    };
+    Ok(json!({ "priority": { "eq": priority } }))
+}
+
+fn user_value(key: &str, value: &str) -> Value {
+    match value.to_lowercase().as_str() {
+        "me" => json!({ key: { "isMe": { "eq": true } } }),
+        "none" => json!({ key: { "null": true } }),
+        _ => json!({ key: { "displayName": { "eqIgnoreCase": value } } }),
+    }
+}
+
+// Compile a single "key:value" term, value is never empty
+fn compile_term(key: &str, value: &str) -> Result<Value> {
+    Ok(match key {
+        "assignee" | "creator" => user_value(key, value),
+        "state" => {
+            let lowercase = value.to_lowercase();
+            if STATE_TYPES.contains(&lowercase.as_str()) {
+                json!({ "state": { "type": { "eq": lowercase } } })
+            } else {
+                json!({ "state": { "name": { "eqIgnoreCase": value } } })
+            }
+        },
+        "label" => json!({ "labels": { "name": { "eqIgnoreCase": value } } }),
+        "team" => json!({ "team": { "key": { "eqIgnoreCase": value } } }),
+        "project" => match value.to_lowercase().as_str() {
+            "none" => json!({ "project": { "null": true } }),
+            _ => json!({ "project": { "name": { "eqIgnoreCase": value } } }),
+        },
+        "priority" => priority_value(&value.to_lowercase())?,
+        "title" => json!({ "title": { "containsIgnoreCase": value } }),
+        _ => return Err(anyhow!("unknown filter key '{}'", key)),
+    })
+}
+
+// Accepts:
+//     expression: space separated "key:value" terms, all of which must match,
+//         a comma separated value matches any of its values, e.g. "state:started,unstarted"
+//     keys: assignee, creator, state, label, team, project, priority, title
+// Returns:
+//     IssueFilter JSON, verified to only use IssueFilter fields
+pub fn compile_filter(expression: &str) -> Result<Value> {
+    let mut terms = Vec::new();
+
+    for token in tokenize(expression)? {
+        let (key, values) = token.split_once(':')
+            .ok_or_else(|| anyhow!("expected 'key:value', found '{}'", token))?;
+        let key = key.to_lowercase();
+
+        let mut alternatives = values.split(',')
+            .filter(|value| !value.is_empty())
+            .map(|value| compile_term(&key, value))
+            .collect::<Result<Vec<Value>>>()?;
+
+        match alternatives.len() {
+            0 => return Err(anyhow!("missing value for '{}'", key)),
+            1 => terms.push(alternatives.remove(0)),
+            _ => terms.push(json!({ "or": alternatives })),
+        }
+    }
+
+    if terms.is_empty() {
+        return Err(anyhow!("empty filter expression"));
+    }
+
+    let filter = json!({ "and": terms });
+    validate_filter(&filter)?;
+
+    Ok(filter)
+}
+
+// Paths of the object keys of 'filter' that are missing from 'parsed'
+fn dropped_fields(filter: &Value, parsed: &Value, path: &str, dropped: &mut Vec<String>) {
+    match (filter, parsed) {
+        (Value::Object(fields), Value::Object(parsed_fields)) => {
+            for (key, value) in fields.iter() {
+                let key_path = if path.is_empty() { key.clone() } else { format!("{}.{}", path, key) };
+                match parsed_fields.get(key) {
+                    Some(parsed_value) => dropped_fields(value, parsed_value, &key_path, dropped),
+                    None => dropped.push(key_path),
+                }
+            }
+        },
+        (Value::Array(values), Value::Array(parsed_values)) => {
+            for (idx, (value, parsed_value)) in values.iter().zip(parsed_values.iter()).enumerate() {
+                dropped_fields(value, parsed_value, &format!("{}[{}]", path, idx), dropped);
+            }
+        },
+        _ => {},
+    }
+}
+
+// IssueFilter deserialization ignores unknown fields, so compare against the fields it kept
+// Returns:
+//     Err if 'filter' isn't a valid IssueFilter, or has fields IssueFilter doesn't
+pub fn validate_filter(filter: &Value) -> Result<()> {
+    let parsed = serde_json::to_value(serde_json::from_value::<IssueFilter>(filter.clone())?)?;
+
+    let mut dropped: Vec<String> = Vec::new();
+    dropped_fields(filter, &parsed, "", &mut dropped);
+
+    if dropped.is_empty() {
+        Ok(())
+    } else {
+        Err(anyhow!("unknown IssueFilter field(s): {}", dropped.join(", ")))
+    }
+}
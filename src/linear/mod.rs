@@ -1,6 +1,14 @@
 pub mod client;
 
+pub mod cache;
+pub use cache::LinearCache;
+
 pub mod config;
 pub use config::LinearConfig;
 
+pub mod scheduler;
+pub use scheduler::RateLimitHandle;
+
 pub mod schema;
+
+pub mod filter;
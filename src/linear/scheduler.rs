@@ -0,0 +1,206 @@
+// Paces the requests of a LinearClient to its API key's rate limits:
+// caps concurrent requests, waits for an exhausted quota to reset,
+// and retries rate limited & transient failures with exponential backoff
+
+use std::future::Future;
+use std::sync::{ Arc, Mutex };
+use std::time::Duration;
+
+use chrono::{ DateTime, Local, TimeZone, Utc };
+use reqwest::header::HeaderMap;
+use tokio::sync::Semaphore;
+
+use crate::error::{ ClientError, ClientResult };
+
+const MAX_CONCURRENT_REQUESTS: usize = 4;
+const MAX_RETRIES: u32 = 4;
+const INITIAL_BACKOFF: Duration = Duration::from_millis(500);
+
+// Requests that would have to wait longer than this fail instead, rather than stall their View Panel
+const MAX_WAIT: Duration = Duration::from_secs(30);
+
+// Remaining quota, from the headers of the most recent response
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RateLimitQuota {
+    pub requests_limit: Option<u64>,
+    pub requests_remaining: Option<u64>,
+    pub requests_reset: Option<DateTime<Utc>>,
+
+    pub complexity_limit: Option<u64>,
+    pub complexity_remaining: Option<u64>,
+    pub complexity_reset: Option<DateTime<Utc>>,
+
+    // Set while a request waits to be retried
+    pub retry_at: Option<DateTime<Utc>>,
+}
+
+impl RateLimitQuota {
+
+    fn header_value<T: std::str::FromStr>(headers: &HeaderMap, name: &str) -> Option<T> {
+        headers.get(name)?.to_str().ok()?.trim().parse().ok()
+    }
+
+    // Reset headers are UTC epoch milliseconds
+    fn header_time(headers: &HeaderMap, name: &str) -> Option<DateTime<Utc>> {
+        Utc.timestamp_millis_opt(RateLimitQuota::header_value(headers, name)?).single()
+    }
+
+    // Responses without rate limit headers leave the quota as is
+    pub fn update(&mut self, headers: &HeaderMap) {
+        if let Some(remaining) = RateLimitQuota::header_value(headers, "x-ratelimit-requests-remaining") {
+            self.requests_remaining = Some(remaining);
+            self.requests_limit = RateLimitQuota::header_value(headers, "x-ratelimit-requests-limit");
+            self.requests_reset = RateLimitQuota::header_time(headers, "x-ratelimit-requests-reset");
+        }
+        if let Some(remaining) = RateLimitQuota::header_value(headers, "x-ratelimit-complexity-remaining") {
+            self.complexity_remaining = Some(remaining);
+            self.complexity_limit = RateLimitQuota::header_value(headers, "x-ratelimit-complexity-limit");
+            self.complexity_reset = RateLimitQuota::header_time(headers, "x-ratelimit-complexity-reset");
+        }
+    }
+
+    // Returns:
+    //     the time the quota resets at, if it's used up & hasn't reset yet
+    pub fn exhausted_until(&self) -> Option<DateTime<Utc>> {
+        let now = Utc::now();
+        let requests = self.requests_reset.filter(|_| self.requests_remaining == Some(0));
+        let complexity = self.complexity_reset.filter(|_| self.complexity_remaining == Some(0));
+
+        requests.into_iter().chain(complexity).filter(|reset| *reset > now).max()
+    }
+
+    // e.g. "API: 1423/1500 requests, 245000/250000 complexity", None before any response with rate limit headers
+    pub fn summary(&self) -> Option<String> {
+        let mut parts: Vec<String> = Vec::new();
+
+        if let (Some(remaining), Some(limit)) = (self.requests_remaining, self.requests_limit) {
+            parts.push(format!("{}/{} requests", remaining, limit));
+        }
+        if let (Some(remaining), Some(limit)) = (self.complexity_remaining, self.complexity_limit) {
+            parts.push(format!("{}/{} complexity", remaining, limit));
+        }
+
+        if let Some(reset) = self.exhausted_until() {
+            parts.push(format!("resets at {}", reset.with_timezone(&Local).format("%H:%M:%S")));
+        } else if let Some(retry_at) = self.retry_at.filter(|x| *x > Utc::now()) {
+            parts.push(format!("retrying in {}s", (retry_at - Utc::now()).num_seconds() + 1));
+        }
+
+        if parts.is_empty() {
+            None
+        } else {
+            Some(format!("API: {}", parts.join(", ")))
+        }
+    }
+}
+
+// Accepts:
+//     attempt: number of failed attempts before this one
+//     backoff: wait before retrying a transient failure
+//     reset_wait: time until the exhausted quota resets, if known
+// Returns:
+//     how long to wait before retrying the failed request, None if it fails instead
+pub fn retry_wait(err: &ClientError, idempotent: bool, attempt: u32, backoff: Duration, reset_wait: Option<Duration>) -> Option<Duration> {
+    let rate_limited = matches!(err, ClientError::RateLimit(_));
+    if attempt >= MAX_RETRIES || !(rate_limited || (idempotent && err.is_transient())) {
+        return None;
+    }
+
+    // Rate limited requests wait until the quota resets, if the response said when
+    let wait = match reset_wait {
+        Some(reset_wait) if rate_limited => reset_wait.max(backoff),
+        _ => backoff,
+    };
+    Some(wait).filter(|wait| *wait <= MAX_WAIT)
+}
+
+// Shared with the UI, which can't lock a client while its requests are in flight
+pub type RateLimitHandle = Arc<Mutex<RateLimitQuota>>;
+
+#[derive(Debug, Clone)]
+pub struct RequestScheduler {
+    permits: Arc<Semaphore>,
+    pub quota: RateLimitHandle,
+}
+
+impl RequestScheduler {
+
+    pub fn new() -> RequestScheduler {
+        RequestScheduler {
+            permits: Arc::new(Semaphore::new(MAX_CONCURRENT_REQUESTS)),
+            quota: Arc::new(Mutex::new(RateLimitQuota::default())),
+        }
+    }
+
+    pub fn record(&self, headers: &HeaderMap) {
+        self.quota.lock().unwrap().update(headers);
+    }
+
+    // Wait for an exhausted quota to reset, fails if it resets too far out
+    async fn wait_for_quota(&self) -> ClientResult<()> {
+        let reset = match self.quota.lock().unwrap().exhausted_until() {
+            Some(x) => x,
+            None => return Ok(()),
+        };
+
+        let wait = (reset - Utc::now()).to_std().unwrap_or_default();
+        if wait > MAX_WAIT {
+            return Err(ClientError::RateLimit(format!(
+                "quota exhausted until {}",
+                reset.with_timezone(&Local).format("%H:%M:%S")
+            )));
+        }
+
+        self.quota.lock().unwrap().retry_at = Some(reset);
+        tokio::time::sleep(wait).await;
+        Ok(())
+    }
+
+    // Accepts:
+    //     idempotent: whether the request may be repeated after a transient failure,
+    //         false for mutations, which are only retried when rate limited (i.e. not applied)
+    //     request: sends one attempt of the request
+    pub async fn run<T, F, Fut>(&self, idempotent: bool, mut request: F) -> ClientResult<T>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = ClientResult<T>>,
+    {
+        let mut backoff = INITIAL_BACKOFF;
+        let mut attempt: u32 = 0;
+
+        loop {
+            self.wait_for_quota().await?;
+
+            let res = {
+                let _permit = self.permits.acquire().await
+                    .map_err(|err| ClientError::Network(err.to_string()))?;
+                request().await
+            };
+
+            let err = match res {
+                Err(err) => err,
+                res => return res,
+            };
+
+            let reset_wait = self.quota.lock().unwrap().exhausted_until()
+                .and_then(|reset| (reset - Utc::now()).to_std().ok());
+            let wait = match retry_wait(&err, idempotent, attempt, backoff, reset_wait) {
+                Some(wait) => wait,
+                None => return Err(err),
+            };
+
+            attempt += 1;
+            warn!("RequestScheduler::run() - attempt {} failed, retrying in {:?}: {}", attempt, wait, err);
+
+            self.quota.lock().unwrap().retry_at = chrono::Duration::from_std(wait).ok().map(|x| Utc::now() + x);
+            tokio::time::sleep(wait).await;
+            backoff *= 2;
+        }
+    }
+}
+
+impl Default for RequestScheduler {
+    fn default() -> RequestScheduler {
+        RequestScheduler::new()
+    }
+}
@@ -103,14 +103,142 @@ pub type State = states_query::StatesQueryWorkflowStatesNodes;
 
 
 
+// Fetch Teams
 #[derive(GraphQLQuery)]
 #[graphql(
     schema_path = "gql/linear/linear_schema.json",
-    query_path = "gql/linear/issue_update.graphql",
+    query_path = "gql/linear/teams.graphql",
+    response_derives = "Debug,Clone,Serialize,Default",
+    variables_derives = "Debug",
+    skip_serializing_none,
+)]
+pub struct TeamsQuery;
+
+pub type TeamsVariables = teams_query::Variables;
+pub type TeamsResponseData = teams_query::ResponseData;
+pub type Team = teams_query::TeamsQueryTeamsNodes;
+
+
+// Team Estimate Scale
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "gql/linear/linear_schema.json",
+    query_path = "gql/linear/team_estimation.graphql",
+    response_derives = "Debug,Clone,Serialize,Default",
+    variables_derives = "Debug",
+    skip_serializing_none,
+)]
+pub struct TeamEstimationQuery;
+
+pub type TeamEstimationVariables = team_estimation_query::Variables;
+pub type TeamEstimationResponseData = team_estimation_query::ResponseData;
+
+
+// Labels by Team (includes workspace labels)
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "gql/linear/linear_schema.json",
+    query_path = "gql/linear/team_labels.graphql",
+    response_derives = "Debug,Clone,Serialize,Default",
+    variables_derives = "Debug,Deserialize",
+    skip_serializing_none,
+)]
+pub struct TeamLabelsQuery;
+
+pub type TeamLabelsVariables = team_labels_query::Variables;
+pub type TeamLabelsResponseData = team_labels_query::ResponseData;
+pub type Label = team_labels_query::TeamLabelsQueryIssueLabelsNodes;
+pub type LabelFilter = team_labels_query::IssueLabelFilter;
+
+
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "gql/linear/linear_schema.json",
+    query_path = "gql/linear/issue_create.graphql",
+    response_derives = "Debug,Clone,Serialize,Default",
+    variables_derives = "Debug",
+    skip_serializing_none,
+)]
+pub struct IssueCreateMut;
+
+pub type IssueCreateVariables = issue_create_mut::Variables;
+pub type IssueCreateResponseData = issue_create_mut::ResponseData;
+pub type IssueCreateInput = issue_create_mut::IssueCreateInput;
+
+
+// Issue Search, selects the same Issue fields as IssuesQuery
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "gql/linear/linear_schema.json",
+    query_path = "gql/linear/issue_search.graphql",
+    response_derives = "Debug,Clone,Serialize,Default",
+    variables_derives = "Debug",
+    skip_serializing_none,
+)]
+pub struct IssueSearchQuery;
+
+pub type IssueSearchVariables = issue_search_query::Variables;
+pub type IssueSearchResponseData = issue_search_query::ResponseData;
+
+
+
+// Issue Description, used to detect remote changes while editing
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "gql/linear/linear_schema.json",
+    query_path = "gql/linear/issue_description.graphql",
+    response_derives = "Debug,Clone,Serialize,Default",
+    variables_derives = "Debug",
+    skip_serializing_none,
+)]
+pub struct IssueDescriptionQuery;
+
+pub type IssueDescriptionVariables = issue_description_query::Variables;
+pub type IssueDescriptionResponseData = issue_description_query::ResponseData;
+
+
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "gql/linear/linear_schema.json",
+    query_path = "gql/linear/issue_comments.graphql",
+    response_derives = "Debug,Clone,Serialize,Default",
+    variables_derives = "Debug",
+    skip_serializing_none,
+)]
+pub struct IssueCommentsQuery;
+
+pub type IssueCommentsVariables = issue_comments_query::Variables;
+pub type IssueCommentsResponseData = issue_comments_query::ResponseData;
+pub type Comment = issue_comments_query::IssueCommentsQueryIssueCommentsNodes;
+
+
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "gql/linear/linear_schema.json",
+    query_path = "gql/linear/comment_create.graphql",
     response_derives = "Debug,Clone,Serialize,Default",
     variables_derives = "Debug",
     skip_serializing_none,
 )]
+pub struct CommentCreateMut;
+
+pub type CommentCreateVariables = comment_create_mut::Variables;
+pub type CommentCreateResponseData = comment_create_mut::ResponseData;
+pub type CommentCreateInput = comment_create_mut::CommentCreateInput;
+
+
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "gql/linear/linear_schema.json",
+    query_path = "gql/linear/issue_update.graphql",
+    response_derives = "Debug,Clone,Serialize,Default",
+    variables_derives = "Debug,Default",
+    skip_serializing_none,
+)]
 pub struct IssueUpdateMut;
 
 pub type IssueUpdateVariables = issue_update_mut::Variables;
@@ -132,4 +260,45 @@ pub struct IssuesQuery;
 pub type IssuesVariables = issues_query::Variables;
 pub type IssuesResponseData = issues_query::ResponseData;
 pub type Issue = issues_query::IssuesQueryIssuesNodes;
-pub type IssueFilter = issues_query::IssueFilter;
\ No newline at end of file
+pub type IssueFilter = issues_query::IssueFilter;
+
+// An Issue's labels, as patched after a modification
+pub type IssueLabelConnection = issues_query::IssuesQueryIssuesNodesLabels;
+pub type IssueLabel = issues_query::IssuesQueryIssuesNodesLabelsNodes;
+
+impl From<Label> for IssueLabel {
+    fn from(label: Label) -> Self {
+        IssueLabel { id: label.id, name: label.name, color: label.color }
+    }
+}
+
+// An Issue's team-scoped fields after a team move, as returned by issueUpdate
+impl From<issue_update_mut::IssueUpdateMutIssueUpdateIssueTeam> for issues_query::IssuesQueryIssuesNodesTeam {
+    fn from(team: issue_update_mut::IssueUpdateMutIssueUpdateIssueTeam) -> Self {
+        issues_query::IssuesQueryIssuesNodesTeam { id: team.id, name: team.name }
+    }
+}
+
+impl From<issue_update_mut::IssueUpdateMutIssueUpdateIssueState> for issues_query::IssuesQueryIssuesNodesState {
+    fn from(state: issue_update_mut::IssueUpdateMutIssueUpdateIssueState) -> Self {
+        issues_query::IssuesQueryIssuesNodesState {
+            id: state.id,
+            name: state.name,
+            type_: state.type_,
+            description: state.description,
+            color: state.color,
+        }
+    }
+}
+
+impl From<issue_update_mut::IssueUpdateMutIssueUpdateIssueCycle> for issues_query::IssuesQueryIssuesNodesCycle {
+    fn from(cycle: issue_update_mut::IssueUpdateMutIssueUpdateIssueCycle) -> Self {
+        issues_query::IssuesQueryIssuesNodesCycle { id: cycle.id, name: cycle.name, description: cycle.description }
+    }
+}
+
+impl From<issue_update_mut::IssueUpdateMutIssueUpdateIssueProject> for issues_query::IssuesQueryIssuesNodesProject {
+    fn from(project: issue_update_mut::IssueUpdateMutIssueUpdateIssueProject) -> Self {
+        issues_query::IssuesQueryIssuesNodesProject { id: project.id, name: project.name, color: project.color }
+    }
+}
\ No newline at end of file
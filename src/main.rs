@@ -10,15 +10,24 @@ extern crate log;
 
 use std::io;
 use std::fs;
-use std::sync::{Arc, atomic::{Ordering}};
+use std::env;
+use std::time::Duration;
+use std::sync::{ Arc, atomic::Ordering };
 
 mod app;
 mod linear;
+mod github;
+mod backend;
 mod ui;
 mod constants;
 mod command;
 mod util;
 mod components;
+mod config;
+mod error;
+mod cli;
+mod subcommand;
+mod webhook;
 
 use crate::components::{
     InputComponent,
@@ -27,20 +36,24 @@ use crate::components::{
 use crate::linear::{
     client::{LinearClient},
     config::LinearConfig,
+    LinearCache,
 };
 
-use app::{ Route, InputMode };
+use crate::config::ConfigFile;
+use crate::cli::Opts;
+
+use clap::Clap;
+
+use app::{ Route, InputMode, Platform, AppEvent };
 
 
 extern crate dotenv;
 use dotenv::dotenv;
 
-use tokio::{sync::Mutex as tMutex,};
-
 // use termion::{input::MouseTerminal, raw::IntoRawMode, screen::AlternateScreen};
 // use tui::{ backend::TermionBackend, Terminal, };
 use crossterm::{
-    event::{ DisableMouseCapture, EnableMouseCapture },
+    event::{ DisableMouseCapture, EnableMouseCapture, KeyCode },
     execute,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
@@ -50,7 +63,9 @@ use tui::{ backend::CrosstermBackend, Terminal };
 
 use util::{
     // event::{Event, Events},
-    event_crossterm::{Event, Events},
+    event_crossterm::{Event, Events, Config as EventConfig},
+    keybindings::{ self, display_key },
+    theme,
     loader::{ LOADER_STATE_MAX },
 };
 
@@ -75,17 +90,33 @@ use command::{ Command,
 
                 exec_delete_cmd,
                 exec_select_view_panel_cmd,
+                exec_open_panel_jump_prompt_cmd,
+                exec_toggle_zoom_panel_cmd,
+                exec_open_panel_export_prompt_cmd,
 
                 exec_refresh_view_panel_cmd,
+                exec_order_view_panel_cmd,
                 exec_expand_issue_cmd,
 
                 exec_select_dashboard_view_list_cmd,
                 exec_select_custom_view_select_cmd,
+                exec_open_dashboard_name_entry_cmd,
+                exec_switch_dashboard_cmd,
+                exec_switch_profile_cmd,
+                exec_toggle_error_log_cmd,
+                exec_undo_issue_modification_cmd,
+                exec_toggle_issue_history_cmd,
                 exec_open_issue_op_interface_cmd,
+                exec_open_issue_create_form_cmd,
+                exec_toggle_issue_op_selection_cmd,
+                exec_open_issue_search_cmd,
+                exec_edit_issue_description_cmd,
                 exec_move_back_cmd,
                 exec_confirm_cmd,
                 exec_scroll_down_cmd,
                 exec_scroll_up_cmd,
+                exec_scroll_comments_down_cmd,
+                exec_scroll_comments_up_cmd,
 };
 
 
@@ -102,6 +133,33 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     dotenv().ok();
 
+    let mut opts = Opts::parse();
+
+    // Load config.toml before entering the alternate screen, so errors stay visible
+    let config_file = match ConfigFile::load() {
+        Ok(config_file) => config_file,
+        Err(errors) => {
+            for err in errors.iter() {
+                eprintln!("{}", err);
+            }
+            std::process::exit(1);
+        }
+    };
+
+    keybindings::set_keybindings(&config_file.keybindings.clone().into_iter().collect());
+    theme::set_theme(&config_file.theme.clone().into_iter().collect());
+
+    // Subcommands print their result & exit without entering the alternate screen
+    if let Some(subcmd) = opts.subcmd.take() {
+        let profile = opts.profile.clone().unwrap_or_else(LinearConfig::read_active_profile);
+        if let Err(err) = subcommand::run(subcmd, &profile, opts.json).await {
+            eprintln!("{:#}", err);
+            std::process::exit(1);
+        }
+        return Ok(());
+    }
+
+    // Only the TUI logs to rust_cli.log, subcommands may be run from any directory
     let log_remove_result = fs::remove_file("rust_cli.log");
 
     match log_remove_result {
@@ -116,21 +174,68 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     WriteLogger::init(LevelFilter::Debug, Config::default(), File::create("rust_cli.log").unwrap()).unwrap();
 
-    // Create default app state
-    let mut app = app::App::default();
+    // --dashboard must name an existing dashboard
+    if let Some(name) = &opts.dashboard {
+        let dashboard_names = LinearConfig::read_dashboard_names();
+        if !dashboard_names.contains(name) {
+            eprintln!("Unknown dashboard '{}', available: {}", name, dashboard_names.join(", "));
+            std::process::exit(1);
+        }
+    }
+
+    // Open local cache, the app continues without caching if unavailable
+    let linear_cache = match LinearCache::open() {
+        Ok(cache) => Some(cache),
+        Err(err) => {
+            error!("LinearCache::open() failed: {:?}", err);
+            None
+        }
+    };
 
+    // Create default app state
+    let mut app = app::App { linear_cache, ..app::App::default() };
+    app.dashboard_name = opts.dashboard.unwrap_or_else(LinearConfig::read_active_dashboard);
+
+    // A profile without a saved token, e.g. an unknown --profile, is created through access token entry
+    app.profile_name = opts.profile.unwrap_or_else(LinearConfig::read_active_profile);
+    app.profile_names = LinearConfig::read_profile_names();
+    if !app.profile_names.contains(&app.profile_name) {
+        app.profile_names.push(app.profile_name.clone());
+    }
+    *app.profile_viewers.lock().unwrap() = LinearConfig::read_profile_viewers();
+    app.profile_platforms = LinearConfig::read_profile_platforms();
+
+    // GitHub profiles are only configured in config.toml, access token entry is for Linear
+    if app.profile_platform(&app.profile_name) == Platform::Github {
+        match backend::connect(&app.profile_name, app.linear_cache.clone()) {
+            Some(client) => {
+                app.input_mode = InputMode::Normal;
+                let profile = app.profile_name.clone();
+                app.backend = app.insert_profile_backend(&profile, Some(client));
+                app.change_route(Route::ActionSelect);
+            },
+            None => {
+                eprintln!("Profile '{}' has no valid GitHub token", app.profile_name);
+                std::process::exit(1);
+            }
+        }
+    }
     // Attempt to load access token, if successful bypass access token entry route
-    {
+    else {
         // Access Token found, continue
-        match LinearConfig::load_config() {
+        match LinearConfig::load_profile(&app.profile_name) {
             Some(config) => {
                 // with_config() can return Err() if token file contains non visible ASCII chars (32-127)
                 match LinearClient::with_config(config) {
-                    Ok(client) => {
+                    Ok(mut client) => {
+                        client.cache = app.linear_cache.clone();
+                        app.due_soon_day_threshold = client.config.due_soon_day_threshold;
+
                         *app.viewer_obj_render.lock().unwrap() = client.config.viewer_object.clone();
 
                         app.input_mode = InputMode::Normal;
-                        app.linear_client = Arc::new(tMutex::new(Some(client)));
+                        let profile = app.profile_name.clone();
+                        app.backend = app.insert_profile_backend(&profile, Some(Arc::new(client)));
                         app.change_route(Route::ActionSelect);
                     },
                     Err(_) => {
@@ -161,7 +266,25 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let backend = CrosstermBackend::new(stdout);
     let mut terminal = Terminal::new(backend)?;
 
-    let mut events = Events::new();
+    // TICK_RATE env var takes precedence over [app] tick_rate_ms
+    let mut event_config = EventConfig { exit_key: KeyCode::Char(display_key('q')), ..EventConfig::default() };
+    if let (Err(_), Some(tick_rate_ms)) = (env::var("TICK_RATE"), config_file.app.tick_rate_ms) {
+        event_config.tick_rate = Duration::from_millis(tick_rate_ms);
+    }
+    let mut events = Events::with_config(event_config);
+
+    // Accept Linear webhooks, if configured
+    if let Some(addr) = &config_file.app.webhook_listen {
+        match tokio::net::TcpListener::bind(addr).await {
+            Ok(listener) => {
+                app.webhook_addr = listener.local_addr().ok();
+                tokio::spawn(webhook::serve(listener, config_file.app.webhook_secret.clone(), app.webhook_events.clone()));
+            },
+            Err(err) => {
+                app.notification_center.notifications.error(&format!("Listening for webhooks on {}", addr), &err);
+            }
+        }
+    }
 
     terminal.clear()?;
 
@@ -181,8 +304,12 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 },
                 Route::DashboardViewDisplay => {
                     ui::draw_dashboard_view_config(f, &mut app);
+                },
+                Route::IssueSearch => {
+                    ui::draw_issue_search(f, &mut app);
                 }
             };
+            ui::draw_notifications(f, &mut app);
         })?;
 
         // Change Route if some other task changed it
@@ -252,6 +379,15 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                             // linear_dashboard_view_panel_selected
                             exec_select_view_panel_cmd(&mut app, idx);
                         },
+                        Command::OpenPanelJumpPrompt => {
+                            exec_open_panel_jump_prompt_cmd(&mut app, &mut events);
+                        },
+                        Command::ToggleZoomPanel => {
+                            exec_toggle_zoom_panel_cmd(&mut app);
+                        },
+                        Command::OpenPanelExportPrompt => {
+                            exec_open_panel_export_prompt_cmd(&mut app, &mut events);
+                        },
 
                         Command::RefreshViewPanel => {
                             exec_refresh_view_panel_cmd(&mut app);
@@ -259,6 +395,12 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                         Command::ExpandIssue => {
                             exec_expand_issue_cmd(&mut app);
                         },
+                        Command::SortViewPanel => {
+                            exec_order_view_panel_cmd(&mut app, true);
+                        },
+                        Command::GroupViewPanel => {
+                            exec_order_view_panel_cmd(&mut app, false);
+                        },
 
                         Command::SelectDashboardViewList => {
                             exec_select_dashboard_view_list_cmd(&mut app);
@@ -266,14 +408,47 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                         Command::SelectCustomViewSelect => {
                             exec_select_custom_view_select_cmd(&mut app);
                         },
+                        Command::OpenDashboardNameEntry(op) => {
+                            exec_open_dashboard_name_entry_cmd(&mut app, &mut events, op);
+                        },
+                        Command::SwitchDashboard(forward) => {
+                            exec_switch_dashboard_cmd(&mut app, forward);
+                        },
+                        Command::SwitchProfile => {
+                            exec_switch_profile_cmd(&mut app);
+                        },
+                        Command::ToggleErrorLog => {
+                            exec_toggle_error_log_cmd(&mut app);
+                        },
+                        Command::UndoIssueModification => {
+                            exec_undo_issue_modification_cmd(&mut app, true);
+                        },
+                        Command::RedoIssueModification => {
+                            exec_undo_issue_modification_cmd(&mut app, false);
+                        },
+                        Command::ToggleIssueHistory => {
+                            exec_toggle_issue_history_cmd(&mut app);
+                        },
                         Command::OpenIssueOpInterface(x) => {
                             exec_open_issue_op_interface_cmd(&mut app, x);
                         },
+                        Command::OpenIssueCreateForm => {
+                            exec_open_issue_create_form_cmd(&mut app);
+                        },
+                        Command::ToggleIssueOpSelection => {
+                            exec_toggle_issue_op_selection_cmd(&mut app);
+                        },
+                        Command::OpenIssueSearch => {
+                            exec_open_issue_search_cmd(&mut app, &mut events);
+                        },
+                        Command::EditIssueDescription => {
+                            exec_edit_issue_description_cmd(&mut app, &mut terminal, &mut events).await;
+                        },
                         Command::MoveBack => {
                             exec_move_back_cmd(&mut app);
                         },
                         Command::Confirm => {
-                            exec_confirm_cmd(&mut app).await;
+                            exec_confirm_cmd(&mut app, &mut events).await;
                         },
                         Command::ScrollDown => {
                             exec_scroll_down_cmd(&mut app);
@@ -281,6 +456,12 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                         Command::ScrollUp => {
                             exec_scroll_up_cmd(&mut app);
                         },
+                        Command::ScrollCommentsDown => {
+                            exec_scroll_comments_down_cmd(&mut app);
+                        },
+                        Command::ScrollCommentsUp => {
+                            exec_scroll_comments_up_cmd(&mut app);
+                        },
                     };
                 }
                 else {
@@ -301,6 +482,10 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 if app.scroll_tick == ( SCROLL_TICK_MAX-1 ) { app.scroll_tick = 0; }
                 else { app.scroll_tick += 1; }
 
+                // Merge finished refreshes & webhook changes, then start refreshes that are due
+                app.dispatch_event(AppEvent::ApplyWebhookEvents);
+                app.dispatch_event(AppEvent::AutoRefreshViewPanels);
+
                 // avoid overflow
                 if tick_idx < 100 {
                     tick_idx += 1;
@@ -0,0 +1,335 @@
+// Non-interactive subcommands, printing a table or JSON to stdout
+
+use anyhow::{ anyhow, Result };
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::app::Platform;
+
+use crate::cli::{
+    SubCommand,
+    IssuesOpts,
+    IssueSubCommand,
+    IssueUpdateOpts,
+    ViewsSubCommand,
+};
+
+use crate::constants::{
+    ISSUE_PRIORITY_LABELS,
+    view_panel_columns::{ ViewPanelColumn, VIEW_PANEL_COLUMN_REGISTRY, lookup_view_panel_column },
+};
+
+use crate::linear::{
+    client::LinearClient,
+    config::LinearConfig,
+    schema::{ CustomView, Issue, IssueFilter, IssueUpdateInput, State, TeamMember },
+};
+
+use crate::util::{
+    GraphQLCursor,
+    str::set_str_end_as_ellipsis,
+};
+
+// Columns of `issues` tables, when --columns isn't passed
+const DEFAULT_ISSUES_COLUMN_IDS: [&str; 6] = ["number", "team", "title", "state", "assignee", "priority"];
+
+// Longer table cells are cut off with an ellipsis
+const MAX_CELL_WIDTH: usize = 48;
+
+fn cursor_after(has_next_page: bool, end_cursor: Option<String>) -> GraphQLCursor {
+    GraphQLCursor { platform: Platform::Linear, has_next_page, end_cursor }
+}
+
+// The local cache isn't opened, a running dashboard holds its lock & scripts expect current data
+fn connect(profile: &str) -> Result<LinearClient> {
+    if LinearConfig::read_profile_platforms().get(profile) == Some(&Platform::Github) {
+        return Err(anyhow!("Profile '{}' is a GitHub profile, subcommands only support Linear profiles", profile));
+    }
+
+    let config = LinearConfig::load_profile(profile)
+        .ok_or_else(|| anyhow!("Profile '{}' has no access token, run without a subcommand to add one", profile))?;
+    Ok(LinearClient::with_config(config)?)
+}
+
+pub async fn run(subcmd: SubCommand, profile: &str, json: bool) -> Result<()> {
+    let client = connect(profile)?;
+
+    match subcmd {
+        SubCommand::Issues(opts) => exec_issues(&client, opts, json).await,
+        SubCommand::Issue(opts) => match opts.subcmd {
+            IssueSubCommand::Show(show_opts) => {
+                let issue = issue_by_identifier(&client, &show_opts.identifier).await?;
+                print_issue(&show_opts.identifier, &issue, json)
+            },
+            IssueSubCommand::Update(update_opts) => exec_issue_update(&client, update_opts, json).await,
+        },
+        SubCommand::Views(opts) => match opts.subcmd {
+            ViewsSubCommand::List => {
+                let views = all_custom_views(&client).await?;
+                print_views(&views, json)
+            },
+        },
+    }
+}
+
+async fn exec_issues(client: &LinearClient, opts: IssuesOpts, json: bool) -> Result<()> {
+    let columns: Vec<&'static ViewPanelColumn> = match &opts.columns {
+        Some(ids) => ids.split(',')
+            .map(|id| lookup_view_panel_column(id.trim()).ok_or_else(|| anyhow!("Unknown column '{}'", id.trim())))
+            .collect::<Result<_>>()?,
+        None => DEFAULT_ISSUES_COLUMN_IDS.iter().filter_map(|id| lookup_view_panel_column(id)).collect(),
+    };
+
+    let view = find_view(client, &opts.view).await?;
+    let issues = all_view_issues(client, &view).await?;
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&issues)?);
+        return Ok(());
+    }
+
+    let headers: Vec<String> = columns.iter().map(|column| column.column.label.to_string()).collect();
+    let rows: Vec<Vec<String>> = issues.iter()
+        .map(|issue| columns.iter().map(|column| (column.cell_value)(issue)).collect())
+        .collect();
+
+    print_table(&headers, &rows);
+    Ok(())
+}
+
+async fn exec_issue_update(client: &LinearClient, opts: IssueUpdateOpts, json: bool) -> Result<()> {
+    let issue = issue_by_identifier(client, &opts.identifier).await?;
+
+    let mut issue_update = IssueUpdateInput {
+        title: opts.title.clone(),
+        ..IssueUpdateInput::default()
+    };
+
+    if let Some(state_name) = &opts.state {
+        let states = all_team_states(client, &issue.team.id).await?;
+        let state = states.iter()
+            .find(|state| state.name.eq_ignore_ascii_case(state_name))
+            .ok_or_else(|| anyhow!("Unknown state '{}', available: {}", state_name,
+                states.iter().map(|state| state.name.as_str()).collect::<Vec<&str>>().join(", ")))?;
+        issue_update.state_id = Some(state.id.clone());
+    }
+
+    if let Some(assignee) = &opts.assignee {
+        issue_update.assignee_id = Some(resolve_assignee(client, &issue.team.id, assignee).await?);
+    }
+
+    if let Some(priority) = &opts.priority {
+        issue_update.priority = Some(parse_priority(priority)?);
+    }
+
+    if issue_update.title.is_none() && issue_update.state_id.is_none() && issue_update.assignee_id.is_none() && issue_update.priority.is_none() {
+        return Err(anyhow!("Nothing to update, pass at least one of --state, --assignee, --priority, --title"));
+    }
+
+    let resp = client.update_issue(&issue.id, issue_update).await?
+        .ok_or_else(|| anyhow!("Issue update returned no data"))?;
+    if !resp.issue_update.success {
+        return Err(anyhow!("Issue update of '{}' was not successful", opts.identifier));
+    }
+
+    // Fetch again to print all updated fields
+    let updated_issue = issue_by_identifier(client, &opts.identifier).await?;
+    print_issue(&opts.identifier, &updated_issue, json)
+}
+
+// Accepts:
+//     identifier - team key & issue number, e.g. "ENG-123"
+async fn issue_by_identifier(client: &LinearClient, identifier: &str) -> Result<Issue> {
+    let (team_key, number) = identifier.rsplit_once('-')
+        .and_then(|(team_key, number)| Some((team_key, number.parse::<i64>().ok()?)))
+        .ok_or_else(|| anyhow!("Invalid issue identifier '{}', expected e.g. ENG-123", identifier))?;
+
+    let filter: IssueFilter = serde_json::from_value(serde_json::json!({
+        "team": { "key": { "eq": team_key.to_uppercase() } },
+        "number": { "eq": number },
+    }))?;
+
+    client.issues(filter, None).await?
+        .and_then(|data| data.issues.nodes.into_iter().next())
+        .ok_or_else(|| anyhow!("Issue '{}' not found", identifier))
+}
+
+// Custom View by id, or case-insensitive name
+async fn find_view(client: &LinearClient, name: &str) -> Result<CustomView> {
+    let views = all_custom_views(client).await?;
+
+    if let Some(view) = views.iter().find(|view| view.id == name || view.name.eq_ignore_ascii_case(name)) {
+        return Ok(view.clone());
+    }
+
+    Err(anyhow!("Unknown view '{}', available: {}", name,
+        views.iter().map(|view| view.name.as_str()).collect::<Vec<&str>>().join(", ")))
+}
+
+async fn all_custom_views(client: &LinearClient) -> Result<Vec<CustomView>> {
+    let mut views = Vec::new();
+    let mut cursor: Option<GraphQLCursor> = None;
+
+    loop {
+        let data = client.custom_views(cursor).await?
+            .ok_or_else(|| anyhow!("Custom Views request returned no data"))?;
+        views.extend(data.custom_views.nodes);
+
+        let page_info = data.custom_views.page_info;
+        if !page_info.has_next_page {
+            return Ok(views);
+        }
+        cursor = Some(cursor_after(page_info.has_next_page, page_info.end_cursor));
+    }
+}
+
+async fn all_view_issues(client: &LinearClient, view: &CustomView) -> Result<Vec<Issue>> {
+    let mut issues = Vec::new();
+    let mut cursor: Option<GraphQLCursor> = None;
+
+    loop {
+        let data = client.view_issues(view, cursor).await?
+            .ok_or_else(|| anyhow!("Issues request for view '{}' returned no data", view.name))?;
+        issues.extend(data.issues.nodes);
+
+        let page_info = data.issues.page_info;
+        if !page_info.has_next_page {
+            return Ok(issues);
+        }
+        cursor = Some(cursor_after(page_info.has_next_page, page_info.end_cursor));
+    }
+}
+
+async fn all_team_states(client: &LinearClient, team_id: &str) -> Result<Vec<State>> {
+    let mut states = Vec::new();
+    let mut cursor: Option<GraphQLCursor> = None;
+
+    loop {
+        let data = client.team_states(team_id, cursor).await?
+            .ok_or_else(|| anyhow!("Workflow States request returned no data"))?;
+        states.extend(data.workflow_states.nodes);
+
+        let page_info = data.workflow_states.page_info;
+        if !page_info.has_next_page {
+            return Ok(states);
+        }
+        cursor = Some(cursor_after(page_info.has_next_page, page_info.end_cursor));
+    }
+}
+
+async fn all_team_members(client: &LinearClient, team_id: &str) -> Result<Vec<TeamMember>> {
+    let mut members = Vec::new();
+    let mut cursor: Option<GraphQLCursor> = None;
+
+    loop {
+        let data = client.team_members(team_id, cursor).await?
+            .ok_or_else(|| anyhow!("Team Members request returned no data"))?;
+        members.extend(data.team.members.nodes);
+
+        let page_info = data.team.members.page_info;
+        if !page_info.has_next_page {
+            return Ok(members);
+        }
+        cursor = Some(cursor_after(page_info.has_next_page, page_info.end_cursor));
+    }
+}
+
+// "me" is the profile's viewer, otherwise a team member's name or display name
+async fn resolve_assignee(client: &LinearClient, team_id: &str, assignee: &str) -> Result<String> {
+    if assignee.eq_ignore_ascii_case("me") {
+        if let Some(viewer) = &client.config.viewer_object {
+            return Ok(viewer.id.clone());
+        }
+        return client.viewer().await?
+            .map(|data| data.viewer.id)
+            .ok_or_else(|| anyhow!("Viewer request returned no data"));
+    }
+
+    let members = all_team_members(client, team_id).await?;
+    members.iter()
+        .find(|member| member.display_name.eq_ignore_ascii_case(assignee) || member.name.eq_ignore_ascii_case(assignee))
+        .map(|member| member.id.clone())
+        .ok_or_else(|| anyhow!("Unknown assignee '{}', available: {}", assignee,
+            members.iter().map(|member| member.display_name.as_str()).collect::<Vec<&str>>().join(", ")))
+}
+
+fn parse_priority(priority: &str) -> Result<i64> {
+    if let Ok(value) = priority.parse::<i64>() {
+        if value >= 0 && (value as usize) < ISSUE_PRIORITY_LABELS.len() {
+            return Ok(value);
+        }
+    }
+
+    ISSUE_PRIORITY_LABELS.iter()
+        .position(|label| label.eq_ignore_ascii_case(priority))
+        .map(|value| value as i64)
+        .ok_or_else(|| anyhow!("Unknown priority '{}', available: {}", priority, ISSUE_PRIORITY_LABELS.join(", ")))
+}
+
+// All View Panel columns as "label: value" lines, followed by the full description
+fn print_issue(identifier: &str, issue: &Issue, json: bool) -> Result<()> {
+    if json {
+        println!("{}", serde_json::to_string_pretty(issue)?);
+        return Ok(());
+    }
+
+    println!("{}  {}", identifier.to_uppercase(), issue.title);
+    for column in VIEW_PANEL_COLUMN_REGISTRY.iter() {
+        if matches!(column.id, "number" | "title" | "description") {
+            continue;
+        }
+        println!("{:>10}: {}", column.column.label, (column.cell_value)(issue));
+    }
+
+    if let Some(description) = issue.description.as_deref().filter(|x| !x.trim().is_empty()) {
+        println!("\n{}", description);
+    }
+    Ok(())
+}
+
+fn print_views(views: &[CustomView], json: bool) -> Result<()> {
+    if json {
+        println!("{}", serde_json::to_string_pretty(views)?);
+        return Ok(());
+    }
+
+    let headers: Vec<String> = ["Name", "Team", "Id"].iter().map(|x| x.to_string()).collect();
+    let rows: Vec<Vec<String>> = views.iter()
+        .map(|view| vec![
+            view.name.clone(),
+            view.team.as_ref().map(|team| team.key.clone()).unwrap_or_default(),
+            view.id.clone(),
+        ])
+        .collect();
+
+    print_table(&headers, &rows);
+    Ok(())
+}
+
+// Left-aligned columns, separated by two spaces
+fn print_table(headers: &[String], rows: &[Vec<String>]) {
+    let cell = |value: &str| -> String {
+        let value = value.replace('\n', " ");
+        if value.graphemes(true).count() > MAX_CELL_WIDTH {
+            set_str_end_as_ellipsis(&value, MAX_CELL_WIDTH).unwrap_or_default()
+        } else {
+            value
+        }
+    };
+
+    let rows: Vec<Vec<String>> = std::iter::once(headers.to_vec())
+        .chain(rows.iter().cloned())
+        .map(|row| row.iter().map(|value| cell(value)).collect())
+        .collect();
+
+    let widths: Vec<usize> = (0..headers.len())
+        .map(|idx| rows.iter().map(|row| row[idx].graphemes(true).count()).max().unwrap_or(0))
+        .collect();
+
+    for row in rows.iter() {
+        let line: Vec<String> = row.iter()
+            .zip(widths.iter())
+            .map(|(value, width)| format!("{}{}", value, " ".repeat(width - value.graphemes(true).count())))
+            .collect();
+        println!("{}", line.join("  ").trim_end());
+    }
+}
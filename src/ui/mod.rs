@@ -1,9 +1,10 @@
 use std::sync::atomic::{ Ordering };
 
 use crate::app;
+use crate::backend;
 use crate::util;
 
-use app::{ App };
+use app::{ App, DescriptionEditState };
 
 use crate::components::{
     dashboard_view_config_display::DashboardViewConfigDisplay,
@@ -17,6 +18,7 @@ use crate::components::{
 use crate::util::{
     ui,
     ui::{ hex_str_from_style_color },
+    grid_layout::{ grid_layout, PanelSpan },
     table::{ TableStyle },
     dashboard::{fetch_selected_view_panel_issue, fetch_selected_view_panel_num},    
     layout::{ widths_from_rect },
@@ -24,8 +26,8 @@ use crate::util::{
 
 use crate::constants::{
     colors,
-    table_columns::{ DASHBOARD_VIEW_CONFIG_COLUMNS, CUSTOM_VIEW_SELECT_COLUMNS,
-        VIEW_PANEL_COLUMNS },
+    table_columns::{ DASHBOARD_VIEW_CONFIG_COLUMNS, CUSTOM_VIEW_SELECT_COLUMNS },
+    view_panel_columns::panel_table_columns,
     IssueModificationOp,
 };
 
@@ -78,6 +80,41 @@ where
         viewer_label.push_str(" - ");
         viewer_label.push_str(org_name);
 
+        // Only shown once there's more than one profile or dashboard
+        if app.profile_names.len() > 1 {
+            viewer_label.push_str(&format!(" - Profile: {}", app.profile_name));
+        }
+        if app.dashboard_names.len() > 1 {
+            viewer_label.push_str(&format!(" - Dashboard: {}", app.dashboard_name));
+        }
+
+        if let Some(cache) = &app.linear_cache {
+            if cache.is_offline() {
+                viewer_label.push_str(" - Offline (cached)");
+            }
+        }
+
+        if let Some(addr) = &app.webhook_addr {
+            viewer_label.push_str(&format!(" - Webhooks: {}", addr));
+        }
+
+        if let Some(rate_limit) = app.rate_limit_summary() {
+            viewer_label.push_str(&format!(" - {}", rate_limit));
+        }
+
+        match &app.description_edit_state {
+            DescriptionEditState::Null => {},
+            DescriptionEditState::Updated => {
+                viewer_label.push_str(" - Description updated");
+            },
+            DescriptionEditState::Conflict(path) => {
+                viewer_label.push_str(&format!(" - Description changed remotely, edit saved to {}", path.display()));
+            },
+            DescriptionEditState::Failed(err) => {
+                viewer_label.push_str(&format!(" - Description edit failed: {}", err));
+            },
+        }
+
         let viewer_block = Block::default()
         .borders(Borders::ALL)
         .border_style(Style::default());
@@ -89,6 +126,7 @@ where
         
         f.render_widget(viewer_p, chunks[0]);
     }
+    drop(viewer_obj_render_lock);
 
 
 
@@ -97,14 +135,8 @@ where
     // Render the View Panel Command Bar
 
     // Determine which Commands are allowed based on state of selection
-    let mut modify_title_cmd_active = false;
-    let mut modify_workflow_state_cmd_active = false;
-    let mut modify_assignee_cmd_active = false;
-    let mut modify_project_cmd_active = false;
-    let mut modify_cycle_cmd_active = false;
-    let mut expand_issue_cmd_active = false;
-
     let mut refresh_cmd_active = false;
+    let mut create_issue_cmd_active = false;
 
 
     // If a View Panel is selected && its not loading && issue is not expanded
@@ -117,30 +149,25 @@ where
             if !x.loading.load(Ordering::Relaxed) && app.issue_to_expand.is_none() {
                 refresh_cmd_active = true;
             }
+            if app.issue_to_expand.is_none() && !app.modifying_issue && !app.creating_issue {
+                create_issue_cmd_active = true;
+            }
         }
         drop(view_panel_list_lock);
     }
 
-    // If a View Panel Issue is selected && issue is not expanded, allow following commands
-    if fetch_selected_view_panel_issue(app).is_some() && app.issue_to_expand.is_none() {
-        modify_title_cmd_active = true;
-        modify_workflow_state_cmd_active = true;
-        modify_assignee_cmd_active = true;
-        modify_project_cmd_active = true;
-        modify_cycle_cmd_active = true;
-        expand_issue_cmd_active = true;
-    }
-
-    // Update Command statuses
-    app.view_panel_cmd_bar.set_modify_title_active(modify_title_cmd_active);
-    app.view_panel_cmd_bar.set_modify_workflow_state_active(modify_workflow_state_cmd_active);
-    app.view_panel_cmd_bar.set_modify_assignee_active(modify_assignee_cmd_active);
-    app.view_panel_cmd_bar.set_modify_project_active(modify_project_cmd_active);
-    app.view_panel_cmd_bar.set_modify_cycle_active(modify_cycle_cmd_active);
-    app.view_panel_cmd_bar.set_expand_issue_active(expand_issue_cmd_active);
-
+    set_issue_cmds_active(app);
 
     app.view_panel_cmd_bar.set_refresh_panel_active(refresh_cmd_active);
+    app.view_panel_cmd_bar.set_create_issue_active(create_issue_cmd_active);
+    app.view_panel_cmd_bar.set_order_panel_active(create_issue_cmd_active);
+
+    let panel_navigation_active = app.issue_to_expand.is_none() && !app.modifying_issue && !app.creating_issue;
+    app.view_panel_cmd_bar.set_zoom_panel_active(panel_navigation_active && app.linear_dashboard_view_panel_selected.is_some());
+    app.view_panel_cmd_bar.set_jump_to_panel_active(panel_navigation_active && !app.linear_dashboard_view_panel_list.lock().unwrap().is_empty());
+    app.view_panel_cmd_bar.set_export_panel_active(panel_navigation_active && app.linear_dashboard_view_panel_selected.is_some());
+    app.view_panel_cmd_bar.set_undo_modification_active(panel_navigation_active && app.issue_history_log.history.can_undo());
+    app.view_panel_cmd_bar.set_redo_modification_active(panel_navigation_active && app.issue_history_log.history.can_redo());
 
     // Render command bar
     if let Ok(cmd_items) = app.view_panel_cmd_bar.render() {
@@ -155,18 +182,49 @@ where
     let view_panel_handle = app.linear_dashboard_view_panel_list.lock().unwrap();
     let num_views = view_panel_handle.len();
 
-    let mut layout_rects = ui::view_layout(num_views, chunks[2]);
+    // With more than one profile, View Panels are labeled with their organization
+    let profile_viewers = app.profile_viewers.lock().unwrap().clone();
+
+    // A zoomed View Panel takes up the whole layout
+    let zoomed_idx: Option<usize> = match app.linear_dashboard_view_panel_selected {
+        Some(selected_idx) if app.view_panel_zoomed && selected_idx <= num_views => Some(selected_idx-1),
+        _ => None,
+    };
+
+    let layout_rects: Vec<Rect> = match (&app.linear_dashboard_layout, zoomed_idx) {
+        (_, Some(_)) => vec![chunks[2]],
+        (Some(grid), None) => {
+            let spans: Vec<PanelSpan> = view_panel_handle.iter()
+                .map(|e| app.linear_dashboard_panel_spans.get(&e.view.id).copied().unwrap_or_default())
+                .collect();
+            grid_layout(grid, &spans, chunks[2])
+        },
+        (None, None) => ui::view_layout(num_views, chunks[2]),
+    };
+    let mut layout_rects = layout_rects.into_iter();
 
     for (i, e) in view_panel_handle.iter().enumerate() {
+        if zoomed_idx.map(|idx| idx != i).unwrap_or(false) {
+            continue;
+        }
+
         let view_data_handle = e.issue_table_data.lock().unwrap();
 
         // Get bounding-box for view panel
-        let view_panel_rect = layout_rects.pop().unwrap();
+        let view_panel_rect = match layout_rects.next() {
+            Some(rect) => rect,
+            None => break,
+        };
+
+        // Too small to draw borders around any content, e.g. many overflow rows in a short terminal
+        if view_panel_rect.width < 3 || view_panel_rect.height < 3 {
+            continue;
+        }
 
         // subtract 2 from width to account for single character table borders
-        let view_panel_content_rect = Rect::new(view_panel_rect.x, view_panel_rect.y, view_panel_rect.width-2, view_panel_rect.height);
+        let view_panel_content_rect = Rect::new(view_panel_rect.x, view_panel_rect.y, view_panel_rect.width.saturating_sub(2), view_panel_rect.height);
 
-        let widths: Vec<Constraint> = widths_from_rect( &view_panel_content_rect, &*VIEW_PANEL_COLUMNS);
+        let widths: Vec<Constraint> = widths_from_rect( &view_panel_content_rect, &panel_table_columns(&e.columns));
 
 
         // Create TableStyle for ViewPanel
@@ -180,8 +238,22 @@ where
         let loading_state: bool = e.loading.load(Ordering::Relaxed);
 
 
+        let order = *e.order.lock().unwrap();
+        let mut view_panel_title = match order.label() {
+            Some(order_label) => format!("{} [{}]", e.view.name, order_label),
+            None => e.view.name.clone(),
+        };
+        if app.profile_names.len() > 1 {
+            let profile = app.panel_profile(&e.view);
+            let org_label = profile_viewers.get(&profile).map(|viewer| viewer.organization.url_key.clone()).unwrap_or(profile);
+            view_panel_title = format!("{}: {}", org_label, view_panel_title);
+        }
+        if zoomed_idx.is_some() {
+            view_panel_title.push_str(" [Zoomed]");
+        }
+
         // TODO: Create default color
-        let view_panel_table_style = TableStyle { title_style: Some(( e.view.name.clone(), e.view.color.clone().unwrap_or("#000000".to_string()) )),
+        let view_panel_table_style = TableStyle { title_style: Some(( view_panel_title, e.view.color.clone().unwrap_or("#000000".to_string()) )),
             row_bottom_margin: Some(0),
             view_idx: Some((i as u16)+1),
             highlight_table,
@@ -192,8 +264,12 @@ where
 
         if let Ok(mut view_panel_table) =
             DashboardViewPanel::render(&view_data_handle,
+                &e.columns,
                 &widths,
-                view_panel_table_style
+                view_panel_table_style,
+                app.due_soon_day_threshold,
+                &order,
+                &e.highlighted_until
             )
         {
             // Determine if this view panel is currently selected
@@ -206,6 +282,11 @@ where
             // Determine the correct TableState, depending on if this view is selected or not
             let mut table_state = if is_selected { app.view_panel_issue_selected.clone().unwrap_or_default() } else { TableState::default() };
 
+            // Account for group header rows
+            if let Some(issue_idx) = table_state.selected() {
+                table_state.select(Some(order.display_row_idx(&view_data_handle, issue_idx)));
+            }
+
             view_panel_table = view_panel_table.widths(&widths);
 
             f.render_stateful_widget(view_panel_table, view_panel_rect, &mut table_state);
@@ -236,9 +317,92 @@ where
 
     f.render_stateful_widget(items, chunks[3], &mut app.actions.state);
 
+    draw_issue_overlays(f, app);
+
+    // Draw Panel Jump Prompt
+    if app.panel_jump_prompt.open {
+        let area = util::ui::centered_rect(30, 20, f.size());
+
+        f.render_widget(Clear, area); //this clears out the background
+
+        app.panel_jump_prompt.render(f, area);
+    }
+
+    // Draw Panel Export Prompt
+    if app.panel_export_prompt.open {
+        let area = util::ui::centered_rect(40, 25, f.size());
+
+        f.render_widget(Clear, area); //this clears out the background
+
+        app.panel_export_prompt.render(f, area);
+    }
+}
+
+// Drawn over every route
+pub fn draw_notifications<B>(f: &mut Frame<B>, app: &mut App)
+where
+  B: Backend,
+{
+    // Draw Issue History, below the Error Log
+    if app.issue_history_log.open {
+        let area = util::ui::centered_rect(70, 60, f.size());
+        app.issue_history_log.render(f, area);
+    }
+
+    // Draw Error Log
+    if app.notification_center.log_open {
+        let area = util::ui::centered_rect(70, 60, f.size());
+        app.notification_center.render_log(f, area);
+    } else {
+        app.notification_center.render_toast(f, f.size());
+    }
+}
+
+
+pub fn draw_issue_search<B>(f: &mut Frame<B>, app: &mut App)
+where
+  B: Backend,
+{
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(3), Constraint::Percentage(20), Constraint::Min(0)].as_ref())
+        .split(f.size());
+
+    // Render query input
+    app.issue_search.input.render(f, chunks[0]);
+
+    // Render the View Panel Command Bar, View Panel specific commands are unavailable
+    set_issue_cmds_active(app);
+    app.view_panel_cmd_bar.set_refresh_panel_active(false);
+    app.view_panel_cmd_bar.set_create_issue_active(false);
+    app.view_panel_cmd_bar.set_export_panel_active(false);
+    app.view_panel_cmd_bar.set_undo_modification_active(!app.modifying_issue && app.issue_history_log.history.can_undo());
+    app.view_panel_cmd_bar.set_redo_modification_active(!app.modifying_issue && app.issue_history_log.history.can_redo());
+
+    if let Ok(cmd_items) = app.view_panel_cmd_bar.render() {
+        f.render_widget(cmd_items, chunks[1]);
+    } else {
+        util::error_panic!("draw_issue_search - app.view_panel_cmd_bar.render() failed");
+    }
+
+    // Render search results
+    // subtract 2 from width to account for single character table borders
+    let results_content_rect = Rect::new(chunks[2].x, chunks[2].y, chunks[2].width.saturating_sub(2), chunks[2].height);
+    let widths: Vec<Constraint> = widths_from_rect( &results_content_rect, &panel_table_columns(&app.issue_search.columns));
+
+    app.issue_search.render(f, chunks[2], &widths, app.loader_tick, app.due_soon_day_threshold);
+
+    draw_issue_overlays(f, app);
+}
+
+// Draw the expanded Issue, Issue Create Form and Issue Op Interface modals
+fn draw_issue_overlays<B>(f: &mut Frame<B>, app: &mut App)
+where
+  B: Backend,
+{
     // Draw Issue Expanded Modal
     if let Some(issue_obj) = &app.issue_to_expand {
-        let area = util::ui::centered_rect(40, 40, f.size());
+        let area = util::ui::centered_rect(50, 60, f.size());
 
         let issue_modal_chunk = Layout::default()
             .direction(Direction::Vertical)
@@ -247,10 +411,19 @@ where
         
         f.render_widget(Clear, area); //this clears out the background
 
-        linear_issue_modal::render_and_layout(f, issue_modal_chunk[0], issue_obj, app.scroll_tick);
+        linear_issue_modal::render_and_layout(f, issue_modal_chunk[0], issue_obj, &app.issue_comments_pane, app.scroll_tick, app.loader_tick);
     }
 
 
+    // Draw Linear Issue Create Form
+    if app.creating_issue {
+        let area = util::ui::centered_rect(50, 60, f.size());
+
+        f.render_widget(Clear, area); //this clears out the background
+
+        app.linear_issue_create_form.render(f, area, app.input_mode == app::InputMode::Edit);
+    }
+
     // Draw Linear Issue Op Interface
 
     // IssueModificationOp::Title is not rendered with a table
@@ -262,7 +435,15 @@ where
         app.title_entry.render(f, area);
     }
 
-    else if app.modifying_issue {
+    // IssueModificationOp::DueDate is rendered with a calendar
+    else if app.modifying_issue && app.linear_issue_op_interface.current_op == Some(IssueModificationOp::DueDate) {
+        let area = util::ui::centered_rect(40, 50, f.size());
+
+        app.due_date_picker.render(f, area);
+    }
+
+    // Drawn over the Issue Create Form when selecting a field value
+    else if app.modifying_issue || app.linear_issue_op_interface.current_op.is_some() {
 
         let area = util::ui::centered_rect(40, 40, f.size());
 
@@ -314,6 +495,28 @@ where
     }
 }
 
+// Enable Issue modification & expand Commands if an Issue is selected and not expanded
+fn set_issue_cmds_active(app: &mut App) {
+    let issue_cmds_active: bool = fetch_selected_view_panel_issue(app).is_some() && app.issue_to_expand.is_none();
+
+    // Ops the selected Issue's platform supports
+    let platform = app.selected_issue_platform();
+    let op_active = |op: IssueModificationOp| issue_cmds_active && backend::supports_op(&platform, op);
+
+    app.view_panel_cmd_bar.set_modify_title_active(op_active(IssueModificationOp::Title));
+    app.view_panel_cmd_bar.set_modify_workflow_state_active(op_active(IssueModificationOp::WorkflowState));
+    app.view_panel_cmd_bar.set_modify_assignee_active(op_active(IssueModificationOp::Assignee));
+    app.view_panel_cmd_bar.set_modify_project_active(op_active(IssueModificationOp::Project));
+    app.view_panel_cmd_bar.set_modify_cycle_active(op_active(IssueModificationOp::Cycle));
+    app.view_panel_cmd_bar.set_modify_labels_active(op_active(IssueModificationOp::Labels));
+    app.view_panel_cmd_bar.set_modify_team_active(op_active(IssueModificationOp::Team));
+    app.view_panel_cmd_bar.set_modify_priority_active(op_active(IssueModificationOp::Priority));
+    app.view_panel_cmd_bar.set_modify_estimate_active(op_active(IssueModificationOp::Estimate));
+    app.view_panel_cmd_bar.set_modify_due_date_active(op_active(IssueModificationOp::DueDate));
+    app.view_panel_cmd_bar.set_modify_description_active(issue_cmds_active && !app.modifying_issue && !app.creating_issue);
+    app.view_panel_cmd_bar.set_expand_issue_active(issue_cmds_active);
+}
+
 
 pub fn draw_dashboard_view_config<B>(f: &mut Frame<B>, app: &mut App)
 where
@@ -349,7 +552,11 @@ where
     }
 
     // Update Command statuses
+    let name_entry_open = app.dashboard_name_entry.op.is_some();
     app.dashboard_view_config_cmd_bar.set_remove_view_active(remove_view_cmd_active);
+    app.dashboard_view_config_cmd_bar.set_edit_dashboard_active(!name_entry_open);
+    app.dashboard_view_config_cmd_bar.set_switch_dashboard_active(!name_entry_open && app.dashboard_names.len() > 1);
+    app.dashboard_view_config_cmd_bar.set_switch_profile_active(!name_entry_open && app.profile_names.len() > 1);
 
     // Render command bar
     if let Ok(cmd_items) = app.dashboard_view_config_cmd_bar.render() {
@@ -379,7 +586,7 @@ where
     let view_list_table_style = TableStyle { 
         title_style: 
         Some((
-            String::from("Dashboard View Configuration"),
+            format!("Dashboard View Configuration - {}", app.dashboard_name),
             hex_str_from_style_color(&colors::DASHBOARD_VIEW_LIST_TABLE_TITLE).unwrap_or_else(|| String::from("#000000")) ) ),
         row_bottom_margin: Some(0),
         view_idx: Some(1),
@@ -447,4 +654,13 @@ where
         util::error_panic!("draw_dashboard_view_config - LinearCustomViewSelect::get_rendered_view_data failed");
     }
 
+    // Draw Dashboard Name Entry
+    if app.dashboard_name_entry.op.is_some() {
+        let area = util::ui::centered_rect(50, 30, f.size());
+
+        f.render_widget(Clear, area); //this clears out the background
+
+        app.dashboard_name_entry.render(f, area);
+    }
+
 }
\ No newline at end of file
@@ -1,13 +1,29 @@
 
-use crate::app::App;
+use crate::app::{ App, Route };
 
-use crate::linear::{ schema::{ Issue }, client::{ IssueFieldObject } };
+use crate::linear::{ schema::{ CustomView, Issue }, client::{ IssueFieldObject } };
+
+// Fewest slots shown when configuring a dashboard
+pub const MIN_DASHBOARD_SLOTS: usize = 6;
+
+// Move filled slots first, e.g. ["View 1", "Empty Slot", "View 2"] -> ["View 1", "View 2", "Empty Slot", ...]
+// and keep an empty slot to add a view to
+pub fn compact_view_list(view_list: &mut Vec<Option<CustomView>>) {
+    view_list.retain(|x| x.is_some());
+    let len = (view_list.len() + 1).max(MIN_DASHBOARD_SLOTS);
+    view_list.resize(len, None);
+}
 
 // Accepts:
 //     app
 // Returns:
 //     full JSON Issue object (as specified in GraphQL request), or None if a View Panel Issue is not selected
 pub fn fetch_selected_view_panel_issue(app: &App) -> Option<Issue> {
+    // On the Issue Search Route, the selected search result is used instead
+    if *app.route.lock().unwrap() == Route::IssueSearch {
+        return app.issue_search.selected_issue();
+    }
+
     // Validate that a ViewPanel and issue are selected
     // using 'app.linear_dashboard_view_panel_selected' & 'app.view_panel_issue_selected'
 
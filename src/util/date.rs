@@ -0,0 +1,141 @@
+use std::convert::TryFrom;
+
+use chrono::{ DateTime, Datelike, Duration, Local, NaiveDate, Weekday };
+
+// Linear TimelessDate format, e.g. "2022-03-21"
+pub const TIMELESS_DATE_FORMAT: &str = "%Y-%m-%d";
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DueStatus {
+    Overdue,
+    DueSoon,
+    Later,
+}
+
+pub fn today() -> NaiveDate {
+    Local::today().naive_local()
+}
+
+pub fn parse_timeless_date(date: &str) -> Option<NaiveDate> {
+    NaiveDate::parse_from_str(date.trim(), TIMELESS_DATE_FORMAT).ok()
+}
+
+pub fn format_timeless_date(date: &NaiveDate) -> String {
+    date.format(TIMELESS_DATE_FORMAT).to_string()
+}
+
+// Format a Linear DateTime (RFC 3339) in local time, e.g. "2022-03-21 14:05"
+pub fn format_local_datetime(datetime: &str) -> String {
+    match DateTime::parse_from_rfc3339(datetime) {
+        Ok(x) => x.with_timezone(&Local).format("%Y-%m-%d %H:%M").to_string(),
+        Err(_) => datetime.to_string(),
+    }
+}
+
+// Accepts:
+//     due_date: TimelessDate string
+//     today
+//     due_soon_day_threshold: number of days from today still considered "due soon"
+// Returns:
+//     None if due_date can't be parsed
+pub fn due_status(due_date: &str, today: NaiveDate, due_soon_day_threshold: u32) -> Option<DueStatus> {
+    let due = parse_timeless_date(due_date)?;
+    let days_left = (due - today).num_days();
+
+    Some(if days_left < 0 {
+        DueStatus::Overdue
+    } else if days_left <= due_soon_day_threshold as i64 {
+        DueStatus::DueSoon
+    } else {
+        DueStatus::Later
+    })
+}
+
+fn parse_weekday(input: &str) -> Option<Weekday> {
+    match input {
+        "mon" | "monday" => Some(Weekday::Mon),
+        "tue" | "tues" | "tuesday" => Some(Weekday::Tue),
+        "wed" | "wednesday" => Some(Weekday::Wed),
+        "thu" | "thur" | "thurs" | "thursday" => Some(Weekday::Thu),
+        "fri" | "friday" => Some(Weekday::Fri),
+        "sat" | "saturday" => Some(Weekday::Sat),
+        "sun" | "sunday" => Some(Weekday::Sun),
+        _ => None,
+    }
+}
+
+// Duration::days panics past ~10^14 days, while NaiveDate only spans ~10^8
+const MAX_OFFSET_DAYS: i64 = 1_000_000_000;
+
+// Returns:
+//     None if the date would be out of range
+fn add_days(date: NaiveDate, days: i64) -> Option<NaiveDate> {
+    if days.abs() > MAX_OFFSET_DAYS {
+        return None;
+    }
+    date.checked_add_signed(Duration::days(days))
+}
+
+// Shift by whole months, clamping the day to the end of the target month
+fn add_months(date: NaiveDate, months: i64) -> Option<NaiveDate> {
+    let total = (date.year() as i64 * 12 + date.month0() as i64).checked_add(months)?;
+    let (year, month) = (i32::try_from(total.div_euclid(12)).ok()?, total.rem_euclid(12) as u32 + 1);
+
+    (1..=date.day()).rev().find_map(|day| NaiveDate::from_ymd_opt(year, month, day))
+}
+
+// Accepts:
+//     input: absolute ("2022-03-21") or relative date expression
+//         "today", "tomorrow", "yesterday"
+//         "+3d", "-1w", "+2m" (days, weeks, months; unit defaults to days)
+//         "fri" (next Friday, today included), "next fri" (next Friday after today)
+//     base: date relative expressions are applied to
+// Returns:
+//     None if the expression isn't recognized or out of range
+pub fn parse_relative_date(input: &str, base: NaiveDate) -> Option<NaiveDate> {
+    let input = input.trim().to_lowercase();
+
+    if let Some(date) = parse_timeless_date(&input) {
+        return Some(date);
+    }
+
+    match input.as_str() {
+        "today" => return Some(base),
+        "tomorrow" => return add_days(base, 1),
+        "yesterday" => return add_days(base, -1),
+        _ => {},
+    }
+
+    // "+3d", "-1w", "+2m"
+    if input.starts_with('+') || input.starts_with('-') {
+        let sign: i64 = if input.starts_with('-') { -1 } else { 1 };
+        let body = &input[1..];
+
+        let (amount, unit) = match body.find(|c: char| !c.is_ascii_digit()) {
+            Some(idx) => (&body[..idx], &body[idx..]),
+            None => (body, "d"),
+        };
+        let amount: i64 = amount.parse::<i64>().ok()? * sign;
+
+        return match unit {
+            "d" | "day" | "days" => add_days(base, amount),
+            "w" | "wk" | "week" | "weeks" => add_days(base, amount.checked_mul(7)?),
+            "m" | "mo" | "month" | "months" => add_months(base, amount),
+            _ => None,
+        };
+    }
+
+    // "fri", "next fri"
+    let (skip_today, weekday_str) = match input.strip_prefix("next ") {
+        Some(x) => (true, x.trim()),
+        None => (false, input.as_str()),
+    };
+    let weekday = parse_weekday(weekday_str)?;
+
+    let mut days_ahead = (7 + weekday.num_days_from_monday() as i64 - base.weekday().num_days_from_monday() as i64) % 7;
+    if days_ahead == 0 && skip_today {
+        days_ahead = 7;
+    }
+
+    add_days(base, days_ahead)
+}
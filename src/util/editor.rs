@@ -0,0 +1,59 @@
+use std::{
+    env,
+    fs,
+    io,
+    path::PathBuf,
+    process,
+};
+
+const DEFAULT_EDITOR: &str = "vi";
+
+// Text edited in an external editor, the backing file is left on disk
+// so the caller can keep it around (e.g. on conflict) or remove it
+pub struct EditedText {
+    pub content: String,
+    pub path: PathBuf,
+}
+
+// Resolve editor command from $VISUAL, then $EDITOR, defaulting to 'vi'
+fn editor_cmd() -> String {
+    env::var("VISUAL").ok()
+        .filter(|x| !x.trim().is_empty())
+        .or_else(|| env::var("EDITOR").ok().filter(|x| !x.trim().is_empty()))
+        .unwrap_or_else(|| DEFAULT_EDITOR.to_string())
+}
+
+// Accepts:
+//     file_name: name of the temp file to create, extension determines editor syntax highlighting
+//     initial: initial contents of the file
+// Blocks until the editor exits, the terminal must already be restored to cooked mode
+pub fn edit_in_external_editor(file_name: &str, initial: &str) -> io::Result<EditedText> {
+    let path = env::temp_dir().join(file_name);
+    fs::write(&path, initial)?;
+
+    // $EDITOR may contain arguments, e.g. "code --wait"
+    let editor = editor_cmd();
+    let mut editor_parts = editor.split_whitespace();
+    let program = editor_parts.next().unwrap_or(DEFAULT_EDITOR);
+
+    let status = process::Command::new(program)
+        .args(editor_parts)
+        .arg(&path)
+        .status()?;
+
+    if !status.success() {
+        return Err(io::Error::other(format!("'{}' exited with {}", editor, status)));
+    }
+
+    let mut content = fs::read_to_string(&path)?;
+
+    // Most editors append a trailing newline, drop it if the original didn't have one
+    if !initial.ends_with('\n') && content.ends_with('\n') {
+        content.pop();
+        if content.ends_with('\r') {
+            content.pop();
+        }
+    }
+
+    Ok(EditedText { content, path })
+}
@@ -26,6 +26,7 @@ pub struct Events {
     rx: mpsc::Receiver<Event<KeyCode>>,
     _input_handle: thread::JoinHandle<()>,
     ignore_exit_key: Arc<AtomicBool>,
+    pause_input: Arc<AtomicBool>,
     _tick_handle: thread::JoinHandle<()>,
 }
 
@@ -58,13 +59,20 @@ impl Events {
     pub fn with_config(config: Config) -> Events {
         let (tx, rx) = mpsc::channel();
         let ignore_exit_key = Arc::new(AtomicBool::new(false));
+        let pause_input = Arc::new(AtomicBool::new(false));
 
 
         let _input_handle = {
             let tx = tx.clone();
             let ignore_exit_key = ignore_exit_key.clone();
+            let pause_input = pause_input.clone();
             thread::spawn(move || {
                 loop {
+                    // don't read stdin while another process (e.g. $EDITOR) owns the terminal
+                    if pause_input.load(Ordering::Relaxed) {
+                        thread::sleep(Duration::from_millis(10));
+                        continue;
+                    }
                     // poll for tick rate duration, if no events, sent tick event.
                     // let timeout = config.tick_rate;
                     if event::poll(Duration::from_secs(0)/*timeout*/).unwrap() {
@@ -96,6 +104,7 @@ impl Events {
         Events {
             rx,
             ignore_exit_key,
+            pause_input,
             _input_handle,
             _tick_handle,
         }
@@ -112,4 +121,12 @@ impl Events {
     pub fn enable_exit_key(&mut self) {
         self.ignore_exit_key.store(false, Ordering::Relaxed);
     }
+
+    pub fn pause_input(&mut self) {
+        self.pause_input.store(true, Ordering::Relaxed);
+    }
+
+    pub fn resume_input(&mut self) {
+        self.pause_input.store(false, Ordering::Relaxed);
+    }
 }
\ No newline at end of file
@@ -0,0 +1,83 @@
+// Render a View Panel's issues for writing to a file
+
+use std::path::Path;
+
+use anyhow::Result;
+
+use crate::constants::view_panel_columns::PanelColumn;
+use crate::linear::schema::Issue;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ExportFormat {
+    Csv,
+    Json,
+    Markdown,
+}
+
+impl ExportFormat {
+    // Format matching the path's extension, None if unsupported
+    pub fn from_path(path: &Path) -> Option<ExportFormat> {
+        let extension = path.extension()?.to_str()?.to_lowercase();
+        match extension.as_str() {
+            "csv" => Some(ExportFormat::Csv),
+            "json" => Some(ExportFormat::Json),
+            "md" | "markdown" => Some(ExportFormat::Markdown),
+            _ => None,
+        }
+    }
+}
+
+// Lowercase view name with non-alphanumerics replaced by '-', used as the default export file name
+pub fn file_stem(view_name: &str) -> String {
+    let stem: String = view_name.trim()
+        .chars()
+        .map(|c| if c.is_alphanumeric() { c.to_ascii_lowercase() } else { '-' })
+        .collect();
+
+    let stem = stem.split('-').filter(|x| !x.is_empty()).collect::<Vec<&str>>().join("-");
+    if stem.is_empty() { String::from("issues") } else { stem }
+}
+
+// Accepts:
+//     columns - the panel's columns, CSV & Markdown only include these, JSON includes all issue fields
+pub fn render(format: ExportFormat, issues: &[Issue], columns: &[PanelColumn]) -> Result<String> {
+    let headers: Vec<String> = columns.iter().map(|column| column.column.label.to_string()).collect();
+    let rows = issues.iter()
+        .map(|issue| columns.iter().map(|column| (column.def.cell_value)(issue)).collect::<Vec<String>>());
+
+    let text = match format {
+        ExportFormat::Json => serde_json::to_string_pretty(issues)?,
+        ExportFormat::Csv => std::iter::once(headers)
+            .chain(rows)
+            .map(|row| row.iter().map(|value| csv_field(value)).collect::<Vec<String>>().join(","))
+            .collect::<Vec<String>>()
+            .join("\n"),
+        ExportFormat::Markdown => {
+            let separator = vec![String::from("---"); headers.len()];
+            std::iter::once(headers)
+                .chain(std::iter::once(separator))
+                .chain(rows.map(|row| row.iter().map(|value| markdown_cell(value)).collect()))
+                .map(|row| format!("| {} |", row.join(" | ")))
+                .collect::<Vec<String>>()
+                .join("\n")
+        },
+    };
+
+    Ok(text + "\n")
+}
+
+// Quoted if the value contains a delimiter, quote or line break
+fn csv_field(value: &str) -> String {
+    if value.contains(&[',', '"', '\n', '\r'][..]) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+// Table cells must stay on one line
+fn markdown_cell(value: &str) -> String {
+    value.replace('|', "\\|")
+        .replace("\r\n", "<br>")
+        .replace('\n', "<br>")
+}
@@ -0,0 +1,111 @@
+use serde::{ Deserialize, Serialize };
+
+use tui::layout::{ Constraint, Direction, Layout, Rect };
+
+// A dashboard's grid, as relative row heights & column widths, e.g.
+//     rows = [2, 1], columns = [1, 1, 1] => 2 rows by 3 columns, the first row twice as tall
+// Panels that don't fit are placed on extra rows of weight 1
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct GridLayout {
+    pub rows: Vec<u16>,
+    pub columns: Vec<u16>,
+}
+
+// Number of rows & columns a panel covers
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PanelSpan {
+    pub rows: u16,
+    pub columns: u16,
+}
+
+impl Default for PanelSpan {
+    fn default() -> PanelSpan {
+        PanelSpan { rows: 1, columns: 1 }
+    }
+}
+
+// Accepts:
+//     spans: the span of each panel, in panel order
+// Returns:
+//     (row, column) of each panel's top-left cell, panels are placed row by row into the first free cells
+pub fn place_panels(num_columns: usize, spans: &[PanelSpan]) -> Vec<(usize, usize)> {
+    if num_columns == 0 {
+        return Vec::new();
+    }
+
+    let mut occupied: Vec<Vec<bool>> = Vec::new();
+    let mut placements = Vec::with_capacity(spans.len());
+
+    for span in spans.iter() {
+        let row_span = (span.rows as usize).max(1);
+        let column_span = (span.columns as usize).max(1).min(num_columns);
+
+        let is_free = |occupied: &Vec<Vec<bool>>, row: usize, column: usize| {
+            (row..row + row_span).all(|r| {
+                (column..column + column_span).all(|c| !occupied.get(r).map(|x| x[c]).unwrap_or(false))
+            })
+        };
+
+        let mut row = 0;
+        let placement = loop {
+            if let Some(column) = (0..=num_columns - column_span).find(|column| is_free(&occupied, row, *column)) {
+                break (row, column);
+            }
+            row += 1;
+        };
+
+        while occupied.len() < placement.0 + row_span {
+            occupied.push(vec![false; num_columns]);
+        }
+        for row_cells in occupied[placement.0..placement.0 + row_span].iter_mut() {
+            for cell in row_cells[placement.1..placement.1 + column_span].iter_mut() {
+                *cell = true;
+            }
+        }
+        placements.push(placement);
+    }
+
+    placements
+}
+
+fn weighted_split(direction: Direction, weights: &[u16], r: Rect) -> Vec<Rect> {
+    let total: u32 = weights.iter().map(|x| *x as u32).sum();
+
+    Layout::default()
+        .direction(direction)
+        .constraints(weights.iter().map(|x| Constraint::Ratio(*x as u32, total.max(1))).collect::<Vec<Constraint>>())
+        .split(r)
+}
+
+// Returns:
+//     the Rect of each panel, in panel order
+pub fn grid_layout(layout: &GridLayout, spans: &[PanelSpan], r: Rect) -> Vec<Rect> {
+    if layout.columns.is_empty() || spans.is_empty() {
+        return Vec::new();
+    }
+
+    let placements = place_panels(layout.columns.len(), spans);
+
+    let num_rows = placements.iter().zip(spans.iter())
+        .map(|((row, _), span)| row + (span.rows as usize).max(1))
+        .max()
+        .unwrap_or(0)
+        .max(layout.rows.len());
+
+    let row_weights: Vec<u16> = (0..num_rows).map(|idx| layout.rows.get(idx).copied().unwrap_or(1)).collect();
+
+    let row_rects = weighted_split(Direction::Vertical, &row_weights, r);
+    let column_rects = weighted_split(Direction::Horizontal, &layout.columns, r);
+
+    placements.iter().zip(spans.iter())
+        .map(|((row, column), span)| {
+            let last_row = &row_rects[row + (span.rows as usize).max(1) - 1];
+            let last_column = &column_rects[(column + (span.columns as usize).max(1)).min(column_rects.len()) - 1];
+
+            let x = column_rects[*column].x;
+            let y = row_rects[*row].y;
+            Rect::new(x, y, last_column.right() - x, last_row.bottom() - y)
+        })
+        .collect()
+}
@@ -0,0 +1,191 @@
+use std::cmp::Ordering;
+
+use serde::{ Deserialize, Serialize };
+
+use crate::linear::schema::Issue;
+
+// Workflow order of Linear WorkflowState types
+const STATE_TYPE_ORDER: [&str; 6] = ["triage", "backlog", "unstarted", "started", "completed", "canceled"];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SortKey {
+    Priority,
+    DueDate,
+    Created,
+    StateType,
+    Assignee,
+}
+
+impl SortKey {
+    pub fn label(&self) -> &'static str {
+        match self {
+            SortKey::Priority => "Priority",
+            SortKey::DueDate => "Due Date",
+            SortKey::Created => "Created",
+            SortKey::StateType => "State",
+            SortKey::Assignee => "Assignee",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum GroupKey {
+    State,
+    Assignee,
+    Project,
+}
+
+impl GroupKey {
+    pub fn label(&self) -> &'static str {
+        match self {
+            GroupKey::State => "State",
+            GroupKey::Assignee => "Assignee",
+            GroupKey::Project => "Project",
+        }
+    }
+}
+
+// Client-side ordering of a DashboardViewPanel's Issues, None keeps API order
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PanelOrder {
+    pub sort: Option<SortKey>,
+    pub group: Option<GroupKey>,
+}
+
+fn state_type_rank(issue: &Issue) -> usize {
+    STATE_TYPE_ORDER.iter()
+        .position(|state_type| *state_type == issue.state.type_)
+        .unwrap_or(STATE_TYPE_ORDER.len())
+}
+
+// Linear priority 0 is "No priority", sort it after Low (4)
+fn priority_rank(issue: &Issue) -> i64 {
+    match issue.priority as i64 {
+        0 => i64::MAX,
+        x => x,
+    }
+}
+
+fn assignee_name(issue: &Issue) -> Option<String> {
+    issue.assignee.as_ref().map(|assignee| assignee.display_name.to_lowercase())
+}
+
+// Groups are ordered case-insensitively, names differing only by case remain separate, adjacent groups
+fn group_name_key(name: Option<&String>) -> Option<(String, String)> {
+    name.map(|name| (name.to_lowercase(), name.clone()))
+}
+
+// Compare Options, placing None last
+fn cmp_none_last<T: Ord>(a: Option<T>, b: Option<T>) -> Ordering {
+    match (a, b) {
+        (Some(a), Some(b)) => a.cmp(&b),
+        (Some(_), None) => Ordering::Less,
+        (None, Some(_)) => Ordering::Greater,
+        (None, None) => Ordering::Equal,
+    }
+}
+
+impl PanelOrder {
+
+    pub fn cycle_sort(&mut self) {
+        self.sort = match self.sort {
+            None => Some(SortKey::Priority),
+            Some(SortKey::Priority) => Some(SortKey::DueDate),
+            Some(SortKey::DueDate) => Some(SortKey::Created),
+            Some(SortKey::Created) => Some(SortKey::StateType),
+            Some(SortKey::StateType) => Some(SortKey::Assignee),
+            Some(SortKey::Assignee) => None,
+        };
+    }
+
+    pub fn cycle_group(&mut self) {
+        self.group = match self.group {
+            None => Some(GroupKey::State),
+            Some(GroupKey::State) => Some(GroupKey::Assignee),
+            Some(GroupKey::Assignee) => Some(GroupKey::Project),
+            Some(GroupKey::Project) => None,
+        };
+    }
+
+    pub fn is_default(&self) -> bool {
+        self.sort.is_none() && self.group.is_none()
+    }
+
+    // Short description for the panel title, e.g. "Sort: Priority, Group: State"
+    pub fn label(&self) -> Option<String> {
+        let parts: Vec<String> = [
+            self.sort.map(|x| format!("Sort: {}", x.label())),
+            self.group.map(|x| format!("Group: {}", x.label())),
+        ].iter().flatten().cloned().collect();
+
+        if parts.is_empty() { None } else { Some(parts.join(", ")) }
+    }
+
+    // Name the Issue is grouped by, None for Issues without one (e.g. unassigned)
+    fn group_name<'a>(&self, issue: &'a Issue) -> Option<&'a String> {
+        match self.group? {
+            GroupKey::State => Some(&issue.state.name),
+            GroupKey::Assignee => issue.assignee.as_ref().map(|assignee| &assignee.display_name),
+            GroupKey::Project => issue.project.as_ref().map(|project| &project.name),
+        }
+    }
+
+    // Header displayed above the Issue's group, None if not grouping
+    pub fn group_label(&self, issue: &Issue) -> Option<String> {
+        let group = self.group?;
+        Some(match self.group_name(issue) {
+            Some(name) => name.clone(),
+            None if group == GroupKey::Assignee => String::from("Unassigned"),
+            None => String::from("No Project"),
+        })
+    }
+
+    // Groups by the same name group_label() displays, so each group is contiguous
+    fn cmp_group(&self, a: &Issue, b: &Issue) -> Ordering {
+        match self.group {
+            Some(GroupKey::State) => state_type_rank(a).cmp(&state_type_rank(b))
+                .then_with(|| group_name_key(self.group_name(a)).cmp(&group_name_key(self.group_name(b)))),
+            Some(_) => cmp_none_last(group_name_key(self.group_name(a)), group_name_key(self.group_name(b))),
+            None => Ordering::Equal,
+        }
+    }
+
+    fn cmp_sort(&self, a: &Issue, b: &Issue) -> Ordering {
+        match self.sort {
+            Some(SortKey::Priority) => priority_rank(a).cmp(&priority_rank(b)),
+            Some(SortKey::DueDate) => cmp_none_last(a.due_date.as_ref(), b.due_date.as_ref()),
+            // Newest first
+            Some(SortKey::Created) => b.created_at.cmp(&a.created_at),
+            Some(SortKey::StateType) => state_type_rank(a).cmp(&state_type_rank(b)),
+            Some(SortKey::Assignee) => cmp_none_last(assignee_name(a), assignee_name(b)),
+            None => Ordering::Equal,
+        }
+    }
+
+    // Stable sort, Issues that compare equal keep their API order
+    pub fn apply(&self, issues: &mut [Issue]) {
+        if self.is_default() {
+            return;
+        }
+        issues.sort_by(|a, b| self.cmp_group(a, b).then_with(|| self.cmp_sort(a, b)));
+    }
+
+    // Accepts:
+    //     issues: Issues already ordered by apply()
+    //     issue_idx: index into issues
+    // Returns:
+    //     row index of the Issue once group header rows are inserted
+    pub fn display_row_idx(&self, issues: &[Issue], issue_idx: usize) -> usize {
+        if self.group.is_none() {
+            return issue_idx;
+        }
+
+        let headers = issues.iter()
+            .take(issue_idx + 1)
+            .enumerate()
+            .filter(|(idx, issue)| *idx == 0 || self.group_label(issue) != self.group_label(&issues[idx - 1]))
+            .count();
+
+        issue_idx + headers
+    }
+}
@@ -0,0 +1,103 @@
+use std::collections::HashMap;
+use std::sync::RwLock;
+
+// Rebindable actions and their default keys, as named in the config [keybindings] table
+pub const KEYBINDING_ACTIONS: [(&str, char); 34] = [
+    ("quit", 'q'),
+    ("edit", 'e'),
+    ("delete", 'd'),
+    ("refresh_panel", 'r'),
+    ("expand_issue", 'f'),
+    ("sort_panel", 'S'),
+    ("group_panel", 'G'),
+    ("modify_title", 't'),
+    ("modify_workflow_state", 'w'),
+    ("modify_assignee", 'a'),
+    ("modify_project", 'p'),
+    ("modify_cycle", 'c'),
+    ("modify_labels", 'l'),
+    ("modify_team", 'm'),
+    ("modify_priority", 'i'),
+    ("modify_estimate", 's'),
+    ("modify_due_date", 'u'),
+    ("edit_description", 'o'),
+    ("toggle_selection", ' '),
+    ("search", '/'),
+    ("create_issue", 'n'),
+    ("new_dashboard", 'N'),
+    ("rename_dashboard", 'R'),
+    ("duplicate_dashboard", 'D'),
+    ("previous_dashboard", '['),
+    ("next_dashboard", ']'),
+    ("jump_to_panel", 'J'),
+    ("zoom_panel", 'z'),
+    ("export_panel", 'x'),
+    ("error_log", 'E'),
+    ("undo_modification", 'U'),
+    ("redo_modification", 'Y'),
+    ("issue_history", 'H'),
+    ("switch_profile", 'W'),
+];
+
+// Maps configured keys to the default key of their action, and back
+#[derive(Debug, Default)]
+struct Keymap {
+    to_default: HashMap<char, char>,
+    from_default: HashMap<char, char>,
+}
+
+lazy_static! {
+    static ref KEYMAP: RwLock<Keymap> = RwLock::new(Keymap::default());
+}
+
+pub fn default_key(action: &str) -> Option<char> {
+    KEYBINDING_ACTIONS.iter().find(|(name, _)| *name == action).map(|(_, key)| *key)
+}
+
+// Accepts a config key value, e.g. "r", "space"
+pub fn parse_key(value: &str) -> Option<char> {
+    if value == "space" {
+        return Some(' ');
+    }
+    let mut chars = value.chars();
+    match (chars.next(), chars.next()) {
+        (Some(key), None) if !key.is_ascii_digit() => Some(key),
+        _ => None,
+    }
+}
+
+// Accepts:
+//     keybindings: action name -> key, already validated
+pub fn set_keybindings(keybindings: &HashMap<String, String>) {
+    let mut keymap = Keymap::default();
+
+    for (action, value) in keybindings.iter() {
+        if let (Some(default), Some(key)) = (default_key(action), parse_key(value)) {
+            if default != key {
+                keymap.to_default.insert(key, default);
+                keymap.from_default.insert(default, key);
+            }
+        }
+    }
+
+    *KEYMAP.write().unwrap() = keymap;
+}
+
+// Translate a pressed key into the default key get_cmd() matches on,
+// None if the key's default action was rebound to another key
+pub fn translate_key(key: char) -> Option<char> {
+    let keymap = KEYMAP.read().unwrap();
+
+    if let Some(default) = keymap.to_default.get(&key) {
+        return Some(*default);
+    }
+    if keymap.from_default.contains_key(&key) {
+        return None;
+    }
+    Some(key)
+}
+
+// Key currently bound to the action with the given default key
+pub fn display_key(default: char) -> char {
+    *KEYMAP.read().unwrap().from_default.get(&default).unwrap_or(&default)
+}
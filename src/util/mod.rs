@@ -7,6 +7,7 @@ pub mod list_state;
 pub mod table_state;
 
 pub mod ui;
+pub mod grid_layout;
 
 mod cursor;
 pub use cursor::GraphQLCursor;
@@ -19,6 +20,13 @@ pub mod loader;
 
 pub mod table;
 
+pub mod editor;
+pub mod date;
+pub mod issue_order;
+pub mod export;
+pub mod keybindings;
+pub mod theme;
+
 #[macro_export]
 macro_rules! error_panic {
     ($($arg:tt)*) => {
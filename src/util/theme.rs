@@ -0,0 +1,87 @@
+use std::collections::HashMap;
+use std::sync::RwLock;
+
+use tui::style::Color;
+
+use crate::constants::colors;
+
+use crate::util::ui::style_color_from_hex_str;
+
+// Themeable colors, as named in the config [theme] table (values are hex strings, e.g. "#F2C94C")
+pub const THEME_KEYS: [&str; 8] = [
+    "table_border",
+    "table_border_selected",
+    "table_header",
+    "table_header_bg",
+    "group_header",
+    "due_overdue",
+    "due_soon",
+    "refreshed_row",
+];
+
+#[derive(Debug, Clone, Copy)]
+pub struct Theme {
+    pub table_border: Color,
+    pub table_border_selected: Color,
+    pub table_header: Color,
+    pub table_header_bg: Color,
+    pub group_header: Color,
+    pub due_overdue: Color,
+    pub due_soon: Color,
+    pub refreshed_row: Color,
+}
+
+impl Default for Theme {
+    fn default() -> Theme {
+        Theme {
+            table_border: Color::White,
+            table_border_selected: Color::Yellow,
+            table_header: Color::LightGreen,
+            table_header_bg: Color::DarkGray,
+            group_header: colors::PANEL_GROUP_HEADER,
+            due_overdue: colors::DUE_DATE_OVERDUE,
+            due_soon: colors::DUE_DATE_SOON,
+            refreshed_row: colors::PANEL_REFRESHED_ROW,
+        }
+    }
+}
+
+impl Theme {
+    pub fn border(&self, highlight_table: bool) -> Color {
+        if highlight_table { self.table_border_selected } else { self.table_border }
+    }
+}
+
+lazy_static! {
+    static ref THEME: RwLock<Theme> = RwLock::new(Theme::default());
+}
+
+pub fn theme() -> Theme {
+    *THEME.read().unwrap()
+}
+
+// Accepts:
+//     overrides: theme key -> hex color, unknown keys and invalid colors are ignored
+pub fn set_theme(overrides: &HashMap<String, String>) {
+    let mut theme = Theme::default();
+
+    for (key, value) in overrides.iter() {
+        let color = match style_color_from_hex_str(value) {
+            Some(color) => color,
+            None => continue,
+        };
+        match key.as_str() {
+            "table_border" => theme.table_border = color,
+            "table_border_selected" => theme.table_border_selected = color,
+            "table_header" => theme.table_header = color,
+            "table_header_bg" => theme.table_header_bg = color,
+            "group_header" => theme.group_header = color,
+            "due_overdue" => theme.due_overdue = color,
+            "due_soon" => theme.due_soon = color,
+            "refreshed_row" => theme.refreshed_row = color,
+            _ => {},
+        }
+    }
+
+    *THEME.write().unwrap() = theme;
+}
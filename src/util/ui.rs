@@ -63,9 +63,9 @@ pub fn hex_str_from_style_color(color: &Color) -> Option<String> {
 }
 
 
+// Default layout of dashboards without a GridLayout, returns the Rect of each panel in panel order
 pub fn view_layout(num_views: usize, r: Rect) -> Vec<Rect> {
 
-    // TODO: make this configurable
     let views_per_row: usize = 2;
     let num_rows: usize = (num_views / views_per_row) + (num_views % views_per_row);
 
@@ -75,13 +75,12 @@ pub fn view_layout(num_views: usize, r: Rect) -> Vec<Rect> {
         vertical_constraints.push(Constraint::Percentage(100/(num_rows as u16)));
     }
 
-    let mut row_rects = Layout::default()
+    let row_rects = Layout::default()
         .direction(Direction::Vertical)
         .constraints(
             vertical_constraints,
         )
         .split(r);
-    row_rects.reverse();
 
     let mut final_rects: Vec<Rect> = Vec::new();
     
@@ -93,14 +92,13 @@ pub fn view_layout(num_views: usize, r: Rect) -> Vec<Rect> {
             horizontal_constraints.push(Constraint::Percentage(100/(rem.min(views_per_row) as u16)));
         }
 
-        let mut row_cols = Layout::default()
+        let row_cols = Layout::default()
             .direction(Direction::Horizontal)
             .constraints(
                 horizontal_constraints,
             )
             .split(row_rects[row_idx]);
-        row_cols.reverse();
-        
+
         final_rects.extend(row_cols);
 
         rem = rem.saturating_sub(views_per_row);
@@ -0,0 +1,231 @@
+// Embedded listener for Linear webhooks, e.g. forwarded by a tunnel or a local relay.
+// Issue create, update & remove payloads are queued, then applied to the matching View Panels on the next tick.
+//
+// Payloads can be replayed locally, e.g. with [app] webhook_listen = "127.0.0.1:8787":
+//     curl --data @tests/fixtures/webhooks/issue_update.json http://127.0.0.1:8787/
+
+use std::collections::HashMap;
+use std::sync::{ Arc, Mutex };
+use std::time::{ Duration, SystemTime, UNIX_EPOCH };
+
+use ring::hmac;
+use serde_json::Value;
+
+use tokio::io::{ AsyncReadExt, AsyncWriteExt };
+use tokio::net::{ TcpListener, TcpStream };
+
+// Requests past these sizes are rejected
+const MAX_HEAD_SIZE: usize = 16 * 1024;
+const MAX_BODY_SIZE: usize = 1024 * 1024;
+
+// Connections still open after this are dropped, so stalled clients can't pile up
+const CONNECTION_TIMEOUT: Duration = Duration::from_secs(10);
+
+// Signed payloads sent longer ago than this are rejected, so a captured delivery can't be replayed
+const MAX_PAYLOAD_AGE: Duration = Duration::from_secs(60);
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum WebhookAction {
+    Create,
+    Update,
+    Remove,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct IssueWebhookEvent {
+    pub action: WebhookAction,
+    pub issue_id: String,
+    // Matched against the organization of each profile's viewer, if present
+    pub organization_id: Option<String>,
+}
+
+impl IssueWebhookEvent {
+
+    // Returns:
+    //     Ok(None) for payloads of other types, e.g. Comment or Project
+    pub fn from_payload(payload: &Value) -> Result<Option<IssueWebhookEvent>, String> {
+        if payload["type"].as_str() != Some("Issue") {
+            return Ok(None);
+        }
+
+        let action = match payload["action"].as_str() {
+            Some("create") => WebhookAction::Create,
+            Some("update") => WebhookAction::Update,
+            Some("remove") => WebhookAction::Remove,
+            x => return Err(format!("unknown action {:?}", x)),
+        };
+
+        let issue_id = payload["data"]["id"].as_str()
+            .ok_or_else(|| String::from("missing data.id"))?
+            .to_string();
+
+        Ok(Some(IssueWebhookEvent {
+            action,
+            issue_id,
+            organization_id: payload["organizationId"].as_str().map(|x| x.to_string()),
+        }))
+    }
+}
+
+// Events received but not yet applied, clones push to the same queue
+#[derive(Debug, Clone, Default)]
+pub struct WebhookEvents {
+    events: Arc<Mutex<Vec<IssueWebhookEvent>>>,
+}
+
+impl WebhookEvents {
+
+    pub fn push(&self, event: IssueWebhookEvent) {
+        self.events.lock().unwrap().push(event);
+    }
+
+    // Oldest first
+    pub fn drain(&self) -> Vec<IssueWebhookEvent> {
+        std::mem::take(&mut *self.events.lock().unwrap())
+    }
+}
+
+fn decode_hex(hex: &str) -> Option<Vec<u8>> {
+    if hex.len() % 2 == 1 {
+        return None;
+    }
+    (0..hex.len())
+        .step_by(2)
+        .map(|idx| u8::from_str_radix(hex.get(idx..idx + 2)?, 16).ok())
+        .collect()
+}
+
+// Linear signs the raw body with HMAC-SHA256, sent hex encoded in the 'Linear-Signature' header
+pub fn verify_signature(secret: &str, body: &[u8], signature: &str) -> bool {
+    let key = hmac::Key::new(hmac::HMAC_SHA256, secret.as_bytes());
+    match decode_hex(signature.trim()) {
+        Some(tag) => hmac::verify(&key, body, &tag).is_ok(),
+        None => false,
+    }
+}
+
+// Linear sends the delivery time as 'webhookTimestamp', in milliseconds since the epoch
+// Clock skew is allowed for in either direction
+pub fn is_fresh_payload(payload: &Value, now: SystemTime) -> bool {
+    let sent_at = match payload["webhookTimestamp"].as_u64() {
+        Some(millis) => UNIX_EPOCH + Duration::from_millis(millis),
+        None => return false,
+    };
+    let age = now.duration_since(sent_at).unwrap_or_else(|err| err.duration());
+    age <= MAX_PAYLOAD_AGE
+}
+
+// Accept connections until the listener fails
+// Accepts:
+//     secret: if set, payloads without a valid signature or a recent 'webhookTimestamp' are rejected
+pub async fn serve(listener: TcpListener, secret: Option<String>, events: WebhookEvents) {
+    loop {
+        let stream = match listener.accept().await {
+            Ok((stream, _)) => stream,
+            Err(err) => {
+                error!("webhook::serve() - accept failed: {:?}", err);
+                return;
+            }
+        };
+
+        let secret = secret.clone();
+        let events = events.clone();
+        tokio::spawn(async move {
+            match tokio::time::timeout(CONNECTION_TIMEOUT, handle_connection(stream, secret.as_deref(), &events)).await {
+                Ok(Ok(())) => {},
+                Ok(Err(err)) => warn!("webhook::handle_connection() - {:?}", err),
+                Err(_) => warn!("webhook::handle_connection() - timed out after {:?}", CONNECTION_TIMEOUT),
+            }
+        });
+    }
+}
+
+async fn respond(stream: &mut TcpStream, status: &str, body: &str) -> std::io::Result<()> {
+    let response = format!(
+        "HTTP/1.1 {}\r\nContent-Type: text/plain\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        status, body.len(), body
+    );
+    stream.write_all(response.as_bytes()).await?;
+    stream.shutdown().await
+}
+
+// Handles a single HTTP/1.1 request, the connection is closed after the response
+async fn handle_connection(mut stream: TcpStream, secret: Option<&str>, events: &WebhookEvents) -> std::io::Result<()> {
+    let mut buf: Vec<u8> = Vec::new();
+    let mut chunk = [0u8; 4096];
+
+    // Read until the end of the request line & headers
+    let head_end = loop {
+        if let Some(idx) = buf.windows(4).position(|x| x == b"\r\n\r\n") {
+            break idx + 4;
+        }
+        if buf.len() > MAX_HEAD_SIZE {
+            return respond(&mut stream, "431 Request Header Fields Too Large", "").await;
+        }
+        let read = stream.read(&mut chunk).await?;
+        if read == 0 {
+            return Ok(());
+        }
+        buf.extend_from_slice(&chunk[..read]);
+    };
+
+    let head = String::from_utf8_lossy(&buf[..head_end]).to_string();
+    let mut lines = head.split("\r\n");
+    let method = lines.next().and_then(|line| line.split_whitespace().next()).unwrap_or_default().to_string();
+
+    // Header names are case-insensitive
+    let headers: HashMap<String, String> = lines
+        .filter_map(|line| {
+            let (name, value) = line.split_once(':')?;
+            Some((name.trim().to_lowercase(), value.trim().to_string()))
+        })
+        .collect();
+
+    if method != "POST" {
+        return respond(&mut stream, "405 Method Not Allowed", "").await;
+    }
+
+    let content_length: usize = match headers.get("content-length").and_then(|x| x.parse().ok()) {
+        Some(x) if x <= MAX_BODY_SIZE => x,
+        Some(_) => return respond(&mut stream, "413 Payload Too Large", "").await,
+        None => return respond(&mut stream, "411 Length Required", "").await,
+    };
+
+    let mut body: Vec<u8> = buf[head_end..].to_vec();
+    while body.len() < content_length {
+        let read = stream.read(&mut chunk).await?;
+        if read == 0 {
+            return respond(&mut stream, "400 Bad Request", "incomplete body").await;
+        }
+        body.extend_from_slice(&chunk[..read]);
+    }
+    body.truncate(content_length);
+
+    if let Some(secret) = secret {
+        let signed = headers.get("linear-signature")
+            .map(|signature| verify_signature(secret, &body, signature))
+            .unwrap_or(false);
+        if !signed {
+            return respond(&mut stream, "401 Unauthorized", "invalid signature").await;
+        }
+    }
+
+    let payload: Value = match serde_json::from_slice(&body) {
+        Ok(x) => x,
+        Err(err) => return respond(&mut stream, "400 Bad Request", &err.to_string()).await,
+    };
+
+    if secret.is_some() && !is_fresh_payload(&payload, SystemTime::now()) {
+        return respond(&mut stream, "401 Unauthorized", "stale webhookTimestamp").await;
+    }
+
+    match IssueWebhookEvent::from_payload(&payload) {
+        Ok(Some(event)) => {
+            info!("webhook - received {:?}", event);
+            events.push(event);
+            respond(&mut stream, "200 OK", "ok").await
+        },
+        Ok(None) => respond(&mut stream, "200 OK", "ignored").await,
+        Err(err) => respond(&mut stream, "400 Bad Request", &err).await,
+    }
+}
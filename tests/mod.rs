@@ -1,14 +1,15 @@
 // NOTE: Rate limiting can cause flakiness
+// NOTE: Set LINEAR_API_ENDPOINT to run against a local GraphQL server instead of api.linear.app
 // TODO: create & blast away custom views each run
 
 #[macro_use] extern crate log;
 extern crate simplelog;
 
 use simplelog::*;
+use chrono::NaiveDate;
+use serde_json::json;
 use insta;
 
-use anyhow::Result;
-
 #[macro_use]
 extern crate lazy_static;
 
@@ -20,12 +21,25 @@ use std::{
 
 use rust_cli::{
     app::{ Platform },
+    components::{ dashboard_view_panel::DashboardViewPanel, issue_history },
+    constants::IssueModificationOp,
+    config::ConfigFile,
+    constants::view_panel_columns::{ PanelColumn, PanelColumnConfig, resolve_panel_columns },
+    error::{ ClientError, ClientResult },
+    github::{ GithubClient, GithubConfig, GithubProject },
     linear::{
         LinearConfig,
         client::{ LinearClient },
-        schema::{ CustomView, CustomViewResponseData }
+        scheduler::{ RateLimitQuota, retry_wait },
+        schema::{ CustomView, CustomViewResponseData, Issue, IssueUpdateInput }
+    },
+    util::{
+        GraphQLCursor, error_panic,
+        export::{ self, ExportFormat },
+        grid_layout::{ GridLayout, PanelSpan, grid_layout, place_panels },
+        issue_order::{ PanelOrder, SortKey, GroupKey },
     },
-    util::{ GraphQLCursor, error_panic },
+    webhook::{ self, WebhookAction, WebhookEvents },
 };
 
 
@@ -122,7 +136,7 @@ pub fn fetch_all_custom_views() -> Vec<CustomView> {
     let client = linear_client_lock.as_ref().unwrap();
 
     // fetch all custom views
-    let mut view_fetch_result: Result<Option<CustomViewResponseData>>;
+    let mut view_fetch_result: ClientResult<Option<CustomViewResponseData>>;
     let mut custom_view_cursor: Option<GraphQLCursor> = None;
 
     let mut custom_views: Vec<CustomView> = Vec::new();
@@ -174,6 +188,12 @@ fn exec_snapshot_test(view: &CustomView, snapshot: &str)
     }
 }
 
+// Offline tests override the fields they need with struct update syntax
+#[cfg(test)]
+fn test_issue(id: &str, title: &str) -> Issue {
+    Issue { id: id.to_string(), title: title.to_string(), ..Issue::default() }
+}
+
 // View Tests
 
 #[test]
@@ -328,4 +348,780 @@ pub fn workflow_state_views() {
         "workflow_state_single_not_selected",
     );
 
-}
\ No newline at end of file
+}
+
+
+// Webhook Tests, fixture payloads are POSTed to a local listener
+
+const WEBHOOK_FIXTURE_ISSUE_ID: &str = "8d4c4c59-1f1c-4c0f-9a44-0e5d1c6b2f3a";
+
+#[cfg(test)]
+fn read_webhook_fixture(name: &str) -> String {
+    fs::read_to_string(format!("tests/fixtures/webhooks/{}.json", name)).expect("Failed to read webhook fixture")
+}
+
+// Returns:
+//     the queue the listener pushes to & its address
+#[cfg(test)]
+async fn start_webhook_listener(secret: Option<&str>) -> (WebhookEvents, String) {
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("http://{}/", listener.local_addr().unwrap());
+    let events = WebhookEvents::default();
+
+    tokio::spawn(webhook::serve(listener, secret.map(|x| x.to_string()), events.clone()));
+    (events, url)
+}
+
+#[test]
+pub fn webhook_issue_fixtures() {
+    aw!(async {
+        let (events, url) = start_webhook_listener(None).await;
+        let client = reqwest::Client::new();
+
+        for fixture in ["issue_create", "issue_update", "issue_remove"].iter() {
+            let resp = client.post(&url).body(read_webhook_fixture(fixture)).send().await.unwrap();
+            assert_eq!(resp.status().as_u16(), 200, "{} was rejected", fixture);
+        }
+
+        let received = events.drain();
+        let actions: Vec<WebhookAction> = received.iter().map(|event| event.action).collect();
+        assert_eq!(actions, vec![WebhookAction::Create, WebhookAction::Update, WebhookAction::Remove]);
+        assert!(received.iter().all(|event| event.issue_id == WEBHOOK_FIXTURE_ISSUE_ID));
+
+        // Malformed payloads aren't queued
+        let resp = client.post(&url).body("{").send().await.unwrap();
+        assert_eq!(resp.status().as_u16(), 400);
+
+        // A body cut short of its Content-Length isn't parsed
+        use tokio::io::{ AsyncReadExt, AsyncWriteExt };
+        let body = read_webhook_fixture("issue_update");
+        let mut stream = tokio::net::TcpStream::connect(url.trim_start_matches("http://").trim_end_matches('/')).await.unwrap();
+        let request = format!("POST / HTTP/1.1\r\nContent-Length: {}\r\n\r\n{}", body.len(), &body[..body.len() / 2]);
+        stream.write_all(request.as_bytes()).await.unwrap();
+        stream.shutdown().await.unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).await.unwrap();
+        assert!(response.starts_with("HTTP/1.1 400"), "{}", response);
+        assert!(response.ends_with("incomplete body"), "{}", response);
+
+        assert!(events.drain().is_empty());
+    });
+}
+
+#[test]
+pub fn webhook_signature() {
+    use std::time::{ Duration, SystemTime, UNIX_EPOCH };
+
+    aw!(async {
+        let secret = "webhook-test-secret";
+        let (events, url) = start_webhook_listener(Some(secret)).await;
+        let client = reqwest::Client::new();
+        let sign = |body: &str| -> String {
+            let key = ring::hmac::Key::new(ring::hmac::HMAC_SHA256, secret.as_bytes());
+            ring::hmac::sign(&key, body.as_bytes()).as_ref()
+                .iter()
+                .map(|byte| format!("{:02x}", byte))
+                .collect()
+        };
+
+        // The fixture was sent long ago, as if captured & replayed
+        let stale_body = read_webhook_fixture("issue_update");
+        let mut payload: serde_json::Value = serde_json::from_str(&stale_body).unwrap();
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_millis() as u64;
+        payload["webhookTimestamp"] = json!(now);
+        let body = payload.to_string();
+
+        let resp = client.post(&url).body(body.clone()).send().await.unwrap();
+        assert_eq!(resp.status().as_u16(), 401);
+
+        assert!(webhook::verify_signature(secret, body.as_bytes(), &sign(&body)));
+        let resp = client.post(&url).header("Linear-Signature", sign(&stale_body)).body(stale_body.clone()).send().await.unwrap();
+        assert_eq!(resp.status().as_u16(), 401);
+
+        let resp = client.post(&url).header("Linear-Signature", sign(&body)).body(body).send().await.unwrap();
+        assert_eq!(resp.status().as_u16(), 200);
+        assert_eq!(events.drain().len(), 1);
+
+        // Payloads are accepted within a minute either way of their timestamp
+        let sent_at = UNIX_EPOCH + Duration::from_millis(now);
+        assert!(webhook::is_fresh_payload(&payload, sent_at + Duration::from_secs(30)));
+        assert!(webhook::is_fresh_payload(&payload, sent_at - Duration::from_secs(30)));
+        assert!(!webhook::is_fresh_payload(&payload, sent_at + Duration::from_secs(120)));
+        assert!(!webhook::is_fresh_payload(&json!({}), sent_at));
+    });
+}
+
+#[test]
+pub fn webhook_listen_requires_secret() {
+    assert!(ConfigFile::parse("version = 3\n[app]\nwebhook_listen = \"127.0.0.1:8787\"\n").is_ok());
+    assert!(ConfigFile::parse("version = 3\n[app]\nwebhook_listen = \"0.0.0.0:8787\"\nwebhook_secret = \"secret\"\n").is_ok());
+
+    let errors = ConfigFile::parse("version = 3\n[app]\nwebhook_listen = \"0.0.0.0:8787\"\n").unwrap_err();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].line, Some(3));
+}
+
+
+// Due Date Tests
+
+#[test]
+pub fn relative_date_expressions() {
+    use rust_cli::util::date::parse_relative_date;
+
+    // Monday
+    let base = NaiveDate::from_ymd(2022, 3, 21);
+    let date = |y, m, d| Some(NaiveDate::from_ymd(y, m, d));
+
+    assert_eq!(parse_relative_date("2022-04-01", base), date(2022, 4, 1));
+    assert_eq!(parse_relative_date(" Today ", base), date(2022, 3, 21));
+    assert_eq!(parse_relative_date("tomorrow", base), date(2022, 3, 22));
+    assert_eq!(parse_relative_date("yesterday", base), date(2022, 3, 20));
+
+    assert_eq!(parse_relative_date("+3d", base), date(2022, 3, 24));
+    assert_eq!(parse_relative_date("+3", base), date(2022, 3, 24));
+    assert_eq!(parse_relative_date("-1w", base), date(2022, 3, 14));
+    assert_eq!(parse_relative_date("+2weeks", base), date(2022, 4, 4));
+    assert_eq!(parse_relative_date("+1m", base), date(2022, 4, 21));
+    // Clamped to the end of the month
+    assert_eq!(parse_relative_date("-1m", NaiveDate::from_ymd(2022, 3, 31)), date(2022, 2, 28));
+
+    // Weekdays include today, unless prefixed with 'next'
+    assert_eq!(parse_relative_date("fri", base), date(2022, 3, 25));
+    assert_eq!(parse_relative_date("mon", base), date(2022, 3, 21));
+    assert_eq!(parse_relative_date("next mon", base), date(2022, 3, 28));
+    assert_eq!(parse_relative_date("next friday", base), date(2022, 3, 25));
+
+    assert_eq!(parse_relative_date("", base), None);
+    assert_eq!(parse_relative_date("+3y", base), None);
+    assert_eq!(parse_relative_date("+d", base), None);
+    assert_eq!(parse_relative_date("someday", base), None);
+    assert_eq!(parse_relative_date("2022-13-01", base), None);
+}
+
+#[test]
+pub fn relative_date_out_of_range() {
+    use rust_cli::util::date::parse_relative_date;
+
+    let base = NaiveDate::from_ymd(2022, 3, 21);
+
+    // Out of range offsets are rejected instead of panicking
+    for input in ["+99999999999d", "-99999999999d", "+9999999999999w", "+99999999999m", "+9223372036854775807w", "-9223372036854775807m", "+99999999999999999999d"].iter() {
+        assert_eq!(parse_relative_date(input, base), None, "{} should be out of range", input);
+    }
+
+    assert_eq!(parse_relative_date("tomorrow", chrono::naive::MAX_DATE), None);
+    assert_eq!(parse_relative_date("yesterday", chrono::naive::MIN_DATE), None);
+}
+
+
+// Local Filter Tests
+
+#[test]
+pub fn filter_expressions() {
+    use rust_cli::linear::filter::compile_filter;
+
+    assert_eq!(
+        compile_filter("assignee:me").unwrap(),
+        json!({ "and": [{ "assignee": { "isMe": { "eq": true } } }] })
+    );
+    assert_eq!(
+        compile_filter("state:started,unstarted").unwrap(),
+        json!({ "and": [{ "or": [
+            { "state": { "type": { "eq": "started" } } },
+            { "state": { "type": { "eq": "unstarted" } } },
+        ] }] })
+    );
+    assert_eq!(
+        compile_filter("state:\"In Review\" priority:high").unwrap(),
+        json!({ "and": [
+            { "state": { "name": { "eqIgnoreCase": "In Review" } } },
+            { "priority": { "eq": 2 } },
+        ] })
+    );
+
+    // Every key compiles to valid IssueFilter fields
+    let all_keys = "assignee:none creator:alice state:backlog label:bug team:ENG project:none project:Roadmap priority:0 title:crash";
+    assert_eq!(compile_filter(all_keys).unwrap()["and"].as_array().unwrap().len(), 9);
+}
+
+#[test]
+pub fn filter_expression_errors() {
+    use rust_cli::linear::filter::compile_filter;
+
+    for expression in ["", "   ", "assignee", "state:", "state:,", "title:\"unterminated", "priority:critical", "asignee:me"].iter() {
+        assert!(compile_filter(expression).is_err(), "'{}' should be rejected", expression);
+    }
+
+    let err = compile_filter("asignee:me").unwrap_err().to_string();
+    assert!(err.contains("asignee"), "{}", err);
+}
+
+#[test]
+pub fn filter_unknown_fields() {
+    use rust_cli::linear::filter::validate_filter;
+
+    assert!(validate_filter(&json!({ "and": [{ "title": { "containsIgnoreCase": "crash" } }] })).is_ok());
+
+    // Deserializing alone would drop these fields, leaving an unfiltered panel
+    let err = validate_filter(&json!({ "and": [{ "titel": { "containsIgnoreCase": "crash" } }] })).unwrap_err().to_string();
+    assert!(err.contains("and[0].titel"), "{}", err);
+
+    let err = validate_filter(&json!({ "state": { "name": { "eqIgnoreCas": "Done" } } })).unwrap_err().to_string();
+    assert!(err.contains("state.name.eqIgnoreCas"), "{}", err);
+
+    // Values of the wrong type
+    assert!(validate_filter(&json!({ "priority": { "eq": "high" } })).is_err());
+}
+
+
+// Panel Order Tests
+
+#[cfg(test)]
+fn group_headers(order: &PanelOrder, issues: &[Issue]) -> Vec<String> {
+    let mut headers: Vec<String> = Vec::new();
+    for issue in issues.iter() {
+        let label = order.group_label(issue).unwrap();
+        if headers.last() != Some(&label) {
+            headers.push(label);
+        }
+    }
+    headers
+}
+
+#[test]
+pub fn panel_order_group_by_assignee() {
+    let order = PanelOrder { sort: Some(SortKey::Priority), group: Some(GroupKey::Assignee) };
+
+    let mut issues: Vec<Issue> = [
+        ("1", 3.0, Some("bob")),
+        ("2", 1.0, None),
+        ("3", 2.0, Some("Alice")),
+        ("4", 1.0, Some("alice")),
+        ("5", 4.0, Some("Alice")),
+        ("6", 0.0, Some("Bob")),
+        ("7", 1.0, Some("bob")),
+    ].iter()
+        .map(|&(id, priority, assignee)| Issue {
+            priority,
+            assignee: assignee.map(|name| serde_json::from_value(json!({ "id": name, "displayName": name })).unwrap()),
+            ..test_issue(id, "")
+        })
+        .collect();
+    order.apply(&mut issues);
+
+    // Names differing only by case are separate groups, each with a single header
+    let ids: Vec<&str> = issues.iter().map(|issue| issue.id.as_str()).collect();
+    assert_eq!(ids, vec!["3", "5", "4", "6", "7", "1", "2"]);
+    assert_eq!(group_headers(&order, &issues), vec!["Alice", "alice", "Bob", "bob", "Unassigned"]);
+
+    // A header row precedes each group
+    assert_eq!(order.display_row_idx(&issues, 0), 1);
+    assert_eq!(order.display_row_idx(&issues, 2), 4);
+    assert_eq!(order.display_row_idx(&issues, 6), 11);
+}
+
+#[test]
+pub fn panel_order_group_by_state() {
+    let order = PanelOrder { sort: None, group: Some(GroupKey::State) };
+
+    let mut issues: Vec<Issue> = [
+        ("1", "completed", "Done"),
+        ("2", "backlog", "Backlog"),
+        ("3", "started", "In Review"),
+        ("4", "started", "In Progress"),
+        ("5", "backlog", "Backlog"),
+        ("6", "started", "in progress"),
+    ].iter()
+        .map(|&(id, state_type, state_name)| {
+            let mut issue = test_issue(id, "");
+            issue.state.type_ = state_type.to_string();
+            issue.state.name = state_name.to_string();
+            issue
+        })
+        .collect();
+    order.apply(&mut issues);
+
+    // Workflow order, then by name, API order within a group
+    let ids: Vec<&str> = issues.iter().map(|issue| issue.id.as_str()).collect();
+    assert_eq!(ids, vec!["2", "5", "4", "6", "3", "1"]);
+    assert_eq!(group_headers(&order, &issues), vec!["Backlog", "In Progress", "in progress", "In Review", "Done"]);
+
+    // Default order keeps API order
+    let mut unordered = issues.clone();
+    unordered.reverse();
+    PanelOrder::default().apply(&mut unordered);
+    assert_eq!(unordered.first().map(|issue| issue.id.as_str()), Some("1"));
+    assert_eq!(PanelOrder::default().display_row_idx(&unordered, 3), 3);
+}
+
+
+// Config Tests
+
+#[cfg(test)]
+const COMMENTED_CONFIG: &str = r#"# rust-cli config
+version = 3
+
+[app]
+tick_rate_ms = 250 # redraw rate
+
+[keybindings]
+# vim-ish
+refresh_panel = "r"
+
+[[dashboards]]
+name = "Work"
+"#;
+
+#[test]
+pub fn config_error_lines() {
+    // The same name & key appear earlier in other tables
+    let text = r#"version = 3
+
+[app]
+profile = "work"
+
+[[profiles]]
+name = "work"
+
+[[profiles]]
+name = "work"
+
+[[dashboards]]
+name = "Main"
+
+[[dashboards.panels]]
+refresh_interval_secs = 60
+
+[[dashboards.panels]]
+refresh_interval_secs = 0
+"#;
+    let errors = ConfigFile::parse(text).unwrap_err();
+    let lines: Vec<Option<usize>> = errors.iter().map(|x| x.line).collect();
+    assert_eq!(lines, vec![Some(10), Some(19)]);
+}
+
+#[test]
+pub fn config_save_keeps_comments() {
+    let mut config = ConfigFile::parse(COMMENTED_CONFIG).unwrap();
+    config.app.tick_rate_ms = Some(500);
+    config.app.dashboard = Some(String::from("Work"));
+
+    let saved = config.to_toml(Some(COMMENTED_CONFIG)).unwrap();
+    assert!(saved.starts_with("# rust-cli config\nversion = 3\n"), "{}", saved);
+    assert!(saved.contains("tick_rate_ms = 500 # redraw rate\n"), "{}", saved);
+    assert!(saved.contains("dashboard = 'Work'"), "{}", saved);
+    assert!(saved.contains("# vim-ish\nrefresh_panel = \"r\""), "{}", saved);
+
+    let reparsed = ConfigFile::parse(&saved).unwrap();
+    assert_eq!(reparsed.app.tick_rate_ms, Some(500));
+    assert_eq!(reparsed.dashboard_names(), vec![String::from("Work")]);
+
+    // Unchanged configs are written back as they were
+    assert_eq!(ConfigFile::parse(COMMENTED_CONFIG).unwrap().to_toml(Some(COMMENTED_CONFIG)).unwrap(), COMMENTED_CONFIG);
+}
+
+
+// Grid Layout Tests
+
+#[cfg(test)]
+fn span(rows: u16, columns: u16) -> PanelSpan {
+    PanelSpan { rows, columns }
+}
+
+#[test]
+pub fn grid_layout_placement() {
+    // A panel wider than the grid is clamped to it, later panels fill the first free cells
+    assert_eq!(place_panels(2, &[span(1, 1), span(1, 3), span(1, 1)]), vec![(0, 0), (1, 0), (0, 1)]);
+
+    // Cells covered by a taller panel are skipped
+    assert_eq!(place_panels(2, &[span(2, 1), span(1, 1), span(1, 1), span(1, 1)]), vec![(0, 0), (0, 1), (1, 1), (2, 0)]);
+
+    assert!(place_panels(0, &[span(1, 1)]).is_empty());
+}
+
+#[test]
+pub fn grid_layout_rects() {
+    use tui::layout::Rect;
+
+    let layout = GridLayout { rows: vec![1], columns: vec![1, 1] };
+    let area = Rect::new(0, 0, 40, 20);
+
+    // The third panel overflows onto an extra row
+    assert_eq!(grid_layout(&layout, &[span(1, 1), span(1, 1), span(1, 1)], area), vec![
+        Rect::new(0, 0, 20, 10),
+        Rect::new(20, 0, 20, 10),
+        Rect::new(0, 10, 20, 10),
+    ]);
+
+    // Spans wider or taller than the grid
+    assert_eq!(grid_layout(&layout, &[span(2, 5)], area), vec![Rect::new(0, 0, 40, 20)]);
+
+    // Overflow rows in a short area can be too small to draw
+    let rects = grid_layout(&layout, &[span(1, 1); 20], Rect::new(0, 0, 40, 5));
+    assert_eq!(rects.len(), 20);
+    assert!(rects.iter().any(|rect| rect.height < 3));
+
+    assert!(grid_layout(&GridLayout { rows: vec![1], columns: Vec::new() }, &[span(1, 1)], area).is_empty());
+}
+
+
+// Export Tests
+
+// A title & description with characters each format escapes
+#[cfg(test)]
+fn export_test_issues() -> (Vec<Issue>, Vec<PanelColumn>) {
+    let issues = vec![
+        Issue { number: 1.0, ..test_issue("1", "Plain") },
+        Issue {
+            number: 2.0,
+            description: Some(String::from("line 1\nline 2\r\nline 3")),
+            ..test_issue("2", "Say \"hi\", then | pipe")
+        },
+    ];
+    let configs: Vec<PanelColumnConfig> = ["number", "title", "description"].iter()
+        .map(|id| PanelColumnConfig { id: id.to_string(), min_width: None, max_height: None, priority: None })
+        .collect();
+    (issues, resolve_panel_columns(&configs))
+}
+
+#[test]
+pub fn export_csv_quoting() {
+    let (issues, columns) = export_test_issues();
+    let csv = export::render(ExportFormat::Csv, &issues, &columns).unwrap();
+
+    assert_eq!(csv, concat!(
+        "#,Title,Desc\n",
+        "1,Plain,\n",
+        "2,\"Say \"\"hi\"\", then | pipe\",\"line 1\nline 2\r\nline 3\"\n",
+    ));
+}
+
+#[test]
+pub fn export_markdown_escaping() {
+    let (issues, columns) = export_test_issues();
+    let markdown = export::render(ExportFormat::Markdown, &issues, &columns).unwrap();
+
+    assert_eq!(markdown, concat!(
+        "| # | Title | Desc |\n",
+        "| --- | --- | --- |\n",
+        "| 1 | Plain |  |\n",
+        "| 2 | Say \"hi\", then \\| pipe | line 1<br>line 2<br>line 3 |\n",
+    ));
+}
+
+
+// Request Scheduler Tests
+
+#[cfg(test)]
+fn rate_limit_headers(headers: &[(&'static str, String)]) -> reqwest::header::HeaderMap {
+    let mut map = reqwest::header::HeaderMap::new();
+    for (name, value) in headers.iter() {
+        map.insert(*name, value.parse().unwrap());
+    }
+    map
+}
+
+#[test]
+pub fn rate_limit_quota() {
+    let reset = chrono::Utc::now() + chrono::Duration::seconds(60);
+    let mut quota = RateLimitQuota::default();
+
+    quota.update(&rate_limit_headers(&[
+        ("x-ratelimit-requests-limit", String::from("1500")),
+        ("x-ratelimit-requests-remaining", String::from("1423")),
+        ("x-ratelimit-requests-reset", reset.timestamp_millis().to_string()),
+    ]));
+    assert_eq!(quota.requests_limit, Some(1500));
+    assert_eq!(quota.requests_remaining, Some(1423));
+    assert_eq!(quota.requests_reset.map(|x| x.timestamp_millis()), Some(reset.timestamp_millis()));
+    assert_eq!(quota.exhausted_until(), None);
+
+    // Responses without rate limit headers leave the quota as is
+    let before = quota.clone();
+    quota.update(&rate_limit_headers(&[]));
+    assert_eq!(quota, before);
+
+    // Exhausted until the latest reset still ahead
+    quota.update(&rate_limit_headers(&[
+        ("x-ratelimit-complexity-limit", String::from("250000")),
+        ("x-ratelimit-complexity-remaining", String::from("0")),
+        ("x-ratelimit-complexity-reset", reset.timestamp_millis().to_string()),
+    ]));
+    assert_eq!(quota.exhausted_until().map(|x| x.timestamp_millis()), Some(reset.timestamp_millis()));
+
+    let past_reset = chrono::Utc::now() - chrono::Duration::seconds(1);
+    quota.update(&rate_limit_headers(&[
+        ("x-ratelimit-complexity-remaining", String::from("0")),
+        ("x-ratelimit-complexity-reset", past_reset.timestamp_millis().to_string()),
+    ]));
+    assert_eq!(quota.exhausted_until(), None);
+}
+
+#[test]
+pub fn request_retry_wait() {
+    use std::time::Duration;
+
+    let backoff = Duration::from_millis(500);
+    let network = ClientError::Network(String::from("connection reset"));
+    let rate_limit = ClientError::RateLimit(String::from("RATELIMITED"));
+    let graphql = ClientError::GraphQL(String::from("invalid filter"));
+
+    // Transient failures are only retried if the request can be repeated
+    assert_eq!(retry_wait(&network, true, 0, backoff, None), Some(backoff));
+    assert_eq!(retry_wait(&network, false, 0, backoff, None), None);
+    assert_eq!(retry_wait(&graphql, true, 0, backoff, None), None);
+
+    // Rate limited requests weren't applied, so are always retried, after the quota resets
+    assert_eq!(retry_wait(&rate_limit, false, 0, backoff, None), Some(backoff));
+    assert_eq!(retry_wait(&rate_limit, false, 0, backoff, Some(Duration::from_secs(10))), Some(Duration::from_secs(10)));
+    assert_eq!(retry_wait(&rate_limit, false, 0, backoff, Some(Duration::from_millis(100))), Some(backoff));
+    assert_eq!(retry_wait(&network, true, 0, backoff, Some(Duration::from_secs(10))), Some(backoff));
+
+    // Gives up after too many attempts, or if the wait is too long
+    assert_eq!(retry_wait(&network, true, 4, backoff, None), None);
+    assert_eq!(retry_wait(&rate_limit, false, 0, backoff, Some(Duration::from_secs(120))), None);
+}
+
+
+// View Panel Tests
+
+#[cfg(test)]
+fn panel_titles(view_panel: &DashboardViewPanel) -> Vec<String> {
+    view_panel.issue_table_data.lock().unwrap().iter().map(|x| format!("{}:{}", x.id, x.title)).collect()
+}
+
+#[test]
+pub fn view_panel_refresh_keeps_modified_issues() {
+    use std::time::{ Duration, Instant };
+
+    let mut view_panel = DashboardViewPanel::default();
+    *view_panel.issue_table_data.lock().unwrap() = vec![test_issue("a", "old"), test_issue("b", "old")];
+
+    // 'a' is modified while a refresh started before is in flight
+    *view_panel.refreshed_at.lock().unwrap() = Instant::now() - Duration::from_secs(1);
+    view_panel.mark_modified("a");
+    view_panel.issue_table_data.lock().unwrap()[0].title = String::from("patched");
+
+    *view_panel.refresh_result.lock().unwrap() = Some((
+        vec![test_issue("a", "stale"), test_issue("b", "new"), test_issue("c", "new")],
+        None,
+    ));
+    assert_eq!(view_panel.apply_refresh_result(Some(1)), Some(1));
+    assert_eq!(panel_titles(&view_panel), vec!["a:patched", "b:new", "c:new"]);
+
+    // A refresh started after the modification includes it
+    *view_panel.refreshed_at.lock().unwrap() = Instant::now();
+    *view_panel.refresh_result.lock().unwrap() = Some((vec![test_issue("a", "fetched")], None));
+    view_panel.apply_refresh_result(None);
+    assert_eq!(panel_titles(&view_panel), vec!["a:fetched"]);
+    assert!(view_panel.modified_at.lock().unwrap().is_empty());
+}
+
+#[test]
+pub fn view_panel_issue_changes() {
+    let mut view_panel = DashboardViewPanel::default();
+    *view_panel.issue_table_data.lock().unwrap() = vec![
+        test_issue("a", "old"), test_issue("b", "old"), test_issue("c", "old"),
+    ];
+
+    // Insert, replace & remove, the selected issue stays selected
+    view_panel.issue_changes.lock().unwrap().extend(vec![
+        (String::from("d"), Some(test_issue("d", "new"))),
+        (String::from("b"), Some(test_issue("b", "new"))),
+        (String::from("c"), None),
+        (String::from("x"), None),
+    ]);
+    assert_eq!(view_panel.apply_issue_changes(Some(1)), Some(2));
+    assert_eq!(panel_titles(&view_panel), vec!["d:new", "a:old", "b:new"]);
+    assert!(view_panel.highlighted_until.contains_key("d") && view_panel.highlighted_until.contains_key("b"));
+    assert!(!view_panel.highlighted_until.contains_key("a"));
+
+    // Removing the selected issue selects the nearest row
+    view_panel.issue_changes.lock().unwrap().push((String::from("b"), None));
+    assert_eq!(view_panel.apply_issue_changes(Some(2)), Some(1));
+    assert_eq!(panel_titles(&view_panel), vec!["d:new", "a:old"]);
+
+    // Unselected panels stay unselected
+    view_panel.issue_changes.lock().unwrap().push((String::from("a"), None));
+    assert_eq!(view_panel.apply_issue_changes(None), None);
+    assert_eq!(panel_titles(&view_panel), vec!["d:new"]);
+}
+
+
+// Issue History Tests
+
+#[test]
+pub fn issue_history_restore_team() {
+    let mut snapshot = Issue {
+        project: serde_json::from_value(json!({ "id": "project", "name": "Project", "color": "#000000" })).unwrap(),
+        ..test_issue("issue", "")
+    };
+    snapshot.team.id = String::from("team");
+    snapshot.state.id = String::from("state");
+
+    // Fields unset in the snapshot are cleared, as restore_fields unsets them locally
+    let (update, cleared) = issue_history::restore_input(&snapshot, IssueModificationOp::Team);
+    assert_eq!(update.team_id.as_deref(), Some("team"));
+    assert_eq!(update.state_id.as_deref(), Some("state"));
+    assert_eq!(update.project_id.as_deref(), Some("project"));
+    assert_eq!(update.cycle_id, None);
+    assert_eq!(cleared, vec![IssueModificationOp::Cycle]);
+
+    snapshot.project = None;
+    let (_, cleared) = issue_history::restore_input(&snapshot, IssueModificationOp::Team);
+    assert_eq!(cleared, vec![IssueModificationOp::Cycle, IssueModificationOp::Project]);
+}
+
+
+// GitHub Tests, requests are answered by a local mock of the GraphQL API
+
+// Returns:
+//     the operation names & variables of the requests received, & the mock's address
+// Accepts:
+//     responses: the "data" of each operation, by name
+#[cfg(test)]
+async fn start_github_mock(responses: Vec<(&'static str, serde_json::Value)>) -> (Arc<Mutex<Vec<(String, serde_json::Value)>>>, String) {
+    use tokio::io::{ AsyncReadExt, AsyncWriteExt };
+
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("http://{}/graphql", listener.local_addr().unwrap());
+    let requests: Arc<Mutex<Vec<(String, serde_json::Value)>>> = Arc::new(Mutex::new(Vec::new()));
+
+    let received = requests.clone();
+    tokio::spawn(async move {
+        loop {
+            let (mut stream, _) = listener.accept().await.unwrap();
+            let mut buf: Vec<u8> = Vec::new();
+            let mut chunk = [0u8; 4096];
+
+            // Read the headers, then the rest of the body
+            let head_end = loop {
+                if let Some(idx) = buf.windows(4).position(|x| x == b"\r\n\r\n") {
+                    break idx + 4;
+                }
+                let read = stream.read(&mut chunk).await.unwrap();
+                buf.extend_from_slice(&chunk[..read]);
+            };
+            let head = String::from_utf8_lossy(&buf[..head_end]).to_lowercase();
+            let content_length: usize = head.lines()
+                .find_map(|line| line.strip_prefix("content-length:"))
+                .map(|x| x.trim().parse().unwrap())
+                .unwrap();
+            while buf.len() < head_end + content_length {
+                let read = stream.read(&mut chunk).await.unwrap();
+                buf.extend_from_slice(&chunk[..read]);
+            }
+
+            let request: serde_json::Value = serde_json::from_slice(&buf[head_end..]).unwrap();
+            let operation: String = request["query"].as_str().unwrap()
+                .split_whitespace()
+                .nth(1).unwrap()
+                .split('(').next().unwrap()
+                .to_string();
+            received.lock().unwrap().push((operation.clone(), request["variables"].clone()));
+
+            let data = responses.iter()
+                .find(|(name, _)| *name == operation)
+                .map(|(_, data)| data.clone())
+                .unwrap_or(serde_json::Value::Null);
+            let body = json!({ "data": data }).to_string();
+            let response = format!("HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}", body.len(), body);
+            stream.write_all(response.as_bytes()).await.unwrap();
+            stream.shutdown().await.unwrap();
+        }
+    });
+
+    (requests, url)
+}
+
+#[cfg(test)]
+fn github_project_issue(id: &str, number: u64) -> serde_json::Value {
+    json!({
+        "id": id, "number": number, "title": format!("Issue {}", number), "body": "", "createdAt": "2023-03-14T00:00:00Z", "state": "OPEN",
+        "repository": { "id": "R_1", "nameWithOwner": "octo-org/app" },
+        "author": { "login": "octocat" },
+        "assignees": { "nodes": [] },
+        "labels": { "nodes": [] },
+        "milestone": null,
+    })
+}
+
+#[test]
+pub fn github_project_items() {
+    aw!(async {
+        let options = json!([
+            { "id": "opt-todo", "name": "Todo", "color": "GREEN", "description": "" },
+            { "id": "opt-progress", "name": "In Progress", "color": "YELLOW", "description": "" },
+            { "id": "opt-done", "name": "Done", "color": "PURPLE", "description": "" },
+        ]);
+        let (requests, url) = start_github_mock(vec![
+            ("ProjectItems", json!({ "repositoryOwner": { "projectV2": {
+                "id": "PVT_1",
+                "field": { "id": "PVTSSF_1", "options": options },
+                "items": {
+                    "nodes": [
+                        { "fieldValueByName": { "optionId": "opt-progress" }, "content": github_project_issue("I_1", 1) },
+                        // A draft issue
+                        { "fieldValueByName": null, "content": {} },
+                        { "fieldValueByName": null, "content": github_project_issue("I_2", 2) },
+                    ],
+                    "pageInfo": { "hasNextPage": false, "endCursor": "cursor" },
+                },
+            } } })),
+            ("StatusFieldOptions", json!({ "node": { "options": options } })),
+            ("IssueProjectItems", json!({ "node": { "projectItems": { "nodes": [
+                { "id": "PVTI_other", "project": { "id": "PVT_other" } },
+                { "id": "PVTI_1", "project": { "id": "PVT_1" } },
+            ] } } })),
+            ("UpdateItemStatus", json!({ "updateProjectV2ItemFieldValue": { "projectV2Item": { "id": "PVTI_1" } } })),
+            ("ClearItemStatus", json!({ "clearProjectV2ItemFieldValue": { "projectV2Item": { "id": "PVTI_1" } } })),
+            ("UpdateIssue", json!({ "updateIssue": { "issue": { "id": "I_1" } } })),
+        ]).await;
+
+        let project = GithubProject { name: String::from("Roadmap"), owner: String::from("octo-org"), number: 5, status_field: None };
+        let client = GithubClient::with_config(GithubConfig {
+            profile: String::from("github"),
+            token: Some(String::from("token")),
+            queries: Vec::new(),
+            projects: vec![project],
+            api_endpoint: url,
+            page_size: 50,
+        }).unwrap();
+
+        let views = client.custom_views(None).await.unwrap().unwrap().custom_views.nodes;
+        assert_eq!(views.len(), 1);
+        assert_eq!(views[0].id, "github-project:octo-org/5");
+
+        // Draft issues are skipped, the status field is the workflow state
+        let issues = client.view_issues(&views[0], None).await.unwrap().unwrap().issues.nodes;
+        let states: Vec<(&str, &str, &str)> = issues.iter().map(|issue| (issue.id.as_str(), issue.state.name.as_str(), issue.state.type_.as_str())).collect();
+        assert_eq!(states, vec![("I_1", "In Progress", "started"), ("I_2", "No Status", "backlog")]);
+        assert_eq!(issues[0].team.name, "octo-org/app");
+        assert_eq!(requests.lock().unwrap()[0].1["field"], json!("Status"));
+
+        // The field's options are the workflow states of the project's items
+        let options = client.team_states(&issues[0].team.id, None).await.unwrap().unwrap().workflow_states.nodes;
+        let names: Vec<&str> = options.iter().map(|state| state.name.as_str()).collect();
+        assert_eq!(names, vec!["No Status", "Todo", "In Progress", "Done"]);
+
+        // Setting the state updates the status of the issue's item in the project, without an issue update
+        requests.lock().unwrap().clear();
+        let update = IssueUpdateInput { state_id: Some(options[3].id.clone()), ..IssueUpdateInput::default() };
+        assert!(client.update_issue(&issues[0].id, update).await.unwrap().unwrap().issue_update.success);
+        {
+            let requests = requests.lock().unwrap();
+            let operations: Vec<&str> = requests.iter().map(|(operation, _)| operation.as_str()).collect();
+            assert_eq!(operations, vec!["IssueProjectItems", "UpdateItemStatus"]);
+            assert_eq!(requests[1].1["input"], json!({ "projectId": "PVT_1", "itemId": "PVTI_1", "fieldId": "PVTSSF_1", "value": { "singleSelectOptionId": "opt-done" } }));
+        }
+
+        // "No Status" clears the field, other fields are still updated on the issue
+        requests.lock().unwrap().clear();
+        let update = IssueUpdateInput { title: Some(String::from("Renamed")), state_id: Some(options[0].id.clone()), ..IssueUpdateInput::default() };
+        client.update_issue(&issues[0].id, update).await.unwrap();
+        let requests = requests.lock().unwrap();
+        let operations: Vec<&str> = requests.iter().map(|(operation, _)| operation.as_str()).collect();
+        assert_eq!(operations, vec!["UpdateIssue", "IssueProjectItems", "ClearItemStatus"]);
+        assert_eq!(requests[0].1["input"], json!({ "id": "I_1", "title": "Renamed" }));
+    });
+}